@@ -0,0 +1,40 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use lang::bytecode::vm::VirtualMachine;
+use lang::bytecode::Compiler;
+use lang::interpreter::Interpreter;
+use lang::lexer::Lexer;
+use lang::parser::Parser;
+use lang::semantics::SemanticAnalyzer;
+
+#[allow(clippy::unwrap_used)]
+fn criterion_benchmark(c: &mut Criterion) {
+    let contents = std::fs::read_to_string("../examples/loops.lt").unwrap();
+
+    let mut lexer = Lexer::new(&contents);
+    let tokens = lexer.tokenize().unwrap();
+    let (ast, _) = Parser::new(&tokens).parse();
+
+    let mut semantics = SemanticAnalyzer::new(&ast);
+    semantics.analyze().unwrap();
+
+    let program = Compiler::new(&ast, &semantics).compile().unwrap();
+
+    let mut group = c.benchmark_group("run loops");
+
+    group.sample_size(10);
+    group.bench_function("vm", |b| {
+        b.iter(|| {
+            VirtualMachine::new(&program, std::io::sink())
+                .run()
+                .unwrap();
+        });
+    });
+    group.bench_function("interpreter", |b| {
+        b.iter(|| {
+            Interpreter::new(&ast, std::io::sink()).interpret().unwrap();
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use lang::lexer::Lexer;
+use std::fmt::Write;
+
+/// Generates a program of at least the given size, in bytes, using every kind of token.
+fn generate(size: usize) -> String {
+    let mut program = String::with_capacity(size + 256);
+
+    let mut i = 0;
+    while program.len() < size {
+        let _ = write!(
+            program,
+            r#"// Function number {i}.
+fn function_{i}(a: int, b: float) -> float {{
+    /* Some arithmetic. */
+    let result = a * 0x1F + b / 2.5e-3 - {i};
+    if (result >= 10 && !(a == -1)) {{
+        print("Result: " + "\t{i}\n");
+    }}
+
+    return result;
+}}
+"#
+        );
+        i += 1;
+    }
+
+    program
+}
+
+#[allow(clippy::unwrap_used)]
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lex scaling");
+
+    // If lexing is linear, the throughput stays the same as the input grows.
+    group.sample_size(10);
+    for megabytes in [1, 2, 4, 8] {
+        let program = generate(megabytes * 1024 * 1024);
+
+        group.throughput(Throughput::Bytes(program.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{megabytes} MB")),
+            &program,
+            |b, program| {
+                b.iter(|| {
+                    let mut lexer = Lexer::new(program);
+                    let _ = lexer.tokenize().unwrap();
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
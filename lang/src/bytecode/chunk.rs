@@ -0,0 +1,331 @@
+use crate::bytecode::values::Value;
+use crate::semantics::builtins::Builtin;
+use crate::semantics::numbers::Number;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+/// A single instruction of the virtual machine.
+///
+/// Jump targets are absolute offsets into the code of the same chunk.
+///
+/// # Variants
+///
+/// * `Constant(usize)` - Pushes a constant from the constant pool.
+/// * `Pop` - Discards the top of the stack.
+/// * `GetLocal(usize)` - Pushes the local variable in the given slot.
+/// * `SetLocal(usize)` - Stores the top of the stack in a local slot, without popping it.
+/// * `GetGlobal(usize)` - Pushes the global variable in the given slot.
+/// * `SetGlobal(usize)` - Stores the top of the stack in a global slot, without popping it.
+/// * `ClearLocal(usize)` - Marks a local slot as uninitialized, so reading it fails.
+/// * `ClearGlobal(usize)` - Marks a global slot as uninitialized, so reading it fails.
+/// * `Negate` - Negates a number.
+/// * `Not` - Negates a boolean.
+/// * `ToFloat` - Widens an integer, or the integers in an array, to floats where the type checker
+///   accepted them for floats.
+/// * `Add` - Adds two numbers, or concatenates two strings.
+/// * `Subtract` - Subtracts two numbers.
+/// * `Multiply` - Multiplies two numbers.
+/// * `Divide` - Divides two numbers.
+/// * `Modulo` - Takes the remainder of dividing two numbers.
+/// * `Power` - Raises a number to the power of another.
+/// * `Equal` - Checks whether two values are equal.
+/// * `NotEqual` - Checks whether two values are different.
+/// * `Less` - Checks whether a value is less than another.
+/// * `LessEqual` - Checks whether a value is less than or equal to another.
+/// * `Greater` - Checks whether a value is greater than another.
+/// * `GreaterEqual` - Checks whether a value is greater than or equal to another.
+/// * `BitwiseAnd` - Takes the bitwise and of two integers.
+/// * `BitwiseOr` - Takes the bitwise or of two integers.
+/// * `BitwiseXor` - Takes the bitwise exclusive or of two integers.
+/// * `ShiftLeft` - Shifts an integer left by a number of bits.
+/// * `ShiftRight` - Shifts an integer right by a number of bits, keeping its sign.
+/// * `Jump(usize)` - Jumps unconditionally.
+/// * `JumpIfFalse(usize)` - Jumps if the top of the stack is `false`, without popping it.
+/// * `JumpIfTrue(usize)` - Jumps if the top of the stack is `true`, without popping it.
+/// * `Step(usize)` - Adds the step to the variable of a range loop in the given slot, with the
+///   step two slots after it, and pushes whether the loop goes on, which it doesn't if that would
+///   overflow.
+/// * `Array(usize)` - Builds an array out of the given number of values on top of the stack.
+/// * `Index` - Pushes the element of an array at an index.
+/// * `SetIndex` - Stores the top of the stack in an element of an array, leaving it on the stack.
+/// * `Struct(usize)` - Builds an instance of a struct out of its name and the given number of
+///   field names and values on top of the stack.
+/// * `GetField(usize)` - Pushes the field of an instance, named by the given constant.
+/// * `SetField(usize)` - Stores the top of the stack in the field of an instance, named by the
+///   given constant, leaving it on the stack.
+/// * `Variant(usize)` - Builds a variant of an enum out of the names of the enum and the variant,
+///   and the given number of values on top of the stack.
+/// * `IsVariant(usize)` - Checks whether a variant is the one named by the given constant, such as
+///   `Shape::Circle`.
+/// * `Payload(usize)` - Pushes the value of a variant at the given position.
+/// * `Call(usize)` - Calls the function below the given number of arguments.
+/// * `Return` - Returns the top of the stack to the caller.
+/// * `NoMatch` - Fails because no arm of a match matched the value on top of the stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Constant(usize),
+    Pop,
+    GetLocal(usize),
+    SetLocal(usize),
+    GetGlobal(usize),
+    SetGlobal(usize),
+    ClearLocal(usize),
+    ClearGlobal(usize),
+    Negate,
+    Not,
+    ToFloat,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Power,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
+    Jump(usize),
+    JumpIfFalse(usize),
+    JumpIfTrue(usize),
+    Step(usize),
+    Array(usize),
+    Index,
+    SetIndex,
+    Struct(usize),
+    GetField(usize),
+    SetField(usize),
+    Variant(usize),
+    IsVariant(usize),
+    Payload(usize),
+    Call(usize),
+    Return,
+    NoMatch,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Constant(index) => write!(f, "{:<16}{index}", "CONSTANT"),
+            Self::Pop => write!(f, "POP"),
+            Self::GetLocal(slot) => write!(f, "{:<16}{slot}", "GET_LOCAL"),
+            Self::SetLocal(slot) => write!(f, "{:<16}{slot}", "SET_LOCAL"),
+            Self::GetGlobal(slot) => write!(f, "{:<16}{slot}", "GET_GLOBAL"),
+            Self::SetGlobal(slot) => write!(f, "{:<16}{slot}", "SET_GLOBAL"),
+            Self::ClearLocal(slot) => write!(f, "{:<16}{slot}", "CLEAR_LOCAL"),
+            Self::ClearGlobal(slot) => write!(f, "{:<16}{slot}", "CLEAR_GLOBAL"),
+            Self::Negate => write!(f, "NEGATE"),
+            Self::Not => write!(f, "NOT"),
+            Self::ToFloat => write!(f, "TO_FLOAT"),
+            Self::Add => write!(f, "ADD"),
+            Self::Subtract => write!(f, "SUBTRACT"),
+            Self::Multiply => write!(f, "MULTIPLY"),
+            Self::Divide => write!(f, "DIVIDE"),
+            Self::Modulo => write!(f, "MODULO"),
+            Self::Power => write!(f, "POWER"),
+            Self::Equal => write!(f, "EQUAL"),
+            Self::NotEqual => write!(f, "NOT_EQUAL"),
+            Self::Less => write!(f, "LESS"),
+            Self::LessEqual => write!(f, "LESS_EQUAL"),
+            Self::Greater => write!(f, "GREATER"),
+            Self::GreaterEqual => write!(f, "GREATER_EQUAL"),
+            Self::BitwiseAnd => write!(f, "BITWISE_AND"),
+            Self::BitwiseOr => write!(f, "BITWISE_OR"),
+            Self::BitwiseXor => write!(f, "BITWISE_XOR"),
+            Self::ShiftLeft => write!(f, "SHIFT_LEFT"),
+            Self::ShiftRight => write!(f, "SHIFT_RIGHT"),
+            Self::Jump(target) => write!(f, "{:<16}{target:04}", "JUMP"),
+            Self::JumpIfFalse(target) => write!(f, "{:<16}{target:04}", "JUMP_IF_FALSE"),
+            Self::JumpIfTrue(target) => write!(f, "{:<16}{target:04}", "JUMP_IF_TRUE"),
+            Self::Step(slot) => write!(f, "{:<16}{slot}", "STEP"),
+            Self::Array(elements) => write!(f, "{:<16}{elements}", "ARRAY"),
+            Self::Index => write!(f, "INDEX"),
+            Self::SetIndex => write!(f, "SET_INDEX"),
+            Self::Struct(fields) => write!(f, "{:<16}{fields}", "STRUCT"),
+            Self::GetField(index) => write!(f, "{:<16}{index}", "GET_FIELD"),
+            Self::SetField(index) => write!(f, "{:<16}{index}", "SET_FIELD"),
+            Self::Variant(values) => write!(f, "{:<16}{values}", "VARIANT"),
+            Self::IsVariant(index) => write!(f, "{:<16}{index}", "IS_VARIANT"),
+            Self::Payload(position) => write!(f, "{:<16}{position}", "PAYLOAD"),
+            Self::Call(arguments) => write!(f, "{:<16}{arguments}", "CALL"),
+            Self::Return => write!(f, "RETURN"),
+            Self::NoMatch => write!(f, "NO_MATCH"),
+        }
+    }
+}
+
+/// A chunk of bytecode.
+///
+/// # Fields
+///
+/// * `code` - The instructions.
+/// * `constants` - The constant pool.
+/// * `positions` - The line and column each instruction was compiled from.
+/// * `indices` - The index of each constant in the pool, to reuse it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Chunk {
+    pub code: Vec<Instruction>,
+    pub constants: Vec<Value>,
+    pub positions: Vec<(usize, usize)>,
+    indices: HashMap<Constant, usize>,
+}
+
+impl Chunk {
+    /// Appends an instruction to the chunk.
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - The instruction.
+    /// * `position` - The line and column the instruction was compiled from.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The offset of the instruction.
+    pub fn write(&mut self, instruction: Instruction, position: (usize, usize)) -> usize {
+        self.code.push(instruction);
+        self.positions.push(position);
+
+        self.code.len() - 1
+    }
+
+    /// Adds a constant to the constant pool, reusing an equal one if it's already there.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The constant.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The index of the constant.
+    pub fn add_constant(&mut self, value: Value) -> usize {
+        let key = Constant::of(&value);
+        if let Some(index) = key.as_ref().and_then(|key| self.indices.get(key)) {
+            return *index;
+        }
+
+        self.constants.push(value);
+        let index = self.constants.len() - 1;
+        if let Some(key) = key {
+            self.indices.insert(key, index);
+        }
+
+        index
+    }
+}
+
+/// The identity of a constant, which floats have by their bits so that `0.0` and `-0.0` aren't
+/// merged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Constant {
+    String(Rc<str>),
+    Integer(i64),
+    Float(u64),
+    Boolean(bool),
+    Function(usize),
+    Builtin(Builtin),
+    Unit,
+}
+
+impl Constant {
+    /// Gets the identity of a value, if it's one that can be shared.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Constant>` - The identity, or `None` for arrays, structs and enums.
+    fn of(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(string) => Some(Self::String(Rc::clone(string))),
+            Value::Number(Number::Integer(value)) => Some(Self::Integer(*value)),
+            Value::Number(Number::Float(value)) => Some(Self::Float(value.to_bits())),
+            Value::Boolean(boolean) => Some(Self::Boolean(*boolean)),
+            Value::Function(index) => Some(Self::Function(*index)),
+            Value::Builtin(builtin) => Some(Self::Builtin(*builtin)),
+            Value::Unit => Some(Self::Unit),
+            Value::Array(_) | Value::Struct(_) | Value::Enum(_) => None,
+        }
+    }
+}
+
+/// A compiled function.
+///
+/// # Fields
+///
+/// * `name` - The name of the function.
+/// * `arity` - The number of parameters, which occupy the first local slots.
+/// * `locals` - The number of local slots the function needs.
+/// * `chunk` - The bytecode of the function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub arity: usize,
+    pub locals: usize,
+    pub chunk: Chunk,
+}
+
+/// A compiled program.
+///
+/// # Fields
+///
+/// * `script` - The top-level code.
+/// * `functions` - The functions, by id.
+/// * `globals` - The number of global slots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub script: Function,
+    pub functions: Vec<Function>,
+    pub globals: usize,
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "globals: {}", self.globals)?;
+
+        for function in std::iter::once(&self.script).chain(&self.functions) {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "== {} (arity: {}, locals: {}) ==",
+                function.name, function.arity, function.locals
+            )?;
+
+            let chunk = &function.chunk;
+            for (offset, (instruction, (line, column))) in
+                chunk.code.iter().zip(&chunk.positions).enumerate()
+            {
+                write!(f, "{offset:04} {line:>4}:{column:<4} {instruction}")?;
+
+                if let Instruction::Constant(index)
+                | Instruction::GetField(index)
+                | Instruction::SetField(index)
+                | Instruction::IsVariant(index) = instruction
+                {
+                    match chunk.constants.get(*index) {
+                        Some(Value::String(string)) => write!(f, " ({string:?})")?,
+                        Some(Value::Function(id)) => {
+                            let name = self.functions.get(*id).map_or("?", |f| &f.name);
+
+                            write!(f, " (<fn {name}>)")?;
+                        }
+                        Some(constant) => write!(f, " ({constant})")?,
+                        None => write!(f, " (?)")?,
+                    }
+                }
+
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
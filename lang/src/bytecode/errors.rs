@@ -0,0 +1,78 @@
+use thiserror::Error;
+
+/// A bytecode error, raised either while compiling or while running a program.
+///
+/// # Variants
+///
+/// * `UnresolvedSymbol` - A name wasn't resolved by the semantic analyzer.
+/// * `MissingFunction` - A function was referenced but never compiled.
+/// * `UnsupportedOperator` - An operator has no instruction.
+/// * `UninitializedVariable` - A variable was read before it was assigned a value.
+/// * `InvalidOperands` - An operator was applied to values of the wrong type.
+/// * `DivisionByZero` - A number was divided by zero.
+/// * `NotCallable` - A value which isn't a function was called.
+/// * `ArityMismatch` - A function was called with the wrong number of arguments.
+/// * `StackOverflow` - The maximum call depth was exceeded.
+/// * `StackUnderflow` - An instruction needed more values than were on the stack.
+/// * `IndexOutOfBounds` - An array was indexed past its end, or with a negative index.
+/// * `IoError` - Writing the program output failed.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("The symbol '{name}' wasn't resolved at line {line} and column {column}!")]
+    UnresolvedSymbol {
+        name: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("The function with id {id} was never compiled!")]
+    MissingFunction { id: usize },
+    #[error("The operator '{operator}' isn't supported at line {line} and column {column}!")]
+    UnsupportedOperator {
+        operator: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("A variable hasn't been initialized at line {line} and column {column}!")]
+    UninitializedVariable { line: usize, column: usize },
+    #[error("Invalid operands for '{operator}' at line {line} and column {column}: {message}")]
+    InvalidOperands {
+        operator: String,
+        message: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("Division by zero at line {line} and column {column}!")]
+    DivisionByZero { line: usize, column: usize },
+    #[error("Only functions can be called, found '{value}' at line {line} and column {column}!")]
+    NotCallable {
+        value: String,
+        line: usize,
+        column: usize,
+    },
+    #[error(
+        "Expected {expected} argument(s) but found {found} at line {line} and column {column}!"
+    )]
+    ArityMismatch {
+        expected: usize,
+        found: usize,
+        line: usize,
+        column: usize,
+    },
+    #[error("Stack overflow in '{name}' at line {line} and column {column}!")]
+    StackOverflow {
+        name: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("Stack underflow!")]
+    StackUnderflow,
+    #[error("The index {index} is out of bounds for an array of length {length} at line {line} and column {column}!")]
+    IndexOutOfBounds {
+        index: i64,
+        length: usize,
+        line: usize,
+        column: usize,
+    },
+    #[error("I/O error!")]
+    IoError(#[from] std::io::Error),
+}
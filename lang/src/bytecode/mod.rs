@@ -0,0 +1,1107 @@
+use crate::bytecode::chunk::{Chunk, Function, Instruction, Program};
+use crate::bytecode::errors::Error;
+use crate::bytecode::values::Value;
+use crate::lexer::tokens::{Token, TokenKind};
+use crate::parser::ast::AST;
+use crate::parser::{Arm, Expression, Literal, Pattern, Statement, TypeAnnotation};
+use crate::semantics::numbers::{self, Number};
+use crate::semantics::symbols::Resolution;
+use crate::semantics::types::Type;
+use crate::semantics::SemanticAnalyzer;
+use std::rc::Rc;
+
+pub mod chunk;
+pub mod errors;
+pub mod values;
+pub mod vm;
+
+/// The jumps of a loop which is being compiled.
+///
+/// # Fields
+///
+/// * `label` - The label of the loop, if it has one.
+/// * `breaks` - The offsets of the jumps made by `break` statements.
+/// * `continues` - The offsets of the jumps made by `continue` statements.
+#[derive(Debug, Default)]
+struct Loop {
+    label: Option<TokenKind>,
+    breaks: Vec<usize>,
+    continues: Vec<usize>,
+}
+
+/// A compiler which lowers the AST into bytecode.
+///
+/// # Fields
+///
+/// * `ast` - The AST to compile.
+/// * `semantics` - The semantic analyzer which has analyzed the AST.
+/// * `functions` - The compiled functions, by id.
+/// * `chunk` - The chunk currently being compiled.
+/// * `loops` - The loops currently being compiled, innermost last.
+/// * `return_type` - The declared return type of the function currently being compiled.
+/// * `position` - The line and column of the last token compiled.
+#[derive(Debug)]
+pub struct Compiler<'a> {
+    ast: &'a AST,
+    semantics: &'a SemanticAnalyzer<'a>,
+    functions: Vec<Option<Function>>,
+    chunk: Chunk,
+    loops: Vec<Loop>,
+    return_type: Type,
+    position: (usize, usize),
+}
+
+impl<'a> Compiler<'a> {
+    /// Creates a new compiler.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - The AST to compile.
+    /// * `semantics` - The semantic analyzer which has analyzed the AST.
+    ///
+    /// # Returns
+    ///
+    /// * `Compiler<'a>` - The new compiler.
+    #[must_use]
+    pub fn new(ast: &'a AST, semantics: &'a SemanticAnalyzer<'a>) -> Self {
+        Self {
+            ast,
+            semantics,
+            functions: Vec::new(),
+            chunk: Chunk::default(),
+            loops: Vec::new(),
+            return_type: Type::Unit,
+            position: (0, 0),
+        }
+    }
+
+    /// Compiles the AST into a program.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Program, Error>` - The compiled program, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * If a name wasn't resolved by the semantic analyzer.
+    /// * If a function uses a local variable of an enclosing function.
+    pub fn compile(&mut self) -> Result<Program, Error> {
+        for statement in &self.ast.statements {
+            self.compile_statement(statement)?;
+        }
+
+        self.emit_return();
+
+        let script = Function {
+            name: "<script>".to_string(),
+            arity: 0,
+            locals: self.semantics.script_locals(),
+            chunk: std::mem::take(&mut self.chunk),
+        };
+        let functions = std::mem::take(&mut self.functions)
+            .into_iter()
+            .enumerate()
+            .map(|(id, function)| function.ok_or(Error::MissingFunction { id }))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Program {
+            script,
+            functions,
+            globals: self.semantics.globals(),
+        })
+    }
+
+    fn compile_statement(&mut self, statement: &Statement) -> Result<(), Error> {
+        match statement {
+            Statement::Expression { expression, .. } => {
+                self.compile_expression(expression)?;
+                self.emit(Instruction::Pop);
+            }
+            Statement::Variable {
+                name, initializer, ..
+            } => self.compile_let(name, initializer.as_ref())?,
+            Statement::Block { statements, .. } => {
+                for statement in statements {
+                    self.compile_statement(statement)?;
+                }
+            }
+            Statement::If {
+                condition,
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                self.compile_expression(condition)?;
+
+                let then_jump = self.emit(Instruction::JumpIfFalse(0));
+                self.emit(Instruction::Pop);
+                self.compile_statement(then_branch)?;
+
+                let else_jump = self.emit(Instruction::Jump(0));
+                self.patch(then_jump);
+                self.emit(Instruction::Pop);
+
+                if let Some(else_branch) = else_branch {
+                    self.compile_statement(else_branch)?;
+                }
+
+                self.patch(else_jump);
+            }
+            Statement::While {
+                label,
+                condition,
+                body,
+                ..
+            } => self.compile_while(label.as_ref(), condition, body)?,
+            Statement::For {
+                label,
+                initializer,
+                condition,
+                increment,
+                body,
+                ..
+            } => self.compile_for(
+                label.as_ref(),
+                initializer.as_deref(),
+                condition.as_ref(),
+                increment.as_ref(),
+                body,
+            )?,
+            Statement::ForRange {
+                label,
+                variable,
+                start,
+                end,
+                step,
+                body,
+                ..
+            } => {
+                self.compile_for_range(
+                    label.as_ref(),
+                    variable,
+                    start,
+                    end,
+                    step.as_deref(),
+                    body,
+                )?;
+            }
+            Statement::Break { label, .. } => {
+                let jump = self.emit(Instruction::Jump(0));
+                if let Some(target) = self.target(label.as_ref()) {
+                    target.breaks.push(jump);
+                }
+            }
+            Statement::Continue { label, .. } => {
+                let jump = self.emit(Instruction::Jump(0));
+                if let Some(target) = self.target(label.as_ref()) {
+                    target.continues.push(jump);
+                }
+            }
+            Statement::Return { keyword, value, .. } => {
+                self.compile_return(keyword, value.as_ref())?;
+            }
+            Statement::Function {
+                name,
+                parameters,
+                return_type,
+                body,
+                ..
+            } => self.compile_function(name, parameters, return_type.as_ref(), body)?,
+            // Structs and enums are only types, so there's nothing to run.
+            Statement::Struct { .. } | Statement::Enum { .. } => {}
+        }
+
+        Ok(())
+    }
+
+    /// Compiles a return, widening the value if the function returns a float.
+    fn compile_return(&mut self, keyword: &Token, value: Option<&Expression>) -> Result<(), Error> {
+        self.position = (keyword.line, keyword.column);
+
+        match value {
+            Some(value) => self.compile_expression(value)?,
+            None => self.emit_constant(Value::Unit),
+        }
+        if self.return_type.widens_integers() {
+            self.emit(Instruction::ToFloat);
+        }
+        self.emit(Instruction::Return);
+
+        Ok(())
+    }
+
+    fn compile_while(
+        &mut self,
+        label: Option<&Token>,
+        condition: &Expression,
+        body: &Statement,
+    ) -> Result<(), Error> {
+        let start = self.chunk.code.len();
+        self.compile_expression(condition)?;
+
+        let exit_jump = self.emit(Instruction::JumpIfFalse(0));
+        self.emit(Instruction::Pop);
+
+        self.begin_loop(label);
+        self.compile_statement(body)?;
+        self.emit(Instruction::Jump(start));
+
+        self.patch(exit_jump);
+        self.emit(Instruction::Pop);
+        self.end_loop(start);
+
+        Ok(())
+    }
+
+    fn compile_for(
+        &mut self,
+        label: Option<&Token>,
+        initializer: Option<&Statement>,
+        condition: Option<&Expression>,
+        increment: Option<&Expression>,
+        body: &Statement,
+    ) -> Result<(), Error> {
+        if let Some(initializer) = initializer {
+            self.compile_statement(initializer)?;
+        }
+
+        let start = self.chunk.code.len();
+        let exit_jump = match condition {
+            Some(condition) => {
+                self.compile_expression(condition)?;
+
+                let exit_jump = self.emit(Instruction::JumpIfFalse(0));
+                self.emit(Instruction::Pop);
+
+                Some(exit_jump)
+            }
+            None => None,
+        };
+
+        self.begin_loop(label);
+        self.compile_statement(body)?;
+
+        let continue_target = self.chunk.code.len();
+        if let Some(increment) = increment {
+            self.compile_expression(increment)?;
+            self.emit(Instruction::Pop);
+        }
+        self.emit(Instruction::Jump(start));
+
+        if let Some(exit_jump) = exit_jump {
+            self.patch(exit_jump);
+            self.emit(Instruction::Pop);
+        }
+        self.end_loop(continue_target);
+
+        Ok(())
+    }
+
+    /// Compiles a range loop, which keeps the end and the step in the two slots after its
+    /// variable.
+    fn compile_for_range(
+        &mut self,
+        label: Option<&Token>,
+        variable: &Token,
+        start: &Expression,
+        end: &Expression,
+        step: Option<&Expression>,
+        body: &Statement,
+    ) -> Result<(), Error> {
+        let Resolution::Local(slot) = self.resolve(variable)? else {
+            return Err(unresolved(variable));
+        };
+        let (end_slot, step_slot) = (slot + 1, slot + 2);
+
+        self.compile_expression(start)?;
+        if self.semantics.variable_type(variable) == Some(&Type::Float) {
+            self.emit(Instruction::ToFloat);
+        }
+        self.emit(Instruction::SetLocal(slot));
+        self.emit(Instruction::Pop);
+
+        self.compile_expression(end)?;
+        self.emit(Instruction::SetLocal(end_slot));
+        self.emit(Instruction::Pop);
+
+        match step {
+            Some(step) => self.compile_expression(step)?,
+            None => self.emit_constant(Value::Number(Number::Integer(1))),
+        }
+        self.emit(Instruction::SetLocal(step_slot));
+        self.emit(Instruction::Pop);
+
+        // Count up to the end for a positive step, and down to it for a negative one. A loop with
+        // a zero step never runs.
+        let start = self.chunk.code.len();
+        self.emit(Instruction::GetLocal(step_slot));
+        self.emit_constant(Value::Number(Number::Integer(0)));
+        self.emit(Instruction::Greater);
+        let down_jump = self.emit(Instruction::JumpIfFalse(0));
+        self.emit(Instruction::Pop);
+        self.emit(Instruction::GetLocal(slot));
+        self.emit(Instruction::GetLocal(end_slot));
+        self.emit(Instruction::LessEqual);
+        let up_jump = self.emit(Instruction::Jump(0));
+
+        self.patch(down_jump);
+        self.emit(Instruction::Pop);
+        self.emit(Instruction::GetLocal(step_slot));
+        self.emit_constant(Value::Number(Number::Integer(0)));
+        self.emit(Instruction::Less);
+        let zero_jump = self.emit(Instruction::JumpIfFalse(0));
+        self.emit(Instruction::Pop);
+        self.emit(Instruction::GetLocal(slot));
+        self.emit(Instruction::GetLocal(end_slot));
+        self.emit(Instruction::GreaterEqual);
+
+        self.patch(up_jump);
+        self.patch(zero_jump);
+        let exit_jump = self.emit(Instruction::JumpIfFalse(0));
+        self.emit(Instruction::Pop);
+
+        self.begin_loop(label);
+        self.compile_statement(body)?;
+
+        // A step which overflows goes past the end, so it finishes the loop.
+        let continue_target = self.chunk.code.len();
+        self.position = (variable.line, variable.column);
+        self.emit(Instruction::Step(slot));
+        let overflow_jump = self.emit(Instruction::JumpIfFalse(0));
+        self.emit(Instruction::Pop);
+        self.emit(Instruction::Jump(start));
+
+        self.patch(exit_jump);
+        self.patch(overflow_jump);
+        self.emit(Instruction::Pop);
+        self.end_loop(continue_target);
+
+        Ok(())
+    }
+
+    fn compile_function(
+        &mut self,
+        name: &Token,
+        parameters: &[(Token, TypeAnnotation)],
+        return_type: Option<&TypeAnnotation>,
+        body: &Statement,
+    ) -> Result<(), Error> {
+        let Resolution::Function(id) = self.resolve(name)? else {
+            return Err(unresolved(name));
+        };
+        let arity = parameters.len();
+
+        // Compile the body into its own chunk, with its own loops.
+        let chunk = std::mem::take(&mut self.chunk);
+        let loops = std::mem::take(&mut self.loops);
+        let return_type = std::mem::replace(
+            &mut self.return_type,
+            return_type.map_or(Type::Unit, |r#type| {
+                Type::from_annotation(r#type).unwrap_or(Type::Unknown)
+            }),
+        );
+
+        // Integer arguments are widened where the parameters are floats.
+        for (slot, (_, r#type)) in parameters.iter().enumerate() {
+            if Type::from_annotation(r#type).is_ok_and(|r#type| r#type.widens_integers()) {
+                self.emit(Instruction::GetLocal(slot));
+                self.emit(Instruction::ToFloat);
+                self.emit(Instruction::SetLocal(slot));
+                self.emit(Instruction::Pop);
+            }
+        }
+
+        let result = self.compile_statement(body);
+        self.emit_return();
+
+        let body = std::mem::replace(&mut self.chunk, chunk);
+        self.loops = loops;
+        self.return_type = return_type;
+        result?;
+
+        if self.functions.len() <= id {
+            self.functions.resize(id + 1, None);
+        }
+        self.functions[id] = Some(Function {
+            name: name.token_kind.to_string(),
+            arity,
+            locals: self.semantics.function_locals(id).unwrap_or(arity),
+            chunk: body,
+        });
+
+        Ok(())
+    }
+
+    /// Compiles a variable declaration, widening its initial value if the variable is a float.
+    fn compile_let(&mut self, name: &Token, initializer: Option<&Expression>) -> Result<(), Error> {
+        // A declaration without a value clears the slot, which may hold a value from an earlier
+        // iteration of a loop.
+        let Some(initializer) = initializer else {
+            let instruction = match self.resolve(name)? {
+                Resolution::Global(slot) => Instruction::ClearGlobal(slot),
+                Resolution::Local(slot) => Instruction::ClearLocal(slot),
+                _ => return Err(unresolved(name)),
+            };
+            self.emit(instruction);
+
+            return Ok(());
+        };
+
+        self.compile_expression(initializer)?;
+        if self
+            .semantics
+            .variable_type(name)
+            .is_some_and(Type::widens_integers)
+        {
+            self.emit(Instruction::ToFloat);
+        }
+
+        let instruction = match self.resolve(name)? {
+            Resolution::Global(slot) => Instruction::SetGlobal(slot),
+            Resolution::Local(slot) => Instruction::SetLocal(slot),
+            _ => return Err(unresolved(name)),
+        };
+        self.emit(instruction);
+        self.emit(Instruction::Pop);
+
+        Ok(())
+    }
+
+    fn compile_expression(&mut self, expression: &Expression) -> Result<(), Error> {
+        // Expressions made of literals only are evaluated at compile time.
+        if let Some(literal) = fold(expression) {
+            self.emit_constant(Value::from(&literal));
+
+            return Ok(());
+        }
+
+        match expression {
+            Expression::Literal { value: literal, .. } => self.emit_constant(Value::from(literal)),
+            Expression::Grouping { expression, .. } => self.compile_expression(expression)?,
+            Expression::Variable { name, .. } => self.compile_get(name)?,
+            Expression::Assignment { name, value, .. } => {
+                self.compile_expression(value)?;
+                self.compile_set(name)?;
+            }
+            Expression::CompoundAssignment {
+                name,
+                operator,
+                value,
+                ..
+            } => {
+                self.compile_get(name)?;
+                self.compile_expression(value)?;
+
+                self.position = (operator.line, operator.column);
+                let kind = operator
+                    .token_kind
+                    .compound_operator()
+                    .ok_or_else(|| unsupported_operator(operator))?;
+                self.emit(binary_instruction(&kind, operator)?);
+
+                self.compile_set(name)?;
+            }
+            Expression::Update {
+                name,
+                operator,
+                is_prefix,
+                ..
+            } => self.compile_update(name, operator, *is_prefix)?,
+            Expression::Unary {
+                operator, right, ..
+            } => {
+                self.compile_expression(right)?;
+
+                self.position = (operator.line, operator.column);
+                match operator.token_kind {
+                    TokenKind::LogicalNot => self.emit(Instruction::Not),
+                    _ => self.emit(Instruction::Negate),
+                };
+            }
+            Expression::Binary {
+                left,
+                operator,
+                right,
+                ..
+            } => self.compile_binary(left, operator, right)?,
+            Expression::Call {
+                callee, arguments, ..
+            } => self.compile_call(callee, arguments)?,
+            Expression::Array { elements, .. } => {
+                for element in elements {
+                    self.compile_expression(element)?;
+                }
+
+                self.emit(Instruction::Array(elements.len()));
+            }
+            Expression::Index {
+                array,
+                bracket,
+                index,
+                ..
+            } => self.compile_index(array, bracket, index, None)?,
+            Expression::IndexAssignment {
+                array,
+                bracket,
+                index,
+                value,
+                ..
+            } => self.compile_index(array, bracket, index, Some(value))?,
+            Expression::Struct { name, fields, .. } => self.compile_struct(name, fields)?,
+            Expression::Field { object, name, .. } => self.compile_field(object, name, None)?,
+            Expression::FieldAssignment {
+                object,
+                name,
+                value,
+                ..
+            } => self.compile_field(object, name, Some(value))?,
+            Expression::Variant {
+                enumeration,
+                variant,
+                arguments,
+                ..
+            } => self.compile_variant(enumeration, variant, arguments)?,
+            Expression::Match {
+                keyword,
+                scrutinee,
+                arms,
+                ..
+            } => self.compile_match(keyword, scrutinee, arms)?,
+            Expression::MethodCall {
+                receiver,
+                name,
+                arguments,
+                ..
+            } => self.compile_method_call(receiver, name, arguments)?,
+        }
+
+        Ok(())
+    }
+
+    fn compile_call(&mut self, callee: &Expression, arguments: &[Expression]) -> Result<(), Error> {
+        self.compile_expression(callee)?;
+        let position = self.position;
+
+        for argument in arguments {
+            self.compile_expression(argument)?;
+        }
+
+        self.position = position;
+        self.emit(Instruction::Call(arguments.len()));
+
+        Ok(())
+    }
+
+    /// Compiles a method call as a call of the function with the name of the method, with the
+    /// receiver as its first argument.
+    fn compile_method_call(
+        &mut self,
+        receiver: &Expression,
+        name: &Token,
+        arguments: &[Expression],
+    ) -> Result<(), Error> {
+        self.position = (name.line, name.column);
+        self.compile_get(name)?;
+        self.compile_expression(receiver)?;
+
+        for argument in arguments {
+            self.compile_expression(argument)?;
+        }
+
+        self.position = (name.line, name.column);
+        self.emit(Instruction::Call(arguments.len() + 1));
+
+        Ok(())
+    }
+
+    fn compile_update(
+        &mut self,
+        name: &Token,
+        operator: &Token,
+        is_prefix: bool,
+    ) -> Result<(), Error> {
+        // A postfix update leaves the old value below the new one, which is discarded once it's
+        // stored.
+        self.compile_get(name)?;
+        if !is_prefix {
+            self.compile_get(name)?;
+        }
+
+        self.emit_constant(Value::Number(Number::Integer(1)));
+        self.position = (operator.line, operator.column);
+        self.emit(if operator.token_kind == TokenKind::Increment {
+            Instruction::Add
+        } else {
+            Instruction::Subtract
+        });
+        self.compile_set(name)?;
+
+        if !is_prefix {
+            self.emit(Instruction::Pop);
+        }
+
+        Ok(())
+    }
+
+    fn compile_binary(
+        &mut self,
+        left: &Expression,
+        operator: &Token,
+        right: &Expression,
+    ) -> Result<(), Error> {
+        self.compile_expression(left)?;
+
+        // The logical operators short-circuit, leaving the left side on the stack if it decides
+        // the result.
+        if matches!(
+            operator.token_kind,
+            TokenKind::LogicalAnd | TokenKind::LogicalOr
+        ) {
+            self.position = (operator.line, operator.column);
+            let jump = self.emit(if operator.token_kind == TokenKind::LogicalAnd {
+                Instruction::JumpIfFalse(0)
+            } else {
+                Instruction::JumpIfTrue(0)
+            });
+            self.emit(Instruction::Pop);
+            self.compile_expression(right)?;
+            self.patch(jump);
+
+            return Ok(());
+        }
+
+        self.compile_expression(right)?;
+
+        self.position = (operator.line, operator.column);
+        self.emit(binary_instruction(&operator.token_kind, operator)?);
+
+        Ok(())
+    }
+
+    /// Compiles a read of an element of an array, or an assignment to it if there's a value.
+    fn compile_index(
+        &mut self,
+        array: &Expression,
+        bracket: &Token,
+        index: &Expression,
+        value: Option<&Expression>,
+    ) -> Result<(), Error> {
+        self.compile_expression(array)?;
+        self.compile_expression(index)?;
+        if let Some(value) = value {
+            self.compile_expression(value)?;
+        }
+
+        self.position = (bracket.line, bracket.column);
+        self.emit(if value.is_some() {
+            Instruction::SetIndex
+        } else {
+            Instruction::Index
+        });
+
+        Ok(())
+    }
+
+    /// Compiles a struct literal, widening the values given to float fields.
+    fn compile_struct(
+        &mut self,
+        name: &Token,
+        fields: &[(Token, Expression)],
+    ) -> Result<(), Error> {
+        self.position = (name.line, name.column);
+        self.emit_constant(Value::String(Rc::from(name.token_kind.to_string())));
+
+        for (field, value) in fields {
+            self.position = (field.line, field.column);
+            self.emit_constant(Value::String(Rc::from(field.token_kind.to_string())));
+            self.compile_expression(value)?;
+
+            if self
+                .semantics
+                .field_type(field)
+                .is_some_and(Type::widens_integers)
+            {
+                self.emit(Instruction::ToFloat);
+            }
+        }
+
+        self.position = (name.line, name.column);
+        self.emit(Instruction::Struct(fields.len()));
+
+        Ok(())
+    }
+
+    /// Compiles a read of a field of an instance, or an assignment to it if there's a value.
+    fn compile_field(
+        &mut self,
+        object: &Expression,
+        name: &Token,
+        value: Option<&Expression>,
+    ) -> Result<(), Error> {
+        self.compile_expression(object)?;
+        if let Some(value) = value {
+            self.compile_expression(value)?;
+
+            // Integers are widened by the virtual machine where the field already holds a
+            // float, but an array literal has to be widened here.
+            if self
+                .semantics
+                .field_type(name)
+                .is_some_and(Type::widens_integers)
+            {
+                self.emit(Instruction::ToFloat);
+            }
+        }
+
+        self.position = (name.line, name.column);
+        let index = self
+            .chunk
+            .add_constant(Value::String(Rc::from(name.token_kind.to_string())));
+        self.emit(if value.is_some() {
+            Instruction::SetField(index)
+        } else {
+            Instruction::GetField(index)
+        });
+
+        Ok(())
+    }
+
+    /// Compiles a variant of an enum, widening its values where it holds floats.
+    fn compile_variant(
+        &mut self,
+        enumeration: &Token,
+        variant: &Token,
+        arguments: &[Expression],
+    ) -> Result<(), Error> {
+        self.position = (enumeration.line, enumeration.column);
+        self.emit_constant(Value::String(Rc::from(enumeration.token_kind.to_string())));
+        self.emit_constant(Value::String(Rc::from(variant.token_kind.to_string())));
+
+        for (index, argument) in arguments.iter().enumerate() {
+            self.compile_expression(argument)?;
+
+            let payload = self.semantics.payload_types(variant).unwrap_or_default();
+            if payload.get(index).is_some_and(Type::widens_integers) {
+                self.emit(Instruction::ToFloat);
+            }
+        }
+
+        self.position = (variant.line, variant.column);
+        self.emit(Instruction::Variant(arguments.len()));
+
+        Ok(())
+    }
+
+    /// Compiles a match, which keeps its value in a slot of its own and tries each arm in turn,
+    /// jumping past the rest once one matches.
+    fn compile_match(
+        &mut self,
+        keyword: &Token,
+        scrutinee: &Expression,
+        arms: &[Arm],
+    ) -> Result<(), Error> {
+        self.compile_expression(scrutinee)?;
+        let Resolution::Local(slot) = self.resolve(keyword)? else {
+            return Err(unresolved(keyword));
+        };
+        self.emit(Instruction::SetLocal(slot));
+        self.emit(Instruction::Pop);
+
+        let mut exits = Vec::with_capacity(arms.len());
+        for arm in arms {
+            // Every pattern but a wildcard leaves whether it matched on the stack.
+            let next = match &arm.pattern {
+                Pattern::Wildcard { .. } => None,
+                Pattern::Literal { value, .. } => {
+                    self.emit(Instruction::GetLocal(slot));
+                    self.emit_constant(Value::from(value));
+                    self.emit(Instruction::Equal);
+
+                    let next = self.emit(Instruction::JumpIfFalse(0));
+                    self.emit(Instruction::Pop);
+
+                    Some(next)
+                }
+                Pattern::Variant {
+                    enumeration,
+                    variant,
+                    bindings,
+                    ..
+                } => {
+                    self.position = (variant.line, variant.column);
+                    self.emit(Instruction::GetLocal(slot));
+                    let name = self.chunk.add_constant(Value::String(Rc::from(format!(
+                        "{}::{}",
+                        enumeration.token_kind, variant.token_kind
+                    ))));
+                    self.emit(Instruction::IsVariant(name));
+
+                    let next = self.emit(Instruction::JumpIfFalse(0));
+                    self.emit(Instruction::Pop);
+
+                    for (position, binding) in bindings.iter().enumerate() {
+                        self.emit(Instruction::GetLocal(slot));
+                        self.emit(Instruction::Payload(position));
+                        self.compile_set(binding)?;
+                        self.emit(Instruction::Pop);
+                    }
+
+                    Some(next)
+                }
+            };
+
+            self.compile_expression(&arm.body)?;
+            exits.push(self.emit(Instruction::Jump(0)));
+
+            if let Some(next) = next {
+                self.patch(next);
+                self.emit(Instruction::Pop);
+            }
+        }
+
+        // Matches are checked to be exhaustive, but a value which slips past the checker still
+        // fails here rather than producing nothing.
+        self.position = (keyword.line, keyword.column);
+        self.emit(Instruction::GetLocal(slot));
+        self.emit(Instruction::NoMatch);
+
+        for exit in exits {
+            self.patch(exit);
+        }
+
+        Ok(())
+    }
+
+    fn compile_get(&mut self, name: &Token) -> Result<(), Error> {
+        let instruction = match self.resolve(name)? {
+            Resolution::Local(slot) => Instruction::GetLocal(slot),
+            Resolution::Global(slot) => Instruction::GetGlobal(slot),
+            Resolution::Function(id) => {
+                Instruction::Constant(self.chunk.add_constant(Value::Function(id)))
+            }
+            Resolution::Builtin(builtin) => {
+                Instruction::Constant(self.chunk.add_constant(Value::Builtin(builtin)))
+            }
+            Resolution::Struct | Resolution::Enum => return Err(unresolved(name)),
+        };
+        self.emit(instruction);
+
+        Ok(())
+    }
+
+    fn compile_set(&mut self, name: &Token) -> Result<(), Error> {
+        // A float variable stays a float when it's assigned an integer, and so do the elements of
+        // an array of floats.
+        if self
+            .semantics
+            .variable_type(name)
+            .is_some_and(Type::widens_integers)
+        {
+            self.emit(Instruction::ToFloat);
+        }
+
+        let instruction = match self.resolve(name)? {
+            Resolution::Local(slot) => Instruction::SetLocal(slot),
+            Resolution::Global(slot) => Instruction::SetGlobal(slot),
+            Resolution::Function(_)
+            | Resolution::Builtin(_)
+            | Resolution::Struct
+            | Resolution::Enum => return Err(unresolved(name)),
+        };
+        self.emit(instruction);
+
+        Ok(())
+    }
+
+    fn resolve(&mut self, name: &Token) -> Result<Resolution, Error> {
+        self.position = (name.line, name.column);
+
+        self.semantics
+            .resolution(name)
+            .ok_or_else(|| unresolved(name))
+    }
+
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        self.chunk.write(instruction, self.position)
+    }
+
+    fn emit_constant(&mut self, value: Value) {
+        let index = self.chunk.add_constant(value);
+
+        self.emit(Instruction::Constant(index));
+    }
+
+    fn emit_return(&mut self) {
+        self.emit_constant(Value::Unit);
+        self.emit(Instruction::Return);
+    }
+
+    /// Points a previously emitted jump at the next instruction.
+    fn patch(&mut self, jump: usize) {
+        let target = self.chunk.code.len();
+
+        match &mut self.chunk.code[jump] {
+            Instruction::Jump(offset)
+            | Instruction::JumpIfFalse(offset)
+            | Instruction::JumpIfTrue(offset) => *offset = target,
+            _ => {}
+        }
+    }
+
+    fn begin_loop(&mut self, label: Option<&Token>) {
+        self.loops.push(Loop {
+            label: label.map(|label| label.token_kind.clone()),
+            ..Loop::default()
+        });
+    }
+
+    /// Finds the loop a `break` or `continue` jumps out of, which is the innermost one unless it
+    /// names another.
+    fn target(&mut self, label: Option<&Token>) -> Option<&mut Loop> {
+        let Some(label) = label else {
+            return self.loops.last_mut();
+        };
+
+        self.loops
+            .iter_mut()
+            .rev()
+            .find(|current| current.label.as_ref() == Some(&label.token_kind))
+    }
+
+    fn end_loop(&mut self, continue_target: usize) {
+        let Some(current) = self.loops.pop() else {
+            return;
+        };
+
+        for jump in current.breaks {
+            self.patch(jump);
+        }
+
+        for jump in current.continues {
+            if let Instruction::Jump(offset) = &mut self.chunk.code[jump] {
+                *offset = continue_target;
+            }
+        }
+    }
+}
+
+/// Gets the instruction for a binary operator which isn't short-circuiting.
+fn binary_instruction(kind: &TokenKind, operator: &Token) -> Result<Instruction, Error> {
+    Ok(match kind {
+        TokenKind::Plus => Instruction::Add,
+        TokenKind::Minus => Instruction::Subtract,
+        TokenKind::Star => Instruction::Multiply,
+        TokenKind::Slash => Instruction::Divide,
+        TokenKind::Percent => Instruction::Modulo,
+        TokenKind::Power => Instruction::Power,
+        TokenKind::Equality => Instruction::Equal,
+        TokenKind::NotEqual => Instruction::NotEqual,
+        TokenKind::LessThan => Instruction::Less,
+        TokenKind::LessThanOrEqual => Instruction::LessEqual,
+        TokenKind::GreaterThan => Instruction::Greater,
+        TokenKind::GreaterThanOrEqual => Instruction::GreaterEqual,
+        TokenKind::BitwiseAnd => Instruction::BitwiseAnd,
+        TokenKind::BitwiseOr => Instruction::BitwiseOr,
+        TokenKind::BitwiseXor => Instruction::BitwiseXor,
+        TokenKind::BitwiseLeftShift => Instruction::ShiftLeft,
+        TokenKind::BitwiseRightShift => Instruction::ShiftRight,
+        _ => return Err(unsupported_operator(operator)),
+    })
+}
+
+fn unresolved(name: &Token) -> Error {
+    Error::UnresolvedSymbol {
+        name: name.token_kind.to_string(),
+        line: name.line,
+        column: name.column,
+    }
+}
+
+fn unsupported_operator(operator: &Token) -> Error {
+    Error::UnsupportedOperator {
+        operator: operator.token_kind.to_string(),
+        line: operator.line,
+        column: operator.column,
+    }
+}
+
+/// Folds an expression made of literals only into a single literal.
+///
+/// # Arguments
+///
+/// * `expression` - The expression to fold.
+///
+/// # Returns
+///
+/// * `Option<Literal>` - The folded literal, or `None` if the expression can't be folded.
+#[must_use]
+pub fn fold(expression: &Expression) -> Option<Literal> {
+    match expression {
+        Expression::Literal { value: literal, .. } => Some(literal.clone()),
+        Expression::Grouping { expression, .. } => fold(expression),
+        Expression::Unary {
+            operator, right, ..
+        } => match (&operator.token_kind, fold(right)?) {
+            (TokenKind::Minus, literal) => number(&literal)?.negate().ok().map(Literal::from),
+            (TokenKind::LogicalNot, Literal::Boolean(value)) => Some(Literal::Boolean(!value)),
+            _ => None,
+        },
+        Expression::Binary {
+            left,
+            operator,
+            right,
+            ..
+        } => {
+            let kind = &operator.token_kind;
+
+            Some(match (fold(left)?, fold(right)?) {
+                // Errors such as a division by zero are left to the virtual machine to report.
+                (left, right) if number(&left).is_some() => {
+                    let (left, right) = (number(&left)?, number(&right)?);
+
+                    match numbers::compare(kind, left, right) {
+                        Some(result) => Literal::Boolean(result),
+                        None => Literal::from(numbers::arithmetic(kind, left, right).ok()?),
+                    }
+                }
+                (Literal::String(left), Literal::String(right)) => match kind {
+                    TokenKind::Plus => Literal::String(left + &right),
+                    TokenKind::Equality => Literal::Boolean(left == right),
+                    TokenKind::NotEqual => Literal::Boolean(left != right),
+                    _ => return None,
+                },
+                (Literal::Boolean(left), Literal::Boolean(right)) => match kind {
+                    TokenKind::Equality => Literal::Boolean(left == right),
+                    TokenKind::NotEqual => Literal::Boolean(left != right),
+                    TokenKind::LogicalAnd => Literal::Boolean(left && right),
+                    TokenKind::LogicalOr => Literal::Boolean(left || right),
+                    _ => return None,
+                },
+                _ => return None,
+            })
+        }
+        Expression::Assignment { .. }
+        | Expression::CompoundAssignment { .. }
+        | Expression::Update { .. }
+        | Expression::Variable { .. }
+        | Expression::Call { .. }
+        | Expression::Array { .. }
+        | Expression::Index { .. }
+        | Expression::IndexAssignment { .. }
+        | Expression::Struct { .. }
+        | Expression::Field { .. }
+        | Expression::FieldAssignment { .. }
+        | Expression::Variant { .. }
+        | Expression::Match { .. }
+        | Expression::MethodCall { .. } => None,
+    }
+}
+
+/// Gets the number a literal holds, if it's a number.
+const fn number(literal: &Literal) -> Option<Number> {
+    match literal {
+        Literal::Integer(value) => Some(Number::Integer(*value)),
+        Literal::Float(value) => Some(Number::Float(*value)),
+        Literal::String(_) | Literal::Boolean(_) => None,
+    }
+}
@@ -0,0 +1,269 @@
+use crate::parser::Literal;
+use crate::semantics::builtins::Builtin;
+use crate::semantics::numbers::Number;
+use crate::semantics::types::Type;
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+/// A value on the stack of the virtual machine.
+///
+/// # Variants
+///
+/// * `String` - A string, such as `"foo"`.
+/// * `Number` - An integer or a float, such as `1` or `2.5`.
+/// * `Boolean` - A boolean, `true` or `false`.
+/// * `Function` - A user-defined function, by its index in the program.
+/// * `Builtin` - A built-in function, such as `print`.
+/// * `Array` - An array, which is shared by every value it's assigned to.
+/// * `Struct` - An instance of a struct, which is shared by every value it's assigned to.
+/// * `Enum` - A variant of an enum, along with its values.
+/// * `Unit` - The value of expressions which don't produce anything.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(Rc<str>),
+    Number(Number),
+    Boolean(bool),
+    Function(usize),
+    Builtin(Builtin),
+    Array(Rc<RefCell<Vec<Self>>>),
+    Struct(Rc<RefCell<Instance>>),
+    Enum(Rc<Variant>),
+    Unit,
+}
+
+impl Value {
+    /// Gets the name of the type of the value.
+    ///
+    /// # Returns
+    ///
+    /// * `&'static str` - The name of the type.
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::String(_) => "str",
+            Self::Number(Number::Integer(_)) => "int",
+            Self::Number(Number::Float(_)) => "float",
+            Self::Boolean(_) => "bool",
+            Self::Function(_) | Self::Builtin(_) => "function",
+            Self::Array(_) => "array",
+            Self::Struct(_) => "struct",
+            Self::Enum(_) => "enum",
+            Self::Unit => "void",
+        }
+    }
+
+    /// Creates an array, where integers are widened to floats if any element is a float, just
+    /// like the type checker infers.
+    ///
+    /// # Arguments
+    ///
+    /// * `elements` - The elements of the array.
+    ///
+    /// # Returns
+    ///
+    /// * `Value` - The array.
+    #[must_use]
+    pub fn array(elements: Vec<Self>) -> Self {
+        let is_float = elements
+            .iter()
+            .any(|element| matches!(element, Self::Number(Number::Float(_))));
+        let elements = if is_float {
+            elements
+                .into_iter()
+                .map(|element| match element {
+                    Self::Number(number) => Self::Number(number.widen(&Type::Float)),
+                    element => element,
+                })
+                .collect()
+        } else {
+            elements
+        };
+
+        Self::Array(Rc::new(RefCell::new(elements)))
+    }
+
+    /// Widens an integer to a float, or the integers in an array to floats, where the type
+    /// checker accepted them for floats.
+    ///
+    /// # Returns
+    ///
+    /// * `Value` - The value, widened if needed.
+    #[must_use]
+    pub fn widen(self) -> Self {
+        match self {
+            Self::Number(number) => Self::Number(number.widen(&Type::Float)),
+            Self::Array(elements) => {
+                widen_elements(&elements);
+
+                Self::Array(elements)
+            }
+            value => value,
+        }
+    }
+}
+
+/// Widens the integers in an array to floats, including those in the arrays nested in it.
+fn widen_elements(elements: &RefCell<Vec<Value>>) {
+    let mut elements = elements.borrow_mut();
+
+    // The numbers in an array are either all integers or all floats, so only an array literal of
+    // integers has to be widened, and the first element tells which one it is.
+    match elements.first() {
+        Some(Value::Number(Number::Integer(_))) => {
+            for element in elements.iter_mut() {
+                if let Value::Number(number) = element {
+                    *number = number.widen(&Type::Float);
+                }
+            }
+        }
+        Some(Value::Array(_)) => {
+            for element in elements.iter() {
+                if let Value::Array(nested) = element {
+                    widen_elements(nested);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+impl From<&Literal> for Value {
+    fn from(literal: &Literal) -> Self {
+        match literal {
+            Literal::String(value) => Self::String(Rc::from(value.as_str())),
+            Literal::Integer(value) => Self::Number(Number::Integer(*value)),
+            Literal::Float(value) => Self::Number(Number::Float(*value)),
+            Literal::Boolean(value) => Self::Boolean(*value),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::String(string) => write!(f, "{string}"),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Boolean(boolean) => write!(f, "{boolean}"),
+            Self::Function(index) => write!(f, "<fn #{index}>"),
+            Self::Builtin(builtin) => write!(f, "<builtin {}>", builtin.name()),
+            Self::Array(elements) => {
+                write!(f, "[")?;
+                for (index, element) in elements.borrow().iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{element}")?;
+                }
+                write!(f, "]")
+            }
+            Self::Struct(instance) => write!(f, "{}", instance.borrow()),
+            Self::Enum(variant) => write!(f, "{variant}"),
+            Self::Unit => write!(f, "()"),
+        }
+    }
+}
+
+/// An instance of a struct.
+///
+/// # Fields
+///
+/// * `name` - The name of the struct.
+/// * `fields` - The names and values of the fields, in the order the literal gave them.
+#[derive(Debug, Clone)]
+pub struct Instance {
+    pub name: Rc<str>,
+    pub fields: Vec<(Rc<str>, Value)>,
+}
+
+impl Instance {
+    /// Gets the value of a field.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&Value>` - The value, or `None` if there's no such field.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find_map(|(field, value)| (**field == *name).then_some(value))
+    }
+
+    /// Gets the value of a field, to be assigned.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&mut Value>` - The value, or `None` if there's no such field.
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut Value> {
+        self.fields
+            .iter_mut()
+            .find_map(|(field, value)| (**field == *name).then_some(value))
+    }
+}
+
+impl PartialEq for Instance {
+    fn eq(&self, other: &Self) -> bool {
+        // Literals may give the fields in any order, so they're compared by name.
+        self.name == other.name
+            && self.fields.len() == other.fields.len()
+            && self
+                .fields
+                .iter()
+                .all(|(name, value)| other.field(name) == Some(value))
+    }
+}
+
+impl Display for Instance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {{", self.name)?;
+        for (index, (name, value)) in self.fields.iter().enumerate() {
+            let separator = if index > 0 { "," } else { "" };
+            write!(f, "{separator} {name}: {value}")?;
+        }
+        if self.fields.is_empty() {
+            write!(f, "}}")
+        } else {
+            write!(f, " }}")
+        }
+    }
+}
+
+/// A variant of an enum, along with its values.
+///
+/// # Fields
+///
+/// * `enumeration` - The name of the enum.
+/// * `name` - The name of the variant.
+/// * `values` - The values the variant holds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    pub enumeration: Rc<str>,
+    pub name: Rc<str>,
+    pub values: Vec<Value>,
+}
+
+impl Display for Variant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}::{}", self.enumeration, self.name)?;
+        if self.values.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "(")?;
+        for (index, value) in self.values.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{value}")?;
+        }
+        write!(f, ")")
+    }
+}
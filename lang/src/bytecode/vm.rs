@@ -0,0 +1,732 @@
+use crate::bytecode::chunk::{Function, Instruction, Program};
+use crate::bytecode::errors::Error;
+use crate::bytecode::values::{Instance, Value, Variant};
+use crate::lexer::tokens::TokenKind;
+use crate::semantics::builtins::Builtin;
+use crate::semantics::numbers::{self, ArithmeticError, Number};
+use crate::semantics::types::Type;
+use crate::MAX_CALL_DEPTH;
+use std::cell::RefCell;
+use std::io::Write;
+use std::rc::Rc;
+
+/// A call frame of the virtual machine.
+///
+/// # Fields
+///
+/// * `function` - The function being executed.
+/// * `ip` - The offset of the next instruction.
+/// * `locals` - The local slots, starting with the parameters.
+#[derive(Debug)]
+struct Frame<'a> {
+    function: &'a Function,
+    ip: usize,
+    locals: Vec<Option<Value>>,
+}
+
+impl<'a> Frame<'a> {
+    fn new(function: &'a Function) -> Self {
+        Self {
+            function,
+            ip: 0,
+            locals: vec![None; function.locals],
+        }
+    }
+}
+
+/// A stack-based virtual machine which executes a compiled program.
+///
+/// # Fields
+///
+/// * `program` - The program to execute.
+/// * `stack` - The value stack.
+/// * `frame` - The frame of the function currently being executed.
+/// * `frames` - The frames of the callers, innermost last.
+/// * `globals` - The global slots.
+/// * `output` - Where the program output is written.
+#[derive(Debug)]
+pub struct VirtualMachine<'a, W: Write> {
+    program: &'a Program,
+    stack: Vec<Value>,
+    frame: Frame<'a>,
+    frames: Vec<Frame<'a>>,
+    globals: Vec<Option<Value>>,
+    output: W,
+}
+
+impl<'a, W: Write> VirtualMachine<'a, W> {
+    /// Creates a new virtual machine.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - The program to execute.
+    /// * `output` - Where the program output is written.
+    ///
+    /// # Returns
+    ///
+    /// * `VirtualMachine<'a, W>` - The new virtual machine.
+    #[must_use]
+    pub fn new(program: &'a Program, output: W) -> Self {
+        Self {
+            program,
+            stack: Vec::new(),
+            frame: Frame::new(&program.script),
+            frames: Vec::new(),
+            globals: vec![None; program.globals],
+            output,
+        }
+    }
+
+    /// Executes the program.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - The result of the execution.
+    ///
+    /// # Errors
+    ///
+    /// * If a runtime error occurs, such as a division by zero.
+    /// * If writing the program output fails.
+    pub fn run(&mut self) -> Result<(), Error> {
+        loop {
+            let instruction = self.frame.function.chunk.code[self.frame.ip];
+            self.frame.ip += 1;
+
+            match instruction {
+                Instruction::Constant(index) => {
+                    let constant = self.frame.function.chunk.constants[index].clone();
+
+                    self.stack.push(constant);
+                }
+                Instruction::Pop => {
+                    self.pop()?;
+                }
+                Instruction::GetLocal(_)
+                | Instruction::SetLocal(_)
+                | Instruction::GetGlobal(_)
+                | Instruction::SetGlobal(_)
+                | Instruction::ClearLocal(_)
+                | Instruction::ClearGlobal(_) => self.variable(instruction)?,
+                Instruction::Negate => self.negate()?,
+                Instruction::ToFloat => {
+                    let value = self.pop()?;
+                    self.stack.push(value.widen());
+                }
+                Instruction::Not => match self.pop()? {
+                    Value::Boolean(value) => self.stack.push(Value::Boolean(!value)),
+                    value => return Err(self.invalid_operand("!", &value)),
+                },
+                Instruction::Add
+                | Instruction::Subtract
+                | Instruction::Multiply
+                | Instruction::Divide
+                | Instruction::Modulo
+                | Instruction::Power
+                | Instruction::Equal
+                | Instruction::NotEqual
+                | Instruction::Less
+                | Instruction::LessEqual
+                | Instruction::Greater
+                | Instruction::GreaterEqual
+                | Instruction::BitwiseAnd
+                | Instruction::BitwiseOr
+                | Instruction::BitwiseXor
+                | Instruction::ShiftLeft
+                | Instruction::ShiftRight => {
+                    let right = self.pop()?;
+                    let left = self.pop()?;
+
+                    let result = self.binary(instruction, &left, &right)?;
+                    self.stack.push(result);
+                }
+                Instruction::Jump(target) => self.frame.ip = target,
+                Instruction::JumpIfFalse(target) => {
+                    if !self.condition()? {
+                        self.frame.ip = target;
+                    }
+                }
+                Instruction::JumpIfTrue(target) => {
+                    if self.condition()? {
+                        self.frame.ip = target;
+                    }
+                }
+                Instruction::Step(slot) => self.step(slot)?,
+                Instruction::Array(elements) => self.array(elements)?,
+                Instruction::Index => self.index()?,
+                Instruction::SetIndex => self.set_index()?,
+                Instruction::Struct(fields) => self.instance(fields)?,
+                Instruction::GetField(index) => self.get_field(index)?,
+                Instruction::SetField(index) => self.set_field(index)?,
+                Instruction::Variant(values) => self.variant(values)?,
+                Instruction::IsVariant(_) | Instruction::Payload(_) | Instruction::NoMatch => {
+                    self.pattern(instruction)?;
+                }
+                Instruction::Call(arguments) => self.call(arguments)?,
+                Instruction::Return => {
+                    let value = self.pop()?;
+
+                    // Returning from the top-level code ends the program.
+                    let Some(caller) = self.frames.pop() else {
+                        break;
+                    };
+
+                    self.frame = caller;
+                    self.stack.push(value);
+                }
+            }
+        }
+
+        self.output.flush()?;
+
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Result<Value, Error> {
+        self.stack.pop().ok_or(Error::StackUnderflow)
+    }
+
+    fn peek(&self) -> Result<&Value, Error> {
+        self.stack.last().ok_or(Error::StackUnderflow)
+    }
+
+    /// Reads, writes or clears a local or global slot.
+    fn variable(&mut self, instruction: Instruction) -> Result<(), Error> {
+        match instruction {
+            Instruction::GetLocal(slot) => {
+                let value = self.initialized(self.frame.locals[slot].as_ref())?;
+
+                self.stack.push(value);
+            }
+            Instruction::SetLocal(slot) => {
+                let value = assigned(self.frame.locals[slot].as_ref(), self.peek()?);
+
+                self.frame.locals[slot] = Some(value);
+            }
+            Instruction::GetGlobal(slot) => {
+                let value = self.initialized(self.globals[slot].as_ref())?;
+
+                self.stack.push(value);
+            }
+            Instruction::SetGlobal(slot) => {
+                let value = assigned(self.globals[slot].as_ref(), self.peek()?);
+
+                self.globals[slot] = Some(value);
+            }
+            Instruction::ClearLocal(slot) => self.frame.locals[slot] = None,
+            Instruction::ClearGlobal(slot) => self.globals[slot] = None,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Compares a value against the pattern of a match arm, or fails if no arm matched it.
+    fn pattern(&mut self, instruction: Instruction) -> Result<(), Error> {
+        match instruction {
+            Instruction::IsVariant(index) => {
+                let name = self.constant_name(index);
+                let is_variant = matches!(
+                    self.pop()?,
+                    Value::Enum(variant)
+                        if name.split_once("::") == Some((&variant.enumeration, &variant.name))
+                );
+
+                self.stack.push(Value::Boolean(is_variant));
+            }
+            Instruction::Payload(position) => match self.pop()? {
+                Value::Enum(variant) if position < variant.values.len() => {
+                    self.stack.push(variant.values[position].clone());
+                }
+                value => return Err(self.invalid_operand("match", &value)),
+            },
+            Instruction::NoMatch => {
+                let value = self.pop()?;
+                let (line, column) = self.position();
+
+                return Err(Error::InvalidOperands {
+                    operator: "match".to_string(),
+                    message: format!("No arm matches '{value}'."),
+                    line,
+                    column,
+                });
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Advances the variable of a range loop, pushing `false` if that would overflow.
+    fn step(&mut self, slot: usize) -> Result<(), Error> {
+        let current = self.initialized(self.frame.locals[slot].as_ref())?;
+        let step = self.initialized(self.frame.locals[slot + 2].as_ref())?;
+
+        let next = match (&current, step) {
+            (Value::Number(current), Value::Number(step)) => {
+                numbers::arithmetic(&TokenKind::Plus, *current, step).ok()
+            }
+            _ => return Err(self.invalid_operand("+", &current)),
+        };
+
+        if let Some(next) = next {
+            self.frame.locals[slot] = Some(Value::Number(next));
+        }
+        self.stack.push(Value::Boolean(next.is_some()));
+
+        Ok(())
+    }
+
+    /// Gets the value of a variable, which fails if it was never assigned one.
+    fn initialized(&self, value: Option<&Value>) -> Result<Value, Error> {
+        value.cloned().ok_or_else(|| {
+            let (line, column) = self.position();
+
+            Error::UninitializedVariable { line, column }
+        })
+    }
+
+    fn position(&self) -> (usize, usize) {
+        self.frame
+            .function
+            .chunk
+            .positions
+            .get(self.frame.ip.saturating_sub(1))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn negate(&mut self) -> Result<(), Error> {
+        match self.pop()? {
+            Value::Number(value) => {
+                let value = value.negate().map_err(|why| {
+                    let (line, column) = self.position();
+
+                    Error::InvalidOperands {
+                        operator: "-".to_string(),
+                        message: why.to_string(),
+                        line,
+                        column,
+                    }
+                })?;
+
+                self.stack.push(Value::Number(value));
+
+                Ok(())
+            }
+            value => Err(self.invalid_operand("-", &value)),
+        }
+    }
+
+    fn condition(&self) -> Result<bool, Error> {
+        match self.peek()? {
+            Value::Boolean(value) => Ok(*value),
+            value => Err(self.invalid_operand("condition", value)),
+        }
+    }
+
+    fn binary(
+        &self,
+        instruction: Instruction,
+        left: &Value,
+        right: &Value,
+    ) -> Result<Value, Error> {
+        let (line, column) = self.position();
+
+        Ok(match (instruction, left, right) {
+            (instruction, Value::Number(left), Value::Number(right)) => {
+                let kind = source(instruction);
+                if let Some(result) = numbers::compare(&kind, *left, *right) {
+                    return Ok(Value::Boolean(result));
+                }
+
+                let result =
+                    numbers::arithmetic(&kind, *left, *right).map_err(|why| match why {
+                        ArithmeticError::DivisionByZero => Error::DivisionByZero { line, column },
+                        why => Error::InvalidOperands {
+                            operator: operator(instruction).to_string(),
+                            message: why.to_string(),
+                            line,
+                            column,
+                        },
+                    })?;
+
+                Value::Number(result)
+            }
+            (Instruction::Equal, _, _) => Value::Boolean(left == right),
+            (Instruction::NotEqual, _, _) => Value::Boolean(left != right),
+            (Instruction::Add, Value::String(left), Value::String(right)) => {
+                Value::String(format!("{left}{right}").into())
+            }
+            (Instruction::Less, Value::String(left), Value::String(right)) => {
+                Value::Boolean(left < right)
+            }
+            (Instruction::LessEqual, Value::String(left), Value::String(right)) => {
+                Value::Boolean(left <= right)
+            }
+            (Instruction::Greater, Value::String(left), Value::String(right)) => {
+                Value::Boolean(left > right)
+            }
+            (Instruction::GreaterEqual, Value::String(left), Value::String(right)) => {
+                Value::Boolean(left >= right)
+            }
+            (instruction, _, _) => {
+                return Err(Error::InvalidOperands {
+                    operator: operator(instruction).to_string(),
+                    message: format!(
+                        "Cannot apply to '{}' and '{}'.",
+                        left.type_name(),
+                        right.type_name()
+                    ),
+                    line,
+                    column,
+                })
+            }
+        })
+    }
+
+    fn call(&mut self, arguments: usize) -> Result<(), Error> {
+        let (line, column) = self.position();
+
+        let base = self
+            .stack
+            .len()
+            .checked_sub(arguments + 1)
+            .ok_or(Error::StackUnderflow)?;
+        let callee = self.stack[base].clone();
+
+        let function = match callee {
+            Value::Function(id) => self
+                .program
+                .functions
+                .get(id)
+                .ok_or(Error::MissingFunction { id })?,
+            Value::Builtin(builtin) => {
+                if arguments != builtin.arity() {
+                    return Err(Error::ArityMismatch {
+                        expected: builtin.arity(),
+                        found: arguments,
+                        line,
+                        column,
+                    });
+                }
+
+                let values = self.stack.split_off(base + 1);
+                self.stack.truncate(base);
+
+                let result = self.call_builtin(builtin, &values)?;
+                self.stack.push(result);
+
+                return Ok(());
+            }
+            value => {
+                return Err(Error::NotCallable {
+                    value: value.to_string(),
+                    line,
+                    column,
+                })
+            }
+        };
+
+        if arguments != function.arity {
+            return Err(Error::ArityMismatch {
+                expected: function.arity,
+                found: arguments,
+                line,
+                column,
+            });
+        }
+
+        if self.frames.len() >= MAX_CALL_DEPTH {
+            return Err(Error::StackOverflow {
+                name: function.name.clone(),
+                line,
+                column,
+            });
+        }
+
+        // The arguments become the first local slots of the new frame.
+        let mut frame = Frame::new(function);
+        for (slot, argument) in self.stack.drain(base + 1..).enumerate() {
+            frame.locals[slot] = Some(argument);
+        }
+        self.stack.truncate(base);
+
+        let previous = std::mem::replace(&mut self.frame, frame);
+        self.frames.push(previous);
+
+        Ok(())
+    }
+
+    fn call_builtin(&mut self, builtin: Builtin, arguments: &[Value]) -> Result<Value, Error> {
+        match builtin {
+            Builtin::Print => {
+                for argument in arguments {
+                    writeln!(self.output, "{argument}")?;
+                }
+
+                Ok(Value::Unit)
+            }
+            Builtin::Len => {
+                let length = match arguments {
+                    [Value::Array(elements)] => elements.borrow().len(),
+                    [Value::String(string)] => string.chars().count(),
+                    _ => {
+                        let found = arguments.first().unwrap_or(&Value::Unit);
+
+                        return Err(self.invalid_operand(builtin.name(), found));
+                    }
+                };
+
+                Ok(Value::Number(Number::Integer(
+                    i64::try_from(length).unwrap_or(i64::MAX),
+                )))
+            }
+        }
+    }
+
+    fn array(&mut self, elements: usize) -> Result<(), Error> {
+        let start = self
+            .stack
+            .len()
+            .checked_sub(elements)
+            .ok_or(Error::StackUnderflow)?;
+        let elements = self.stack.split_off(start);
+
+        self.stack.push(Value::array(elements));
+
+        Ok(())
+    }
+
+    fn index(&mut self) -> Result<(), Error> {
+        let index = self.pop()?;
+        let array = self.pop()?;
+
+        let elements = self.elements(&array)?.borrow();
+        let element = elements[self.position_of(&elements, &index)?].clone();
+        drop(elements);
+
+        self.stack.push(element);
+
+        Ok(())
+    }
+
+    fn set_index(&mut self) -> Result<(), Error> {
+        let value = self.pop()?;
+        let index = self.pop()?;
+        let array = self.pop()?;
+
+        let mut elements = self.elements(&array)?.borrow_mut();
+        let position = self.position_of(&elements, &index)?;
+        elements[position] = assigned(Some(&elements[position]), &value);
+        drop(elements);
+
+        self.stack.push(value);
+
+        Ok(())
+    }
+
+    /// Builds an instance of a struct, whose name is below the names and values of its fields.
+    fn instance(&mut self, fields: usize) -> Result<(), Error> {
+        let start = fields
+            .checked_mul(2)
+            .and_then(|values| self.stack.len().checked_sub(values + 1))
+            .ok_or(Error::StackUnderflow)?;
+        let mut values = self.stack.split_off(start).into_iter();
+
+        let Some(Value::String(name)) = values.next() else {
+            return Err(Error::StackUnderflow);
+        };
+        let mut fields = Vec::with_capacity(fields);
+        while let (Some(Value::String(field)), Some(value)) = (values.next(), values.next()) {
+            fields.push((field, value));
+        }
+
+        self.stack
+            .push(Value::Struct(Rc::new(RefCell::new(Instance {
+                name,
+                fields,
+            }))));
+
+        Ok(())
+    }
+
+    fn get_field(&mut self, index: usize) -> Result<(), Error> {
+        let object = self.pop()?;
+        let name = self.constant_name(index);
+
+        let instance = self.instance_of(&object)?.borrow();
+        let value = instance
+            .field(&name)
+            .cloned()
+            .ok_or_else(|| self.unknown_field(&instance, &name))?;
+        drop(instance);
+
+        self.stack.push(value);
+
+        Ok(())
+    }
+
+    fn set_field(&mut self, index: usize) -> Result<(), Error> {
+        let value = self.pop()?;
+        let object = self.pop()?;
+        let name = self.constant_name(index);
+
+        let mut instance = self.instance_of(&object)?.borrow_mut();
+        let error = self.unknown_field(&instance, &name);
+        let field = instance.field_mut(&name).ok_or(error)?;
+        *field = assigned(Some(field), &value);
+        drop(instance);
+
+        self.stack.push(value);
+
+        Ok(())
+    }
+
+    /// Builds a variant, whose enum and name are below its values.
+    fn variant(&mut self, values: usize) -> Result<(), Error> {
+        let start = self
+            .stack
+            .len()
+            .checked_sub(values + 2)
+            .ok_or(Error::StackUnderflow)?;
+        let values = self.stack.split_off(start + 2);
+
+        let (Some(Value::String(name)), Some(Value::String(enumeration))) =
+            (self.stack.pop(), self.stack.pop())
+        else {
+            return Err(Error::StackUnderflow);
+        };
+
+        self.stack.push(Value::Enum(Rc::new(Variant {
+            enumeration,
+            name,
+            values,
+        })));
+
+        Ok(())
+    }
+
+    /// Gets a name from the constant pool, such as the name of a field.
+    fn constant_name(&self, index: usize) -> Rc<str> {
+        match self.frame.function.chunk.constants.get(index) {
+            Some(Value::String(name)) => Rc::clone(name),
+            _ => Rc::from("?"),
+        }
+    }
+
+    /// Gets the instance of a value whose field is accessed, which has to be a struct.
+    fn instance_of<'v>(&self, value: &'v Value) -> Result<&'v RefCell<Instance>, Error> {
+        match value {
+            Value::Struct(instance) => Ok(instance),
+            value => Err(self.invalid_operand(".", value)),
+        }
+    }
+
+    fn unknown_field(&self, instance: &Instance, name: &str) -> Error {
+        let (line, column) = self.position();
+
+        Error::InvalidOperands {
+            operator: ".".to_string(),
+            message: format!("'{}' has no field '{name}'.", instance.name),
+            line,
+            column,
+        }
+    }
+
+    /// Gets the elements of a value which is indexed, which has to be an array.
+    fn elements<'v>(&self, value: &'v Value) -> Result<&'v RefCell<Vec<Value>>, Error> {
+        match value {
+            Value::Array(elements) => Ok(elements),
+            value => Err(self.invalid_operand("[]", value)),
+        }
+    }
+
+    /// Finds the position of the element an index refers to, checking it's within bounds.
+    fn position_of(&self, elements: &[Value], index: &Value) -> Result<usize, Error> {
+        let Value::Number(Number::Integer(index)) = index else {
+            return Err(self.invalid_operand("[]", index));
+        };
+        let (line, column) = self.position();
+
+        usize::try_from(*index)
+            .ok()
+            .filter(|position| *position < elements.len())
+            .ok_or(Error::IndexOutOfBounds {
+                index: *index,
+                length: elements.len(),
+                line,
+                column,
+            })
+    }
+
+    fn invalid_operand(&self, operator: &str, value: &Value) -> Error {
+        let (line, column) = self.position();
+
+        Error::InvalidOperands {
+            operator: operator.to_string(),
+            message: format!("Cannot apply to '{}'.", value.type_name()),
+            line,
+            column,
+        }
+    }
+}
+
+/// Gets the value stored in a slot by an assignment, where a float stays a float when it's
+/// assigned an integer.
+fn assigned(current: Option<&Value>, value: &Value) -> Value {
+    match (current, value) {
+        (Some(Value::Number(Number::Float(_))), Value::Number(number)) => {
+            Value::Number(number.widen(&Type::Float))
+        }
+        _ => value.clone(),
+    }
+}
+
+/// Gets the binary operator an instruction was compiled from, for applying it to numbers.
+const fn source(instruction: Instruction) -> TokenKind {
+    match instruction {
+        Instruction::Add => TokenKind::Plus,
+        Instruction::Subtract => TokenKind::Minus,
+        Instruction::Multiply => TokenKind::Star,
+        Instruction::Divide => TokenKind::Slash,
+        Instruction::Modulo => TokenKind::Percent,
+        Instruction::Power => TokenKind::Power,
+        Instruction::Equal => TokenKind::Equality,
+        Instruction::NotEqual => TokenKind::NotEqual,
+        Instruction::Less => TokenKind::LessThan,
+        Instruction::LessEqual => TokenKind::LessThanOrEqual,
+        Instruction::Greater => TokenKind::GreaterThan,
+        Instruction::GreaterEqual => TokenKind::GreaterThanOrEqual,
+        Instruction::BitwiseAnd => TokenKind::BitwiseAnd,
+        Instruction::BitwiseOr => TokenKind::BitwiseOr,
+        Instruction::BitwiseXor => TokenKind::BitwiseXor,
+        Instruction::ShiftLeft => TokenKind::BitwiseLeftShift,
+        Instruction::ShiftRight => TokenKind::BitwiseRightShift,
+        _ => TokenKind::EndOfFile,
+    }
+}
+
+/// Gets the source operator an instruction was compiled from, for error reporting.
+const fn operator(instruction: Instruction) -> &'static str {
+    match instruction {
+        Instruction::Add => "+",
+        Instruction::Subtract | Instruction::Negate => "-",
+        Instruction::Multiply => "*",
+        Instruction::Divide => "/",
+        Instruction::Modulo => "%",
+        Instruction::Power => "**",
+        Instruction::Equal => "==",
+        Instruction::NotEqual => "!=",
+        Instruction::Less => "<",
+        Instruction::LessEqual => "<=",
+        Instruction::Greater => ">",
+        Instruction::GreaterEqual => ">=",
+        Instruction::Not => "!",
+        Instruction::BitwiseAnd => "&",
+        Instruction::BitwiseOr => "|",
+        Instruction::BitwiseXor => "^",
+        Instruction::ShiftLeft => "<<",
+        Instruction::ShiftRight => ">>",
+        _ => "?",
+    }
+}
@@ -0,0 +1,205 @@
+use crate::lexer::span::Span;
+
+pub mod render;
+
+/// How serious a diagnostic is.
+///
+/// # Variants
+///
+/// * `Error` - The program can't be run.
+/// * `Warning` - The program can be run, but probably doesn't do what was intended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A span of source code a diagnostic points at.
+///
+/// # Fields
+///
+/// * `span` - The span of source code.
+/// * `message` - What the span has to do with the diagnostic.
+/// * `is_primary` - Whether this is where the problem is, rather than some related code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+    pub is_primary: bool,
+}
+
+impl Label {
+    /// Creates a label pointing at where the problem is.
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The span of source code.
+    /// * `message` - What's wrong with the span.
+    ///
+    /// # Returns
+    ///
+    /// * `Label` - The new label.
+    #[must_use]
+    pub fn primary(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            is_primary: true,
+        }
+    }
+
+    /// Creates a label pointing at code related to the problem.
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The span of source code.
+    /// * `message` - What the span has to do with the problem.
+    ///
+    /// # Returns
+    ///
+    /// * `Label` - The new label.
+    #[must_use]
+    pub fn secondary(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            is_primary: false,
+        }
+    }
+}
+
+/// A problem found in a program, shared by every phase of the compiler.
+///
+/// Error codes are grouped by phase: `E00xx` for the lexer, `E01xx` for the parser and `E02xx`
+/// for the semantic analyzer. Warnings use the same numbering with a `W` prefix, such as `W0201`.
+///
+/// # Fields
+///
+/// * `severity` - How serious the problem is.
+/// * `code` - A unique code for the kind of problem, such as `E0101`.
+/// * `message` - A short description of the problem.
+/// * `labels` - The spans of source code the problem concerns.
+/// * `notes` - Extra information about the problem.
+/// * `help` - A suggestion for how to fix the problem, if there is one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: &'static str,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    /// Creates a new diagnostic without any labels, notes or help.
+    ///
+    /// # Arguments
+    ///
+    /// * `severity` - How serious the problem is.
+    /// * `code` - A unique code for the kind of problem.
+    /// * `message` - A short description of the problem.
+    ///
+    /// # Returns
+    ///
+    /// * `Diagnostic` - The new diagnostic.
+    #[must_use]
+    pub fn new(severity: Severity, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            code,
+            message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: None,
+        }
+    }
+
+    /// Creates a new error.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A unique code for the kind of problem.
+    /// * `message` - A short description of the problem.
+    ///
+    /// # Returns
+    ///
+    /// * `Diagnostic` - The new diagnostic.
+    #[must_use]
+    pub fn error(code: &'static str, message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, code, message)
+    }
+
+    /// Creates a new warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - A unique code for the kind of problem.
+    /// * `message` - A short description of the problem.
+    ///
+    /// # Returns
+    ///
+    /// * `Diagnostic` - The new diagnostic.
+    #[must_use]
+    pub fn warning(code: &'static str, message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, code, message)
+    }
+
+    /// Adds a label to the diagnostic.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label.
+    ///
+    /// # Returns
+    ///
+    /// * `Diagnostic` - The diagnostic with the label.
+    #[must_use]
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    /// Adds a note to the diagnostic.
+    ///
+    /// # Arguments
+    ///
+    /// * `note` - The note.
+    ///
+    /// # Returns
+    ///
+    /// * `Diagnostic` - The diagnostic with the note.
+    #[must_use]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Sets the help text of the diagnostic.
+    ///
+    /// # Arguments
+    ///
+    /// * `help` - The help text.
+    ///
+    /// # Returns
+    ///
+    /// * `Diagnostic` - The diagnostic with the help text.
+    #[must_use]
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Gets the label pointing at where the problem is, if there is one.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&Label>` - The first primary label, or the first label if none are primary.
+    #[must_use]
+    pub fn primary_label(&self) -> Option<&Label> {
+        self.labels
+            .iter()
+            .find(|label| label.is_primary)
+            .or_else(|| self.labels.first())
+    }
+}
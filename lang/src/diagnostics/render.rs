@@ -0,0 +1,123 @@
+use crate::diagnostics::{Diagnostic, Label, Severity};
+use crate::lexer::span::SourceMap;
+use std::fmt::Write;
+
+/// How many spaces a tab is shown as.
+const TAB_WIDTH: usize = 4;
+
+impl Diagnostic {
+    /// Renders the diagnostic with the source lines it concerns, similar to `rustc`.
+    ///
+    /// Every labelled line is shown with its labels underlined below it, `^` for primary labels
+    /// and `-` for secondary ones. Labels spanning several lines are underlined on their first
+    /// line only.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source code the diagnostic is about.
+    /// * `path` - The path of the source file, shown next to the location of the problem.
+    ///
+    /// # Returns
+    ///
+    /// * `String` - The rendered diagnostic, ending with a new-line.
+    #[must_use]
+    pub fn render(&self, source: &SourceMap, path: &str) -> String {
+        let mut output = String::new();
+
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let _ = writeln!(output, "{severity}[{}]: {}", self.code, self.message);
+
+        // Every labelled line, in order, with the labels on it.
+        let mut lines: Vec<(usize, Vec<&Label>)> = Vec::new();
+        for label in &self.labels {
+            let (line, _) = source.location(label.span.start);
+            match lines.iter_mut().find(|(number, _)| *number == line) {
+                Some((_, labels)) => labels.push(label),
+                None => lines.push((line, vec![label])),
+            }
+        }
+        lines.sort_by_key(|(line, _)| *line);
+
+        let width = lines.last().map_or(1, |(line, _)| line.to_string().len());
+        let gutter = " ".repeat(width);
+
+        if let Some(label) = self.primary_label() {
+            let (line, column) = source.location(label.span.start);
+            let _ = writeln!(output, "{gutter}--> {path}:{line}:{column}");
+        }
+
+        if !lines.is_empty() {
+            let _ = writeln!(output, "{gutter} |");
+        }
+
+        let mut previous = None;
+        for (line, labels) in &lines {
+            // Skipped lines are shown as an ellipsis.
+            if previous.is_some_and(|previous| line - previous > 1) {
+                let _ = writeln!(output, "...");
+            }
+            previous = Some(*line);
+
+            let text = source.line(*line).unwrap_or_default();
+            let _ = writeln!(output, "{line:>width$} | {}", expand_tabs(text));
+
+            for label in labels {
+                let _ = writeln!(output, "{gutter} | {}", underline(source, text, label));
+            }
+        }
+
+        if !self.notes.is_empty() || self.help.is_some() {
+            let _ = writeln!(output, "{gutter} |");
+        }
+
+        for note in &self.notes {
+            let _ = writeln!(output, "{gutter} = note: {note}");
+        }
+
+        if let Some(help) = &self.help {
+            let _ = writeln!(output, "{gutter} = help: {help}");
+        }
+
+        output
+    }
+}
+
+/// Underlines a label on its line, followed by its message.
+fn underline(source: &SourceMap, text: &str, label: &Label) -> String {
+    let (_, column) = source.location(label.span.start);
+    let start = column - 1;
+
+    // Only underline up to the end of the line.
+    let length = source
+        .text(label.span)
+        .and_then(|span| span.lines().next())
+        .map_or(0, width);
+    let before = text.chars().take(start).collect::<String>();
+
+    let marker = if label.is_primary { "^" } else { "-" };
+    let underline = format!(
+        "{}{}",
+        " ".repeat(width(&before)),
+        marker.repeat(length.max(1))
+    );
+
+    if label.message.is_empty() {
+        underline
+    } else {
+        format!("{underline} {}", label.message)
+    }
+}
+
+/// Gets how many columns some text takes up when shown.
+fn width(text: &str) -> usize {
+    text.chars()
+        .map(|c| if c == '\t' { TAB_WIDTH } else { 1 })
+        .sum()
+}
+
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', &" ".repeat(TAB_WIDTH))
+}
@@ -0,0 +1,60 @@
+use crate::interpreter::errors::Error;
+use crate::interpreter::values::Value;
+use crate::semantics::builtins::Builtin;
+use crate::semantics::numbers::Number;
+use std::io::Write;
+
+/// Calls a built-in function.
+///
+/// # Arguments
+///
+/// * `builtin` - The built-in to call.
+/// * `arguments` - The arguments, which must match the arity.
+/// * `output` - Where to write any output.
+/// * `location` - The line and column of the call, for error reporting.
+///
+/// # Returns
+///
+/// * `Result<Value<'a>, Error>` - The return value, or an error.
+///
+/// # Errors
+///
+/// * If writing to the output fails.
+/// * If an argument has the wrong type.
+pub fn call<'a>(
+    builtin: Builtin,
+    arguments: &[Value<'a>],
+    output: &mut impl Write,
+    location: (usize, usize),
+) -> Result<Value<'a>, Error> {
+    match builtin {
+        Builtin::Print => {
+            for argument in arguments {
+                writeln!(output, "{argument}")?;
+            }
+
+            Ok(Value::Unit)
+        }
+        Builtin::Len => {
+            let length = match arguments {
+                [Value::Array(elements)] => elements.borrow().len(),
+                [Value::String(string)] => string.chars().count(),
+                _ => {
+                    let (line, column) = location;
+                    let found = arguments.first().map_or("void", Value::type_name);
+
+                    return Err(Error::InvalidOperands {
+                        operator: builtin.name().to_string(),
+                        message: format!("Expected 'array' or 'str', found '{found}'."),
+                        line,
+                        column,
+                    });
+                }
+            };
+
+            Ok(Value::Number(Number::Integer(
+                i64::try_from(length).unwrap_or(i64::MAX),
+            )))
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::interpreter::errors::Error;
+use crate::interpreter::values::Value;
+use crate::lexer::tokens::Token;
+use crate::semantics::numbers::Number;
+use crate::semantics::types::Type;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// An environment which stores the values of the variables in a scope.
+///
+/// # Fields
+///
+/// * `values` - The variables, which are `None` until initialized.
+/// * `types` - The types the variables were declared with, for those declared with a type.
+/// * `enclosing` - The environment this one is nested in, if any.
+#[derive(Debug, Default)]
+pub struct Environment<'a> {
+    values: HashMap<String, Option<Value<'a>>>,
+    types: HashMap<String, Type>,
+    enclosing: Option<Rc<RefCell<Self>>>,
+}
+
+impl<'a> Environment<'a> {
+    /// Creates a new environment nested in another one.
+    ///
+    /// # Arguments
+    ///
+    /// * `enclosing` - The enclosing environment.
+    ///
+    /// # Returns
+    ///
+    /// * `Environment<'a>` - The new environment.
+    #[must_use]
+    pub fn new(enclosing: Rc<RefCell<Self>>) -> Self {
+        Self {
+            values: HashMap::new(),
+            types: HashMap::new(),
+            enclosing: Some(enclosing),
+        }
+    }
+
+    /// Defines a variable in this environment, replacing any previous one with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the variable.
+    /// * `value` - The value of the variable, if it's initialized.
+    pub fn define(&mut self, name: &str, value: Option<Value<'a>>) {
+        self.types.remove(name);
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Defines a variable which was declared with a type, which the values assigned to it later
+    /// are widened to.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the variable.
+    /// * `value` - The value of the variable, if it's initialized.
+    /// * `r#type` - The type the variable was declared with.
+    pub fn define_typed(&mut self, name: &str, value: Option<Value<'a>>, r#type: Type) {
+        self.values.insert(name.to_string(), value);
+        self.types.insert(name.to_string(), r#type);
+    }
+
+    /// Gets the value of a variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The token naming the variable.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Value<'a>, Error>` - The value, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * If the variable is undefined.
+    /// * If the variable hasn't been initialized.
+    pub fn get(&self, name: &Token) -> Result<Value<'a>, Error> {
+        let key = name.token_kind.to_string();
+
+        match self.values.get(&key) {
+            Some(Some(value)) => Ok(value.clone()),
+            Some(None) => Err(Error::UninitializedVariable {
+                name: key,
+                line: name.line,
+                column: name.column,
+            }),
+            None => self.enclosing.as_ref().map_or(
+                Err(Error::UndefinedVariable {
+                    name: key,
+                    line: name.line,
+                    column: name.column,
+                }),
+                |enclosing| enclosing.borrow().get(name),
+            ),
+        }
+    }
+
+    /// Assigns a new value to an existing variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The token naming the variable.
+    /// * `value` - The new value.
+    ///
+    /// # Errors
+    ///
+    /// * If the variable is undefined.
+    pub fn assign(&mut self, name: &Token, value: Value<'a>) -> Result<(), Error> {
+        let key = name.token_kind.to_string();
+
+        if let Some(slot) = self.values.get_mut(&key) {
+            // A float variable stays a float when it's assigned an integer, even if it had no
+            // value yet, and so do the elements of an array of floats.
+            let expected = match slot {
+                Some(Value::Number(Number::Float(_))) => Some(&Type::Float),
+                _ => self.types.get(&key),
+            };
+            *slot = Some(match expected {
+                Some(expected) => value.widen_to(expected),
+                None => value,
+            });
+
+            return Ok(());
+        }
+
+        self.enclosing.as_ref().map_or(
+            Err(Error::UndefinedVariable {
+                name: key,
+                line: name.line,
+                column: name.column,
+            }),
+            |enclosing| enclosing.borrow_mut().assign(name, value),
+        )
+    }
+}
@@ -0,0 +1,69 @@
+use thiserror::Error;
+
+/// A runtime error.
+///
+/// # Variants
+///
+/// * `UndefinedVariable` - A variable which doesn't exist was accessed.
+/// * `UninitializedVariable` - A variable was read before it was assigned a value.
+/// * `InvalidOperands` - An operator was applied to values of the wrong type.
+/// * `DivisionByZero` - A number was divided by zero.
+/// * `NotCallable` - A value which isn't a function was called.
+/// * `ArityMismatch` - A function was called with the wrong number of arguments.
+/// * `StackOverflow` - The maximum call depth was exceeded.
+/// * `IndexOutOfBounds` - An array was indexed past its end, or with a negative index.
+/// * `IoError` - Writing the program output failed.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("The variable '{name}' is undefined at line {line} and column {column}!")]
+    UndefinedVariable {
+        name: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("The variable '{name}' hasn't been initialized at line {line} and column {column}!")]
+    UninitializedVariable {
+        name: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("Invalid operands for '{operator}' at line {line} and column {column}: {message}")]
+    InvalidOperands {
+        operator: String,
+        message: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("Division by zero at line {line} and column {column}!")]
+    DivisionByZero { line: usize, column: usize },
+    #[error("Only functions can be called, found '{value}' at line {line} and column {column}!")]
+    NotCallable {
+        value: String,
+        line: usize,
+        column: usize,
+    },
+    #[error(
+        "Expected {expected} argument(s) but found {found} at line {line} and column {column}!"
+    )]
+    ArityMismatch {
+        expected: usize,
+        found: usize,
+        line: usize,
+        column: usize,
+    },
+    #[error("Stack overflow in '{name}' at line {line} and column {column}!")]
+    StackOverflow {
+        name: String,
+        line: usize,
+        column: usize,
+    },
+    #[error("The index {index} is out of bounds for an array of length {length} at line {line} and column {column}!")]
+    IndexOutOfBounds {
+        index: i64,
+        length: usize,
+        line: usize,
+        column: usize,
+    },
+    #[error("I/O error!")]
+    IoError(#[from] std::io::Error),
+}
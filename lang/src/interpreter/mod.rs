@@ -0,0 +1,1203 @@
+use crate::interpreter::environment::Environment;
+use crate::interpreter::errors::Error;
+use crate::interpreter::values::{Function, Instance, Value, Variant};
+use crate::lexer::tokens::{Token, TokenKind};
+use crate::parser::ast::AST;
+use crate::parser::{Arm, Expression, Pattern, Statement, TypeAnnotation};
+use crate::semantics::builtins::Builtin;
+use crate::semantics::numbers::{self, ArithmeticError, Number};
+use crate::semantics::types::Type;
+use crate::MAX_CALL_DEPTH;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::Write;
+use std::rc::Rc;
+
+pub mod builtins;
+pub mod environment;
+pub mod errors;
+pub mod values;
+
+/// How control leaves a statement.
+///
+/// # Variants
+///
+/// * `Normal` - The statement ran to completion.
+/// * `Break` - A `break` statement was executed, with the label of the loop it leaves, if any.
+/// * `Continue` - A `continue` statement was executed, with the label of the loop it continues,
+///   if any.
+/// * `Return` - A `return` statement was executed, with the returned value.
+#[derive(Debug)]
+enum ControlFlow<'a> {
+    Normal,
+    Break(Option<&'a Token>),
+    Continue(Option<&'a Token>),
+    Return(Value<'a>),
+}
+
+impl ControlFlow<'_> {
+    /// Decides how a loop goes on once its body has run.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label of the loop, if it has one.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<ControlFlow>` - `None` if the loop keeps going, or how control leaves the loop.
+    fn after_loop_body(self, label: Option<&Token>) -> Option<Self> {
+        // An unlabeled `break` or `continue` targets the innermost loop.
+        let targets = |target: Option<&Token>| {
+            target.is_none_or(|target| {
+                label.is_some_and(|label| label.token_kind == target.token_kind)
+            })
+        };
+
+        match self {
+            Self::Normal => None,
+            Self::Continue(target) if targets(target) => None,
+            Self::Break(target) if targets(target) => Some(Self::Normal),
+            flow => Some(flow),
+        }
+    }
+}
+
+/// A call frame, which is pushed for every function call.
+///
+/// # Fields
+///
+/// * `caller` - The environment of the caller, which is restored when the call returns.
+#[derive(Debug)]
+struct CallFrame<'a> {
+    caller: Rc<RefCell<Environment<'a>>>,
+}
+
+/// A tree-walking interpreter which executes the AST.
+///
+/// # Fields
+///
+/// * `ast` - The AST to execute.
+/// * `environment` - The environment of the scope currently being executed.
+/// * `frames` - The call stack.
+/// * `structs` - The fields of every struct declared so far, by name, so that struct literals can
+///   widen the values of float fields.
+/// * `enums` - The variants of every enum declared so far, by name, so that variants can widen
+///   their float values.
+/// * `output` - Where the program output is written.
+#[derive(Debug)]
+pub struct Interpreter<'a, W: Write> {
+    ast: &'a AST,
+    environment: Rc<RefCell<Environment<'a>>>,
+    frames: Vec<CallFrame<'a>>,
+    structs: HashMap<String, &'a [(Token, TypeAnnotation)]>,
+    enums: HashMap<String, &'a [(Token, Vec<TypeAnnotation>)]>,
+    output: W,
+}
+
+impl<'a, W: Write> Interpreter<'a, W> {
+    /// Creates a new interpreter.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - The AST to execute.
+    /// * `output` - Where the program output is written.
+    ///
+    /// # Returns
+    ///
+    /// * `Interpreter<'a, W>` - The new interpreter.
+    #[must_use]
+    pub fn new(ast: &'a AST, output: W) -> Self {
+        let mut globals = Environment::default();
+        for builtin in Builtin::ALL {
+            globals.define(builtin.name(), Some(Value::Builtin(*builtin)));
+        }
+
+        Self {
+            ast,
+            environment: Rc::new(RefCell::new(globals)),
+            frames: Vec::new(),
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            output,
+        }
+    }
+
+    /// Executes the AST.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - The result of the execution.
+    ///
+    /// # Errors
+    ///
+    /// * If a runtime error occurs, such as a division by zero.
+    /// * If writing the program output fails.
+    pub fn interpret(&mut self) -> Result<(), Error> {
+        self.hoist(&self.ast.statements);
+        for statement in &self.ast.statements {
+            // A top-level `return` ends the program.
+            if let ControlFlow::Return(_) = self.execute_statement(statement)? {
+                break;
+            }
+        }
+
+        self.output.flush()?;
+
+        Ok(())
+    }
+
+    /// Executes a statement.
+    ///
+    /// Nested calls recurse through here, so statements which need more than a single call are
+    /// executed by their own functions, which keeps this one's frame on the native stack small.
+    fn execute_statement(&mut self, statement: &'a Statement) -> Result<ControlFlow<'a>, Error> {
+        match statement {
+            Statement::Expression { expression, .. } => {
+                return self.evaluate(expression).map(|_| ControlFlow::Normal);
+            }
+            Statement::Variable {
+                name,
+                type_annotation,
+                initializer,
+                ..
+            } => {
+                return self
+                    .execute_let(name, type_annotation.as_ref(), initializer.as_ref())
+                    .map(|()| ControlFlow::Normal)
+            }
+            Statement::Block { statements, .. } => {
+                let environment = Environment::new(Rc::clone(&self.environment));
+
+                return self.execute_block(statements, Rc::new(RefCell::new(environment)));
+            }
+            Statement::If {
+                condition,
+                then_branch,
+                else_branch,
+                ..
+            } => return self.execute_if(condition, then_branch, else_branch.as_deref()),
+            Statement::While {
+                label,
+                condition,
+                body,
+                ..
+            } => return self.execute_while(label.as_ref(), condition, body),
+            Statement::For {
+                label,
+                initializer,
+                condition,
+                increment,
+                body,
+                ..
+            } => {
+                return self.execute_for(
+                    label.as_ref(),
+                    initializer.as_deref(),
+                    condition.as_ref(),
+                    increment.as_ref(),
+                    body,
+                )
+            }
+            Statement::ForRange {
+                label,
+                variable,
+                start,
+                end,
+                step,
+                body,
+                ..
+            } => {
+                return self.execute_for_range(
+                    label.as_ref(),
+                    variable,
+                    start,
+                    end,
+                    step.as_deref(),
+                    body,
+                )
+            }
+            Statement::Break { label, .. } => return Ok(ControlFlow::Break(label.as_ref())),
+            Statement::Continue { label, .. } => return Ok(ControlFlow::Continue(label.as_ref())),
+            Statement::Return { value, .. } => return self.execute_return(value.as_ref()),
+            // Functions, structs and enums are declared when their block starts.
+            Statement::Function { .. } | Statement::Struct { .. } | Statement::Enum { .. } => {}
+        }
+
+        Ok(ControlFlow::Normal)
+    }
+
+    fn execute_if(
+        &mut self,
+        condition: &'a Expression,
+        then_branch: &'a Statement,
+        else_branch: Option<&'a Statement>,
+    ) -> Result<ControlFlow<'a>, Error> {
+        if self.evaluate_condition(condition)? {
+            return self.execute_statement(then_branch);
+        }
+
+        else_branch.map_or(Ok(ControlFlow::Normal), |else_branch| {
+            self.execute_statement(else_branch)
+        })
+    }
+
+    fn execute_return(&mut self, value: Option<&'a Expression>) -> Result<ControlFlow<'a>, Error> {
+        let value = match value {
+            Some(value) => self.evaluate(value)?,
+            None => Value::Unit,
+        };
+
+        Ok(ControlFlow::Return(value))
+    }
+
+    /// Declares the functions, structs and enums of a block before any of its statements runs,
+    /// so that they can be used before the point where they're declared.
+    fn hoist(&mut self, statements: &'a [Statement]) {
+        for statement in statements {
+            match statement {
+                Statement::Function {
+                    name,
+                    parameters,
+                    return_type,
+                    body,
+                    ..
+                } => {
+                    let function = Function {
+                        name,
+                        parameters,
+                        return_type: return_type.as_ref(),
+                        body,
+                        closure: Rc::clone(&self.environment),
+                    };
+
+                    self.define(name, Value::Function(Rc::new(function)));
+                }
+                Statement::Struct { name, fields, .. } => {
+                    self.structs.insert(name.token_kind.to_string(), fields);
+                }
+                Statement::Enum { name, variants, .. } => {
+                    self.enums.insert(name.token_kind.to_string(), variants);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn define(&self, name: &Token, value: Value<'a>) {
+        self.environment
+            .borrow_mut()
+            .define(&name.token_kind.to_string(), Some(value));
+    }
+
+    fn execute_block(
+        &mut self,
+        statements: &'a [Statement],
+        environment: Rc<RefCell<Environment<'a>>>,
+    ) -> Result<ControlFlow<'a>, Error> {
+        let previous = std::mem::replace(&mut self.environment, environment);
+        self.hoist(statements);
+
+        let mut result = Ok(ControlFlow::Normal);
+        for statement in statements {
+            result = self.execute_statement(statement);
+            if !matches!(result, Ok(ControlFlow::Normal)) {
+                break;
+            }
+        }
+
+        // Restore the previous environment, even if an error occurred.
+        self.environment = previous;
+
+        result
+    }
+
+    /// Executes a variable declaration, widening its initial value to the type it's annotated with.
+    fn execute_let(
+        &mut self,
+        name: &'a Token,
+        annotation: Option<&'a TypeAnnotation>,
+        initializer: Option<&'a Expression>,
+    ) -> Result<(), Error> {
+        let value = match (initializer, annotation) {
+            (Some(initializer), Some(annotation)) => {
+                Some(self.evaluate(initializer)?.widen(annotation))
+            }
+            (Some(initializer), None) => Some(self.evaluate(initializer)?),
+            (None, _) => None,
+        };
+
+        let name = name.token_kind.to_string();
+        let mut environment = self.environment.borrow_mut();
+        match annotation.map(Type::from_annotation) {
+            Some(Ok(r#type)) => environment.define_typed(&name, value, r#type),
+            _ => environment.define(&name, value),
+        }
+
+        Ok(())
+    }
+
+    fn execute_while(
+        &mut self,
+        label: Option<&'a Token>,
+        condition: &'a Expression,
+        body: &'a Statement,
+    ) -> Result<ControlFlow<'a>, Error> {
+        while self.evaluate_condition(condition)? {
+            if let Some(flow) = self.execute_statement(body)?.after_loop_body(label) {
+                return Ok(flow);
+            }
+        }
+
+        Ok(ControlFlow::Normal)
+    }
+
+    fn execute_for(
+        &mut self,
+        label: Option<&'a Token>,
+        initializer: Option<&'a Statement>,
+        condition: Option<&'a Expression>,
+        increment: Option<&'a Expression>,
+        body: &'a Statement,
+    ) -> Result<ControlFlow<'a>, Error> {
+        // The initializer gets its own scope, so the loop variable doesn't leak.
+        let environment = Environment::new(Rc::clone(&self.environment));
+        let previous = std::mem::replace(&mut self.environment, Rc::new(RefCell::new(environment)));
+
+        let result = self.execute_clauses(label, initializer, condition, increment, body);
+        self.environment = previous;
+
+        result
+    }
+
+    fn execute_clauses(
+        &mut self,
+        label: Option<&'a Token>,
+        initializer: Option<&'a Statement>,
+        condition: Option<&'a Expression>,
+        increment: Option<&'a Expression>,
+        body: &'a Statement,
+    ) -> Result<ControlFlow<'a>, Error> {
+        if let Some(initializer) = initializer {
+            self.execute_statement(initializer)?;
+        }
+
+        loop {
+            if let Some(condition) = condition {
+                if !self.evaluate_condition(condition)? {
+                    break;
+                }
+            }
+
+            if let Some(flow) = self.execute_statement(body)?.after_loop_body(label) {
+                return Ok(flow);
+            }
+
+            if let Some(increment) = increment {
+                self.evaluate(increment)?;
+            }
+        }
+
+        Ok(ControlFlow::Normal)
+    }
+
+    /// Executes a range loop, where the variable is the counter and the end and the step are
+    /// evaluated once, up front.
+    fn execute_for_range(
+        &mut self,
+        label: Option<&'a Token>,
+        variable: &'a Token,
+        start: &'a Expression,
+        end: &'a Expression,
+        step: Option<&'a Expression>,
+        body: &'a Statement,
+    ) -> Result<ControlFlow<'a>, Error> {
+        let start = self.evaluate_number(start)?;
+        let end = self.evaluate_number(end)?;
+        let step = match step {
+            Some(step) => self.evaluate_number(step)?,
+            None => Number::Integer(1),
+        };
+
+        // The variable is a float if anything it's computed from is.
+        let start = match (end, step) {
+            (Number::Float(_), _) | (_, Number::Float(_)) => start.widen(&Type::Float),
+            _ => start,
+        };
+
+        let mut environment = Environment::new(Rc::clone(&self.environment));
+        environment.define(&variable.token_kind.to_string(), Some(Value::Number(start)));
+        let previous = std::mem::replace(&mut self.environment, Rc::new(RefCell::new(environment)));
+
+        let result = self.execute_range(label, variable, end, step, body);
+        self.environment = previous;
+
+        result
+    }
+
+    fn execute_range(
+        &mut self,
+        label: Option<&'a Token>,
+        variable: &'a Token,
+        end: Number,
+        step: Number,
+        body: &'a Statement,
+    ) -> Result<ControlFlow<'a>, Error> {
+        loop {
+            let Value::Number(current) = self.environment.borrow().get(variable)? else {
+                break;
+            };
+            if !in_range(current, end, step) {
+                break;
+            }
+
+            if let Some(flow) = self.execute_statement(body)?.after_loop_body(label) {
+                return Ok(flow);
+            }
+
+            // The body may have changed the variable, so it's read again. A step which overflows
+            // goes past the end, so it finishes the loop.
+            let Value::Number(current) = self.environment.borrow().get(variable)? else {
+                break;
+            };
+            let Ok(next) = numbers::arithmetic(&TokenKind::Plus, current, step) else {
+                break;
+            };
+            self.environment
+                .borrow_mut()
+                .assign(variable, Value::Number(next))?;
+        }
+
+        Ok(ControlFlow::Normal)
+    }
+
+    fn evaluate_number(&mut self, expression: &'a Expression) -> Result<Number, Error> {
+        match self.evaluate(expression)? {
+            Value::Number(number) => Ok(number),
+            value => {
+                let (line, column) = locate(expression);
+
+                Err(Error::InvalidOperands {
+                    operator: "range".to_string(),
+                    message: format!("Expected a number, found '{}'.", value.type_name()),
+                    line,
+                    column,
+                })
+            }
+        }
+    }
+
+    fn evaluate_condition(&mut self, condition: &'a Expression) -> Result<bool, Error> {
+        match self.evaluate(condition)? {
+            Value::Boolean(value) => Ok(value),
+            value => {
+                let (line, column) = locate(condition);
+
+                Err(Error::InvalidOperands {
+                    operator: "condition".to_string(),
+                    message: format!("Expected 'bool', found '{}'.", value.type_name()),
+                    line,
+                    column,
+                })
+            }
+        }
+    }
+
+    /// Evaluates an expression.
+    ///
+    /// Like `execute_statement`, this is on the path of every nested call, so expressions which
+    /// need more than a single call are evaluated by their own functions.
+    fn evaluate(&mut self, expression: &'a Expression) -> Result<Value<'a>, Error> {
+        match expression {
+            Expression::Literal { value: literal, .. } => Ok(Value::from(literal)),
+            Expression::Grouping { expression, .. } => self.evaluate(expression),
+            Expression::Variable { name, .. } => self.environment.borrow().get(name),
+            Expression::Assignment { name, value, .. } => self.evaluate_assignment(name, value),
+            Expression::CompoundAssignment {
+                name,
+                operator,
+                value,
+                ..
+            } => self.evaluate_compound_assignment(name, operator, value),
+            Expression::Update {
+                name,
+                operator,
+                is_prefix,
+                ..
+            } => self.evaluate_update(name, operator, *is_prefix),
+            Expression::Unary {
+                operator, right, ..
+            } => self.evaluate_unary(operator, right),
+            Expression::Binary {
+                left,
+                operator,
+                right,
+                ..
+            } => self.evaluate_binary(left, operator, right),
+            Expression::Call {
+                callee, arguments, ..
+            } => self.evaluate_call(callee, arguments),
+            Expression::Array { elements, .. } => self.evaluate_array(elements),
+            Expression::Index {
+                array,
+                bracket,
+                index,
+                ..
+            } => self.evaluate_index(array, bracket, index, None),
+            Expression::IndexAssignment {
+                array,
+                bracket,
+                index,
+                value,
+                ..
+            } => self.evaluate_index(array, bracket, index, Some(value)),
+            Expression::Struct { name, fields, .. } => self.evaluate_struct(name, fields),
+            Expression::Field { object, name, .. } => self.evaluate_field(object, name, None),
+            Expression::FieldAssignment {
+                object,
+                name,
+                value,
+                ..
+            } => self.evaluate_field(object, name, Some(value)),
+            Expression::Variant {
+                enumeration,
+                variant,
+                arguments,
+                ..
+            } => self.evaluate_variant(enumeration, variant, arguments),
+            Expression::Match {
+                keyword,
+                scrutinee,
+                arms,
+                ..
+            } => self.evaluate_match(keyword, scrutinee, arms),
+            Expression::MethodCall {
+                receiver,
+                name,
+                arguments,
+                ..
+            } => self.evaluate_method_call(receiver, name, arguments, expression),
+        }
+    }
+
+    fn evaluate_assignment(
+        &mut self,
+        name: &'a Token,
+        value: &'a Expression,
+    ) -> Result<Value<'a>, Error> {
+        let value = self.evaluate(value)?;
+        self.environment.borrow_mut().assign(name, value.clone())?;
+
+        Ok(value)
+    }
+
+    fn evaluate_array(&mut self, elements: &'a [Expression]) -> Result<Value<'a>, Error> {
+        let mut values = Vec::with_capacity(elements.len());
+        for element in elements {
+            values.push(self.evaluate(element)?);
+        }
+
+        Ok(Value::array(values))
+    }
+
+    /// Evaluates an element of an array, assigning a value to it first if one is given.
+    fn evaluate_index(
+        &mut self,
+        array: &'a Expression,
+        bracket: &'a Token,
+        index: &'a Expression,
+        value: Option<&'a Expression>,
+    ) -> Result<Value<'a>, Error> {
+        let array = self.evaluate(array)?;
+        let index = self.evaluate(index)?;
+        let value = value.map(|value| self.evaluate(value)).transpose()?;
+
+        let mut elements = elements(&array, bracket)?.borrow_mut();
+        let position = position(&elements, &index, bracket)?;
+
+        // A float element stays a float when it's assigned an integer.
+        if let Some(value) = value {
+            elements[position] = match (&elements[position], value) {
+                (Value::Number(Number::Float(_)), Value::Number(number)) => {
+                    Value::Number(number.widen(&Type::Float))
+                }
+                (_, value) => value,
+            };
+        }
+
+        Ok(elements[position].clone())
+    }
+
+    /// Evaluates a variant of an enum, widening the values it's given where it holds floats.
+    fn evaluate_variant(
+        &mut self,
+        enumeration: &'a Token,
+        variant: &'a Token,
+        arguments: &'a [Expression],
+    ) -> Result<Value<'a>, Error> {
+        let payload = self
+            .enums
+            .get(&enumeration.token_kind.to_string())
+            .and_then(|variants| {
+                variants
+                    .iter()
+                    .find(|(declared, _)| declared.token_kind == variant.token_kind)
+            })
+            .map(|(_, payload)| payload.as_slice());
+
+        let mut values = Vec::with_capacity(arguments.len());
+        for (index, argument) in arguments.iter().enumerate() {
+            let value = self.evaluate(argument)?;
+
+            values.push(match payload.and_then(|payload| payload.get(index)) {
+                Some(annotation) => value.widen(annotation),
+                None => value,
+            });
+        }
+
+        Ok(Value::Enum(Rc::new(Variant {
+            enumeration: enumeration.token_kind.to_string(),
+            name: variant.token_kind.to_string(),
+            values,
+        })))
+    }
+
+    /// Evaluates the first arm of a match whose pattern matches, with the names it binds in a
+    /// scope of their own.
+    fn evaluate_match(
+        &mut self,
+        keyword: &'a Token,
+        scrutinee: &'a Expression,
+        arms: &'a [Arm],
+    ) -> Result<Value<'a>, Error> {
+        let value = self.evaluate(scrutinee)?;
+
+        for arm in arms {
+            let Some(bindings) = bind(&arm.pattern, &value) else {
+                continue;
+            };
+
+            let mut environment = Environment::new(Rc::clone(&self.environment));
+            for (name, value) in bindings {
+                environment.define(&name.token_kind.to_string(), Some(value));
+            }
+            let previous =
+                std::mem::replace(&mut self.environment, Rc::new(RefCell::new(environment)));
+
+            let result = self.evaluate(&arm.body);
+            self.environment = previous;
+
+            return result;
+        }
+
+        Err(Error::InvalidOperands {
+            operator: "match".to_string(),
+            message: format!("No arm matches '{value}'."),
+            line: keyword.line,
+            column: keyword.column,
+        })
+    }
+
+    /// Evaluates a read of a field of an instance, or an assignment to it if there's a value.
+    fn evaluate_field(
+        &mut self,
+        object: &'a Expression,
+        name: &'a Token,
+        value: Option<&'a Expression>,
+    ) -> Result<Value<'a>, Error> {
+        let object = self.evaluate(object)?;
+        let value = value.map(|value| self.evaluate(value)).transpose()?;
+
+        let mut instance = instance(&object, name)?.borrow_mut();
+        let annotation = self
+            .structs
+            .get(&instance.name)
+            .copied()
+            .into_iter()
+            .flatten()
+            .find(|(declared, _)| declared.token_kind == name.token_kind);
+        let error = unknown_field(&instance, name);
+        let field = instance
+            .field_mut(&name.token_kind.to_string())
+            .ok_or(error)?;
+
+        // A float field stays a float when it's assigned an integer, and so do the elements of
+        // an array of floats.
+        if let Some(value) = value {
+            *field = match (&*field, value, annotation) {
+                (Value::Number(Number::Float(_)), Value::Number(number), _) => {
+                    Value::Number(number.widen(&Type::Float))
+                }
+                (_, value, Some((_, annotation))) => value.widen(annotation),
+                (_, value, None) => value,
+            };
+        }
+
+        Ok(field.clone())
+    }
+
+    /// Evaluates a struct literal, widening the values given to float fields.
+    fn evaluate_struct(
+        &mut self,
+        name: &'a Token,
+        fields: &'a [(Token, Expression)],
+    ) -> Result<Value<'a>, Error> {
+        let declared = self.structs.get(&name.token_kind.to_string()).copied();
+
+        let mut values = Vec::with_capacity(fields.len());
+        for (field, value) in fields {
+            let mut value = self.evaluate(value)?;
+            let annotation = declared
+                .into_iter()
+                .flatten()
+                .find(|(declared, _)| declared.token_kind == field.token_kind);
+            if let Some((_, annotation)) = annotation {
+                value = value.widen(annotation);
+            }
+
+            values.push((field.token_kind.to_string(), value));
+        }
+
+        Ok(Value::Struct(Rc::new(RefCell::new(Instance {
+            name: name.token_kind.to_string(),
+            fields: values,
+        }))))
+    }
+
+    fn evaluate_unary(
+        &mut self,
+        operator: &'a Token,
+        right: &'a Expression,
+    ) -> Result<Value<'a>, Error> {
+        let right = self.evaluate(right)?;
+
+        match (&operator.token_kind, right) {
+            (TokenKind::Minus, Value::Number(value)) => value
+                .negate()
+                .map(Value::Number)
+                .map_err(|why| arithmetic_error(operator, why)),
+            (TokenKind::LogicalNot, Value::Boolean(value)) => Ok(Value::Boolean(!value)),
+            (_, right) => Err(invalid_operands(
+                operator,
+                format!("Cannot apply to '{}'.", right.type_name()),
+            )),
+        }
+    }
+
+    fn evaluate_call(
+        &mut self,
+        callee: &'a Expression,
+        arguments: &'a [Expression],
+    ) -> Result<Value<'a>, Error> {
+        let function = self.evaluate(callee)?;
+
+        let mut values = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            values.push(self.evaluate(argument)?);
+        }
+
+        self.call(&function, values, callee)
+    }
+
+    /// Evaluates a method call by calling the function with the name of the method, giving it the
+    /// receiver as its first argument.
+    fn evaluate_method_call(
+        &mut self,
+        receiver: &'a Expression,
+        name: &'a Token,
+        arguments: &'a [Expression],
+        location: &'a Expression,
+    ) -> Result<Value<'a>, Error> {
+        let mut values = Vec::with_capacity(arguments.len() + 1);
+        values.push(self.evaluate(receiver)?);
+        let function = self.environment.borrow().get(name)?;
+
+        for argument in arguments {
+            values.push(self.evaluate(argument)?);
+        }
+
+        self.call(&function, values, location)
+    }
+
+    fn evaluate_compound_assignment(
+        &mut self,
+        name: &'a Token,
+        operator: &'a Token,
+        value: &'a Expression,
+    ) -> Result<Value<'a>, Error> {
+        let current = self.environment.borrow().get(name)?;
+        let value = self.evaluate(value)?;
+
+        let kind = operator
+            .token_kind
+            .compound_operator()
+            .ok_or_else(|| invalid_operands(operator, "Unsupported operator.".to_string()))?;
+        let value = apply_binary(&kind, operator, &current, &value)?;
+        self.environment.borrow_mut().assign(name, value.clone())?;
+
+        Ok(value)
+    }
+
+    fn evaluate_update(
+        &self,
+        name: &'a Token,
+        operator: &'a Token,
+        is_prefix: bool,
+    ) -> Result<Value<'a>, Error> {
+        let current = self.environment.borrow().get(name)?;
+        let Value::Number(number) = current else {
+            return Err(invalid_operands(
+                operator,
+                format!("Cannot apply to '{}'.", current.type_name()),
+            ));
+        };
+
+        let kind = if operator.token_kind == TokenKind::Increment {
+            TokenKind::Plus
+        } else {
+            TokenKind::Minus
+        };
+        let updated = numbers::arithmetic(&kind, number, Number::Integer(1))
+            .map_err(|why| arithmetic_error(operator, why))?;
+        self.environment
+            .borrow_mut()
+            .assign(name, Value::Number(updated))?;
+
+        // A prefix update gives the new value, a postfix one the old value.
+        Ok(Value::Number(if is_prefix { updated } else { number }))
+    }
+
+    fn evaluate_binary(
+        &mut self,
+        left: &'a Expression,
+        operator: &'a Token,
+        right: &'a Expression,
+    ) -> Result<Value<'a>, Error> {
+        let left = self.evaluate(left)?;
+
+        // The logical operators short-circuit, so the right side may never be evaluated.
+        if matches!(
+            operator.token_kind,
+            TokenKind::LogicalAnd | TokenKind::LogicalOr
+        ) {
+            return self.evaluate_logical(&left, operator, right);
+        }
+
+        let right = self.evaluate(right)?;
+
+        apply_binary(&operator.token_kind, operator, &left, &right)
+    }
+
+    /// Evaluates the right side of `&&` or `||`, unless the left side already decides the result.
+    fn evaluate_logical(
+        &mut self,
+        left: &Value<'a>,
+        operator: &'a Token,
+        right: &'a Expression,
+    ) -> Result<Value<'a>, Error> {
+        let Value::Boolean(left) = *left else {
+            return Err(invalid_operands(
+                operator,
+                format!("Expected 'bool', found '{}'.", left.type_name()),
+            ));
+        };
+
+        if left == (operator.token_kind == TokenKind::LogicalOr) {
+            return Ok(Value::Boolean(left));
+        }
+
+        match self.evaluate(right)? {
+            Value::Boolean(right) => Ok(Value::Boolean(right)),
+            right => Err(invalid_operands(
+                operator,
+                format!("Expected 'bool', found '{}'.", right.type_name()),
+            )),
+        }
+    }
+
+    fn call(
+        &mut self,
+        callee: &Value<'a>,
+        arguments: Vec<Value<'a>>,
+        location: &'a Expression,
+    ) -> Result<Value<'a>, Error> {
+        let (line, column) = locate(location);
+
+        match callee {
+            Value::Function(function) => self.call_function(function, arguments, (line, column)),
+            Value::Builtin(builtin) => {
+                if arguments.len() != builtin.arity() {
+                    return Err(Error::ArityMismatch {
+                        expected: builtin.arity(),
+                        found: arguments.len(),
+                        line,
+                        column,
+                    });
+                }
+
+                builtins::call(*builtin, &arguments, &mut self.output, (line, column))
+            }
+            value => Err(Error::NotCallable {
+                value: value.to_string(),
+                line,
+                column,
+            }),
+        }
+    }
+
+    /// Calls a user-defined function.
+    ///
+    /// Every nested call recurses through here, so the work which doesn't have to stay on the
+    /// native stack for the whole call is done by `enter`.
+    fn call_function(
+        &mut self,
+        function: &Function<'a>,
+        arguments: Vec<Value<'a>>,
+        location: (usize, usize),
+    ) -> Result<Value<'a>, Error> {
+        let environment = self.enter(function, arguments, location)?;
+        let result = match function.body {
+            Statement::Block { statements, .. } => self.execute_block(statements, environment),
+            body => {
+                self.environment = environment;
+
+                self.execute_statement(body)
+            }
+        };
+
+        if let Some(frame) = self.frames.pop() {
+            self.environment = frame.caller;
+        }
+
+        match (result?, function.return_type) {
+            (ControlFlow::Return(value), Some(return_type)) => Ok(value.widen(return_type)),
+            (ControlFlow::Return(value), None) => Ok(value),
+            _ => Ok(Value::Unit),
+        }
+    }
+
+    /// Enters a call of a function, checking its arguments and the call depth, and pushing a call
+    /// frame.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Rc<RefCell<Environment<'a>>>, Error>` - The environment of the call, where the
+    ///   arguments are bound to the parameters, enclosed by the environment the function was
+    ///   declared in.
+    fn enter(
+        &mut self,
+        function: &Function<'a>,
+        arguments: Vec<Value<'a>>,
+        (line, column): (usize, usize),
+    ) -> Result<Rc<RefCell<Environment<'a>>>, Error> {
+        if arguments.len() != function.parameters.len() {
+            return Err(Error::ArityMismatch {
+                expected: function.parameters.len(),
+                found: arguments.len(),
+                line,
+                column,
+            });
+        }
+
+        if self.frames.len() >= MAX_CALL_DEPTH {
+            return Err(Error::StackOverflow {
+                name: function.name.token_kind.to_string(),
+                line,
+                column,
+            });
+        }
+
+        let mut environment = Environment::new(Rc::clone(&function.closure));
+        for ((parameter, r#type), argument) in function.parameters.iter().zip(arguments) {
+            let name = parameter.token_kind.to_string();
+            match Type::from_annotation(r#type) {
+                Ok(r#type) => {
+                    environment.define_typed(&name, Some(argument.widen_to(&r#type)), r#type);
+                }
+                Err(_) => environment.define(&name, Some(argument)),
+            }
+        }
+
+        self.frames.push(CallFrame {
+            caller: Rc::clone(&self.environment),
+        });
+
+        Ok(Rc::new(RefCell::new(environment)))
+    }
+}
+
+/// Applies a binary operator which isn't short-circuiting to two values.
+///
+/// The operator is given separately from its token, since compound assignments apply the binary
+/// operator of their `+=`-like token.
+fn apply_binary<'a>(
+    kind: &TokenKind,
+    operator: &Token,
+    left: &Value<'a>,
+    right: &Value<'a>,
+) -> Result<Value<'a>, Error> {
+    match (kind, left, right) {
+        (TokenKind::Equality, _, _) => Ok(Value::Boolean(left == right)),
+        (TokenKind::NotEqual, _, _) => Ok(Value::Boolean(left != right)),
+        (TokenKind::Plus, Value::String(left), Value::String(right)) => {
+            Ok(Value::String(format!("{left}{right}")))
+        }
+        (TokenKind::LessThan, Value::String(left), Value::String(right)) => {
+            Ok(Value::Boolean(left < right))
+        }
+        (TokenKind::LessThanOrEqual, Value::String(left), Value::String(right)) => {
+            Ok(Value::Boolean(left <= right))
+        }
+        (TokenKind::GreaterThan, Value::String(left), Value::String(right)) => {
+            Ok(Value::Boolean(left > right))
+        }
+        (TokenKind::GreaterThanOrEqual, Value::String(left), Value::String(right)) => {
+            Ok(Value::Boolean(left >= right))
+        }
+        (kind, Value::Number(left), Value::Number(right)) => {
+            if let Some(result) = numbers::compare(kind, *left, *right) {
+                return Ok(Value::Boolean(result));
+            }
+
+            numbers::arithmetic(kind, *left, *right)
+                .map(Value::Number)
+                .map_err(|why| arithmetic_error(operator, why))
+        }
+        _ => Err(invalid_operands(
+            operator,
+            format!(
+                "Cannot apply to '{}' and '{}'.",
+                left.type_name(),
+                right.type_name()
+            ),
+        )),
+    }
+}
+
+/// Gets the elements of a value which is indexed, which has to be an array.
+fn elements<'v, 'a>(
+    value: &'v Value<'a>,
+    bracket: &Token,
+) -> Result<&'v RefCell<Vec<Value<'a>>>, Error> {
+    match value {
+        Value::Array(elements) => Ok(elements),
+        value => Err(invalid_operands(
+            bracket,
+            format!("Cannot index into '{}'.", value.type_name()),
+        )),
+    }
+}
+
+/// Compares a value against a pattern, giving the values it binds to names if it matches.
+fn bind<'p, 'a>(pattern: &'p Pattern, value: &Value<'a>) -> Option<Vec<(&'p Token, Value<'a>)>> {
+    match (pattern, value) {
+        (Pattern::Wildcard { .. }, _) => Some(Vec::new()),
+        (Pattern::Literal { value: literal, .. }, value) => {
+            (Value::from(literal) == *value).then(Vec::new)
+        }
+        (
+            Pattern::Variant {
+                enumeration,
+                variant,
+                bindings,
+                ..
+            },
+            Value::Enum(actual),
+        ) if actual.enumeration == enumeration.token_kind.to_string()
+            && actual.name == variant.token_kind.to_string() =>
+        {
+            Some(bindings.iter().zip(actual.values.iter().cloned()).collect())
+        }
+        _ => None,
+    }
+}
+
+/// Gets the instance of a value whose field is accessed, which has to be a struct.
+fn instance<'v, 'a>(
+    value: &'v Value<'a>,
+    name: &Token,
+) -> Result<&'v RefCell<Instance<'a>>, Error> {
+    match value {
+        Value::Struct(instance) => Ok(instance),
+        value => Err(Error::InvalidOperands {
+            operator: ".".to_string(),
+            message: format!("Cannot access a field of '{}'.", value.type_name()),
+            line: name.line,
+            column: name.column,
+        }),
+    }
+}
+
+fn unknown_field(instance: &Instance<'_>, name: &Token) -> Error {
+    Error::InvalidOperands {
+        operator: ".".to_string(),
+        message: format!("'{}' has no field '{}'.", instance.name, name.token_kind),
+        line: name.line,
+        column: name.column,
+    }
+}
+
+/// Finds the position of the element an index refers to, checking it's within bounds.
+fn position(elements: &[Value<'_>], index: &Value<'_>, bracket: &Token) -> Result<usize, Error> {
+    let Value::Number(Number::Integer(index)) = index else {
+        return Err(invalid_operands(
+            bracket,
+            format!("Expected 'int', found '{}'.", index.type_name()),
+        ));
+    };
+
+    usize::try_from(*index)
+        .ok()
+        .filter(|position| *position < elements.len())
+        .ok_or(Error::IndexOutOfBounds {
+            index: *index,
+            length: elements.len(),
+            line: bracket.line,
+            column: bracket.column,
+        })
+}
+
+/// Checks whether a range loop goes on, counting up to the end for a positive step and down to it
+/// for a negative one. A loop with a zero step never runs.
+fn in_range(current: Number, end: Number, step: Number) -> bool {
+    let zero = Number::Integer(0);
+
+    match step.compare(zero) {
+        Some(Ordering::Greater) => {
+            matches!(current.compare(end), Some(Ordering::Less | Ordering::Equal))
+        }
+        Some(Ordering::Less) => matches!(
+            current.compare(end),
+            Some(Ordering::Greater | Ordering::Equal)
+        ),
+        _ => false,
+    }
+}
+
+fn arithmetic_error(operator: &Token, why: ArithmeticError) -> Error {
+    match why {
+        ArithmeticError::DivisionByZero => Error::DivisionByZero {
+            line: operator.line,
+            column: operator.column,
+        },
+        why => invalid_operands(operator, why.to_string()),
+    }
+}
+
+fn invalid_operands(operator: &Token, message: String) -> Error {
+    Error::InvalidOperands {
+        operator: operator.token_kind.to_string(),
+        message,
+        line: operator.line,
+        column: operator.column,
+    }
+}
+
+/// Finds the location of the first token in an expression, for error reporting.
+///
+/// Literals don't carry a token, so they're located at line 0, column 0.
+fn locate(expression: &Expression) -> (usize, usize) {
+    expression
+        .first_token()
+        .map_or((0, 0), |token| (token.line, token.column))
+}
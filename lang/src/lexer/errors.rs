@@ -1,3 +1,5 @@
+use crate::diagnostics::{Diagnostic, Label};
+use crate::lexer::span::Span;
 use thiserror::Error;
 
 /// An lexing error.
@@ -7,29 +9,157 @@ use thiserror::Error;
 /// * `InvalidToken` - An invalid token.
 /// * `UnexpectedCharacter` - An unexpected character.
 /// * `UnterminatedString` - An unterminated string.
-/// * `ParseIntError` - An integer parsing error.
+/// * `UnterminatedComment` - An unterminated multi-line comment.
+/// * `IntegerOverflow` - An integer literal which doesn't fit in 64 bits.
 /// * `ParseFloatError` - A float parsing error.
 /// * `IoError` - An IO error.
+/// * `InvalidFileExtension` - A source file which doesn't end in `.lt`.
+/// * `InvalidFilePath` - A source file which doesn't exist.
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Invalid token at line {line}, column {column}!")]
-    InvalidToken { line: usize, column: usize },
+    InvalidToken {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
     #[error("Unexpected character '{char}' at line {line}, column {column}!", char = char.unwrap_or(' '))]
     UnexpectedCharacter {
         char: Option<char>,
         line: usize,
         column: usize,
+        span: Span,
     },
     #[error("Unterminated string at line {line}, column {column}!")]
-    UnterminatedString { line: usize, column: usize },
-    #[error("Failed to parse integer!")]
-    ParseIntError(#[from] std::num::ParseIntError),
+    UnterminatedString {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("Unterminated comment at line {line}, column {column}!")]
+    UnterminatedComment {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("Integer literal out of range at line {line}, column {column}!")]
+    IntegerOverflow {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
     #[error("Failed to parse float!")]
     ParseFloatError(#[from] std::num::ParseFloatError),
     #[error("I/O error!")]
     IoError(#[from] std::io::Error),
     #[error("Invalid file extension!")]
     InvalidFileExtension,
-    #[error("File doesn't exist!")]
-    InvalidFilePath,
+    #[error("File '{path}' doesn't exist!")]
+    InvalidFilePath { path: String },
+}
+
+// I/O errors can't be compared, so they're equal if they're of the same kind.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::InvalidToken { line, column, span },
+                Self::InvalidToken {
+                    line: other_line,
+                    column: other_column,
+                    span: other_span,
+                },
+            )
+            | (
+                Self::UnterminatedString { line, column, span },
+                Self::UnterminatedString {
+                    line: other_line,
+                    column: other_column,
+                    span: other_span,
+                },
+            )
+            | (
+                Self::UnterminatedComment { line, column, span },
+                Self::UnterminatedComment {
+                    line: other_line,
+                    column: other_column,
+                    span: other_span,
+                },
+            )
+            | (
+                Self::IntegerOverflow { line, column, span },
+                Self::IntegerOverflow {
+                    line: other_line,
+                    column: other_column,
+                    span: other_span,
+                },
+            ) => (line, column, span) == (other_line, other_column, other_span),
+            (
+                Self::UnexpectedCharacter {
+                    char,
+                    line,
+                    column,
+                    span,
+                },
+                Self::UnexpectedCharacter {
+                    char: other_char,
+                    line: other_line,
+                    column: other_column,
+                    span: other_span,
+                },
+            ) => (char, line, column, span) == (other_char, other_line, other_column, other_span),
+            (Self::ParseFloatError(why), Self::ParseFloatError(other)) => why == other,
+            (Self::IoError(why), Self::IoError(other)) => why.kind() == other.kind(),
+            (Self::InvalidFilePath { path }, Self::InvalidFilePath { path: other }) => {
+                path == other
+            }
+            (Self::InvalidFileExtension, Self::InvalidFileExtension) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Error {}
+
+impl From<&Error> for Diagnostic {
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::InvalidToken { span, .. } => Self::error("E0001", "Invalid token.")
+                .with_label(Label::primary(*span, "not a valid token")),
+            Error::UnexpectedCharacter {
+                char: Some(char),
+                span,
+                ..
+            } => Self::error("E0002", format!("Unexpected character '{char}'."))
+                .with_label(Label::primary(*span, "unexpected character")),
+            Error::UnexpectedCharacter { span, .. } => {
+                Self::error("E0002", "Unexpected end of file.")
+                    .with_label(Label::primary(*span, "expected a character"))
+            }
+            Error::UnterminatedString { span, .. } => Self::error("E0003", "Unterminated string.")
+                .with_label(Label::primary(*span, "the string starts here"))
+                .with_help("add a '\"' at the end of the string"),
+            Error::UnterminatedComment { span, .. } => {
+                Self::error("E0006", "Unterminated multi-line comment.")
+                    .with_label(Label::primary(*span, "the comment starts here"))
+                    .with_help("add a '*/' at the end of the comment")
+            }
+            Error::IntegerOverflow { span, .. } => {
+                Self::error("E0007", "Integer literal out of range.")
+                    .with_label(Label::primary(*span, "doesn't fit in a 64-bit integer"))
+                    .with_help(
+                        "integers range from -2^63 to 2^63 - 1, use a float for larger numbers",
+                    )
+            }
+            Error::ParseFloatError(why) => {
+                Self::error("E0004", format!("Failed to parse float: {why}."))
+            }
+            Error::IoError(why) => Self::error("E0005", format!("I/O error: {why}.")),
+            Error::InvalidFileExtension => Self::error("E0008", "Invalid file extension.")
+                .with_help("Lithium files end in '.lt'"),
+            Error::InvalidFilePath { path } => {
+                Self::error("E0009", format!("File '{path}' doesn't exist."))
+            }
+        }
+    }
 }
@@ -1,25 +1,35 @@
+use crate::lexer::span::Span;
 use crate::lexer::tokens::{Token, TokenKind};
 use errors::Error;
+use std::iter::FusedIterator;
 
 pub mod errors;
+pub mod span;
 pub mod tokens;
 
-static ALLOWED_NUMBER_CHARS: &[char] = &['+', '-', 'e', 'E', '.', 'b', 'B', 'o', 'O', 'x', 'X'];
-
 /// A lexer.
 ///
+/// The lexer walks the input once, keeping a byte offset into it, so lexing takes time linear in
+/// the size of the input. It's an iterator over the tokens of the input, which skips comments and
+/// ends after the end-of-file token or the first error.
+///
 /// # Fields
 ///
 /// * `input` - The input to lex.
-/// * `position` - The current position in the input.
+/// * `offset` - The current position in the input, in bytes.
 /// * `line` - The current line.
 /// * `column` - The current column.
+/// * `after_operand` - Whether the last token ended an operand, in which case a following `+` or
+///   `-` is an operator rather than the sign of a number.
+/// * `is_done` - Whether the last token, or an error, has been produced.
 #[derive(Debug, Clone)]
 pub struct Lexer<'a> {
     input: &'a str,
-    position: usize,
+    offset: usize,
     line: usize,
     column: usize,
+    after_operand: bool,
+    is_done: bool,
 }
 
 impl<'a> Lexer<'a> {
@@ -33,47 +43,60 @@ impl<'a> Lexer<'a> {
     ///
     /// * `Lexer<'a>` - The lexer.
     #[must_use]
-    pub fn new(input: &'a str) -> Self {
+    pub const fn new(input: &'a str) -> Self {
         Self {
-            input: input.trim(), // Sanitize the input.
-            position: 0,
+            input,
+            offset: 0,
             line: 1,
-            column: 0,
+            column: 1,
+            after_operand: false,
+            is_done: false,
         }
     }
 
+    fn rest(&self) -> &'a str {
+        self.input.get(self.offset..).unwrap_or_default()
+    }
+
     fn current_char(&self) -> Option<char> {
-        self.input.chars().nth(self.position)
+        self.rest().chars().next()
     }
 
     fn next_char(&self) -> Option<char> {
-        self.input.chars().nth(self.position + 1)
+        self.rest().chars().nth(1)
     }
 
     fn advance(&mut self) {
-        if self.current_char() == Some('\n') {
-            self.line += 1;
-            self.column = 1;
-        } else {
-            self.column += 1;
+        if let Some(c) = self.current_char() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+
+            self.offset += c.len_utf8();
         }
+    }
 
-        self.position += 1;
+    fn advance_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while self.current_char().is_some_and(&predicate) {
+            self.advance();
+        }
     }
 
-    fn read_identifier(&mut self) -> Token {
-        let mut identifier = String::new();
-        while let Some(c) = self.current_char() {
-            if !c.is_alphanumeric() && c != '_' {
-                break;
-            }
+    fn char_span(&self) -> Span {
+        let length = self.current_char().map_or(0, char::len_utf8);
 
-            self.advance();
+        Span::new(self.offset, self.offset + length)
+    }
 
-            identifier.push(c);
-        }
+    fn read_identifier(&mut self) -> TokenKind {
+        let start = self.offset;
+        self.advance_while(|c| c.is_alphanumeric() || c == '_');
 
-        let token_kind = match identifier.as_str() {
+        let identifier = &self.input[start..self.offset];
+        match identifier {
             "true" => TokenKind::True,
             "false" => TokenKind::False,
             "if" => TokenKind::If,
@@ -87,83 +110,106 @@ impl<'a> Lexer<'a> {
             "return" => TokenKind::Return,
             "fn" => TokenKind::Function,
             "let" => TokenKind::Variable,
-            _ => TokenKind::Identifier(identifier),
-        };
-
-        Token::new(token_kind, self.line, self.column)
+            "struct" => TokenKind::Struct,
+            "enum" => TokenKind::Enum,
+            "match" => TokenKind::Match,
+            _ => TokenKind::Identifier(identifier.to_string()),
+        }
     }
 
-    fn read_number(&mut self) -> Result<Option<Token>, Error> {
-        let is_positive = self.current_char() == Some('+');
+    fn read_number(&mut self) -> Result<TokenKind, Error> {
+        let (start, line, column) = (self.offset, self.line, self.column);
         let is_negative = self.current_char() == Some('-');
-        if is_positive || is_negative {
-            if let Some(c) = self.next_char() {
-                if !c.is_numeric() {
-                    return Ok(None);
-                }
-            }
-
+        if matches!(self.current_char(), Some('+' | '-')) {
             self.advance();
         }
-
-        let mut number = String::new();
-        while let Some(c) = self.current_char() {
-            if !c.is_numeric() && !ALLOWED_NUMBER_CHARS.contains(&c) {
-                break;
-            }
-
+        let unsigned = self.offset;
+
+        // Binary, octal and hexadecimal integers.
+        let radix = match (self.current_char(), self.next_char()) {
+            (Some('0'), Some('b' | 'B')) => Some(2),
+            (Some('0'), Some('o' | 'O')) => Some(8),
+            (Some('0'), Some('x' | 'X')) => Some(16),
+            _ => None,
+        };
+        if let Some(radix) = radix {
+            self.advance();
             self.advance();
 
-            number.push(c);
-        }
+            let digits = self.offset;
+            self.advance_while(|c| c.is_ascii_alphanumeric());
 
-        // Make sure the number isn't empty.
-        let number = number.trim();
-        if number.is_empty() {
-            return Err(Error::UnexpectedCharacter {
-                char: self.current_char(),
-                line: self.line,
-                column: self.column,
+            let number = parse_integer(&self.input[digits..self.offset], radix, is_negative);
+
+            return number.map(TokenKind::Integer).ok_or_else(|| {
+                self.invalid_integer(&self.input[digits..self.offset], radix, line, column, start)
             });
         }
 
-        let token_kind = if number.contains('.') || number.contains('e') || number.contains('E') {
-            let number = number.parse::<f64>()?;
-
-            TokenKind::Float(number)
-        } else if number.contains('b') || number.contains('B') {
-            let number = i64::from_str_radix(&number[2..], 2)?;
+        self.advance_while(|c| c.is_ascii_digit());
 
-            TokenKind::Integer(number)
-        } else if number.contains('o') || number.contains('O') {
-            let number = i64::from_str_radix(&number[2..], 8)?;
+        // A fraction needs digits after the dot, otherwise the dot is a token of its own.
+        let mut is_float = false;
+        if self.current_char() == Some('.') && self.next_char().is_some_and(|c| c.is_ascii_digit())
+        {
+            is_float = true;
 
-            TokenKind::Integer(number)
-        } else if number.contains('x') || number.contains('X') {
-            let number = i64::from_str_radix(&number[2..], 16)?;
+            self.advance();
+            self.advance_while(|c| c.is_ascii_digit());
+        }
 
-            TokenKind::Integer(number)
-        } else {
-            let number = number.parse::<i64>()?;
+        // An exponent, which may have a sign of its own.
+        if matches!(self.current_char(), Some('e' | 'E')) {
+            let mut exponent = self.rest().chars().skip(1);
+            let has_exponent = match exponent.next() {
+                Some('+' | '-') => exponent.next().is_some_and(|c| c.is_ascii_digit()),
+                Some(c) => c.is_ascii_digit(),
+                None => false,
+            };
 
-            TokenKind::Integer(number)
-        };
+            if has_exponent {
+                is_float = true;
 
-        // If the number is negative, then we need to negate it.
-        let token_kind = if is_negative {
-            match token_kind {
-                TokenKind::Integer(number) => TokenKind::Integer(-number),
-                TokenKind::Float(number) => TokenKind::Float(-number),
-                _ => unreachable!(),
+                self.advance();
+                if matches!(self.current_char(), Some('+' | '-')) {
+                    self.advance();
+                }
+                self.advance_while(|c| c.is_ascii_digit());
             }
-        } else {
-            token_kind
-        };
+        }
+
+        if is_float {
+            return Ok(TokenKind::Float(self.input[start..self.offset].parse()?));
+        }
 
-        Ok(Some(Token::new(token_kind, self.line, self.column)))
+        let digits = &self.input[unsigned..self.offset];
+        parse_integer(digits, 10, is_negative)
+            .map(TokenKind::Integer)
+            .ok_or_else(|| self.invalid_integer(digits, 10, line, column, start))
     }
 
-    fn read_string(&mut self) -> Result<Token, Error> {
+    /// Explains why an integer literal couldn't be parsed, which is either because it doesn't fit
+    /// in 64 bits or because its digits are invalid.
+    fn invalid_integer(
+        &self,
+        digits: &str,
+        radix: u32,
+        line: usize,
+        column: usize,
+        start: usize,
+    ) -> Error {
+        let span = Span::new(start, self.offset);
+        let is_valid = !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix));
+
+        if is_valid {
+            Error::IntegerOverflow { line, column, span }
+        } else {
+            Error::InvalidToken { line, column, span }
+        }
+    }
+
+    fn read_string(&mut self) -> Result<TokenKind, Error> {
+        let (start, line, column) = (self.offset, self.line, self.column);
         self.advance();
 
         let mut string = String::new();
@@ -174,8 +220,9 @@ impl<'a> Lexer<'a> {
 
                 let Some(c) = self.current_char() else {
                     return Err(Error::UnterminatedString {
-                        line: self.line,
-                        column: self.column,
+                        line,
+                        column,
+                        span: Span::new(start, self.offset),
                     });
                 };
 
@@ -190,6 +237,7 @@ impl<'a> Lexer<'a> {
                             char: Some(c),
                             line: self.line,
                             column: self.column,
+                            span: self.char_span(),
                         })
                     }
                 }
@@ -201,11 +249,7 @@ impl<'a> Lexer<'a> {
             if c == '"' {
                 self.advance();
 
-                return Ok(Token::new(
-                    TokenKind::String(string),
-                    self.line,
-                    self.column,
-                ));
+                return Ok(TokenKind::String(string));
             }
 
             string.push(c);
@@ -213,11 +257,35 @@ impl<'a> Lexer<'a> {
         }
 
         Err(Error::UnterminatedString {
-            line: self.line,
-            column: self.column,
+            line,
+            column,
+            span: Span::new(start, self.offset),
         })
     }
 
+    fn read_label(&mut self) -> Result<TokenKind, Error> {
+        let (line, column, span) = (self.line, self.column, self.char_span());
+        self.advance();
+
+        // The apostrophe has to be followed by a name, as in `'outer`.
+        if !self
+            .current_char()
+            .is_some_and(|c| c.is_alphabetic() || c == '_')
+        {
+            return Err(Error::UnexpectedCharacter {
+                char: Some('\''),
+                line,
+                column,
+                span,
+            });
+        }
+
+        let start = self.offset;
+        self.advance_while(|c| c.is_alphanumeric() || c == '_');
+
+        Ok(TokenKind::Label(self.input[start..self.offset].to_string()))
+    }
+
     fn handle_plus(&mut self) -> TokenKind {
         match self.next_char() {
             Some('=') => {
@@ -271,42 +339,48 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn handle_slash(&mut self) -> TokenKind {
+    fn handle_slash(&mut self) -> Result<TokenKind, Error> {
         match self.next_char() {
             Some('=') => {
                 self.advance();
 
-                TokenKind::DivisionAssign
+                Ok(TokenKind::DivisionAssign)
             }
             Some('/') => {
                 // Single-line comment, ignore until a new-line.
-                while let Some(c) = self.current_char() {
-                    if c == '\n' {
-                        break;
-                    }
-
+                while !matches!(self.next_char(), Some('\n') | None) {
                     self.advance();
                 }
 
-                TokenKind::Comment
+                Ok(TokenKind::Comment)
             }
             Some('*') => {
-                self.advance();
+                let (start, line, column) = (self.offset, self.line, self.column);
                 self.advance();
 
                 // Multi-line comment, ignore until a */ is found.
-                while self.current_char() != Some('*') && self.next_char() != Some('/') {
-                    // Advance to the next character.
+                loop {
                     self.advance();
+
+                    match (self.current_char(), self.next_char()) {
+                        (Some('*'), Some('/')) => break,
+                        (None, _) => {
+                            return Err(Error::UnterminatedComment {
+                                line,
+                                column,
+                                span: Span::new(start, self.offset),
+                            })
+                        }
+                        _ => {}
+                    }
                 }
 
-                // Skip over the */ by advancing twice.
-                self.advance();
+                // Stop at the '/', which is skipped along with the last character of any operator.
                 self.advance();
 
-                TokenKind::Comment
+                Ok(TokenKind::Comment)
             }
-            _ => TokenKind::Slash,
+            _ => Ok(TokenKind::Slash),
         }
     }
 
@@ -350,10 +424,26 @@ impl<'a> Lexer<'a> {
 
                 TokenKind::Equality
             }
+            Some('>') => {
+                self.advance();
+
+                TokenKind::FatArrow
+            }
             _ => TokenKind::Assign,
         }
     }
 
+    fn handle_colon(&mut self) -> TokenKind {
+        match self.next_char() {
+            Some(':') => {
+                self.advance();
+
+                TokenKind::DoubleColon
+            }
+            _ => TokenKind::Colon,
+        }
+    }
+
     fn handle_less_than(&mut self) -> TokenKind {
         match self.next_char() {
             Some('=') => {
@@ -430,13 +520,13 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn read_operator(&mut self) -> Result<Token, Error> {
+    fn read_operator(&mut self) -> Result<TokenKind, Error> {
         let current_char = self.current_char();
         let kind = match current_char {
             Some('+') => self.handle_plus(),
             Some('-') => self.handle_minus(),
             Some('*') => self.handle_asterisk(),
-            Some('/') => self.handle_slash(),
+            Some('/') => self.handle_slash()?,
             Some('%') => self.handle_percent(),
             Some('^') => self.handle_caret(),
             Some('!') => self.handle_bang(),
@@ -453,23 +543,22 @@ impl<'a> Lexer<'a> {
             Some(']') => TokenKind::RightBracket,
             Some(',') => TokenKind::Comma,
             Some('.') => TokenKind::Dot,
-            Some(':') => TokenKind::Colon,
+            Some(':') => self.handle_colon(),
             Some(';') => TokenKind::Semicolon,
             _ => {
                 return Err(Error::UnexpectedCharacter {
                     char: current_char,
                     line: self.line,
                     column: self.column,
+                    span: self.char_span(),
                 })?
             }
         };
 
-        let token = Token::new(kind, self.line, self.column);
-
         // Advance to the next character.
         self.advance();
 
-        Ok(token)
+        Ok(kind)
     }
 
     fn skip_whitespace(&mut self) {
@@ -486,63 +575,105 @@ impl<'a> Lexer<'a> {
         // Skip whitespace.
         self.skip_whitespace();
 
+        // The token starts here, whatever it turns out to be.
+        let (start, line, column) = (self.offset, self.line, self.column);
+
         let current_char = self.current_char();
-        let token = match current_char {
+        let kind = match current_char {
             Some(c) if c.is_alphabetic() || c == '_' => self.read_identifier(),
-            Some(c) if c.is_numeric() || c == '+' || c == '-' => {
-                // If we receive a None, then we know it's not a number, so we can read an operator.
-                let Some(token) = self.read_number()? else {
-                    return self.read_operator();
-                };
-
-                token
+            Some(c) if c.is_ascii_digit() => self.read_number()?,
+            // A sign is only part of a number where an operand is expected, so `a-1` is a
+            // subtraction while `a = -1` has a negative number.
+            Some('+' | '-')
+                if !self.after_operand && self.next_char().is_some_and(|c| c.is_ascii_digit()) =>
+            {
+                self.read_number()?
             }
             Some('"') => self.read_string()?,
-            None => Token::new(TokenKind::EndOfFile, self.line, self.column),
+            Some('\'') => self.read_label()?,
+            None => TokenKind::EndOfFile,
             _ => self.read_operator()?,
         };
 
-        Ok(token)
+        Ok(Token::new(
+            kind,
+            line,
+            column,
+            Span::new(start, self.offset),
+        ))
     }
 
-    const fn is_at_end(&self) -> bool {
-        self.position >= self.input.len()
-    }
-
-    /// Tokenizes the input.
+    /// Tokenizes the whole input at once.
     ///
     /// # Returns
     ///
-    /// * `Result<Vec<Token>, Error>` - The tokens, or an error.
+    /// * `Result<Vec<Token>, Error>` - The tokens, ending with an end-of-file token, or an error.
     ///
     /// # Errors
     ///
     /// * If an invalid token is found.
-    /// * If an unterminated string is found.
+    /// * If an unterminated string or comment is found.
     /// * If a number fails to parse.
     /// * If an unexpected character is found.
     pub fn tokenize(&mut self) -> Result<Vec<Token>, Error> {
-        let mut tokens = Vec::new();
-        while !self.is_at_end() {
-            let token = self.read_token()?;
-            if token.token_kind == TokenKind::Comment {
-                continue;
-            }
+        self.collect()
+    }
+}
+
+/// Parses the digits of an integer literal, applying its sign.
+///
+/// # Returns
+///
+/// * `Option<i64>` - The integer, or `None` if the digits are invalid or it doesn't fit in 64 bits.
+fn parse_integer(digits: &str, radix: u32, is_negative: bool) -> Option<i64> {
+    // The magnitude of `i64::MIN` doesn't fit in an `i64`, so the sign is applied to a wider type.
+    let magnitude = i128::from_str_radix(digits, radix).ok()?;
 
-            tokens.push(token);
+    i64::try_from(if is_negative { -magnitude } else { magnitude }).ok()
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Result<Token, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
         }
 
-        // Make sure the last token is an EOF token.
-        if let Some(token) = tokens.last() {
-            if token.token_kind != TokenKind::EndOfFile {
-                tokens.push(Token::new(TokenKind::EndOfFile, self.line, self.column));
+        // Comments are skipped.
+        let token = loop {
+            match self.read_token() {
+                Ok(token) if token.token_kind == TokenKind::Comment => {}
+                Ok(token) => break token,
+                Err(why) => {
+                    self.is_done = true;
+
+                    return Some(Err(why));
+                }
             }
-        }
+        };
 
-        Ok(tokens)
+        self.is_done = token.token_kind == TokenKind::EndOfFile;
+        self.after_operand = matches!(
+            token.token_kind,
+            TokenKind::Identifier(_)
+                | TokenKind::Integer(_)
+                | TokenKind::Float(_)
+                | TokenKind::String(_)
+                | TokenKind::True
+                | TokenKind::False
+                | TokenKind::RightParenthesis
+                | TokenKind::RightBracket
+                | TokenKind::Increment
+                | TokenKind::Decrement
+        );
+
+        Some(Ok(token))
     }
 }
 
+impl FusedIterator for Lexer<'_> {}
+
 /// Reads a `.lt` file and returns its contents.
 ///
 /// # Arguments
@@ -559,15 +690,17 @@ impl<'a> Lexer<'a> {
 /// * If the file doesn't end in `.lt`.
 /// * If the file can't be read.
 pub fn read_file(path: &str) -> Result<String, Error> {
-    let path = std::path::Path::new(path);
-
     // If the file doesn't exist, return an error.
-    if !path.exists() {
-        return Err(Error::InvalidFilePath);
+    if !std::path::Path::new(path).exists() {
+        return Err(Error::InvalidFilePath {
+            path: path.to_string(),
+        });
     }
 
+    let path = std::path::Path::new(path);
+
     // If the file doesn't end in ".lt", return an error.
-    if !path.extension().is_some_and(|ext| ext == "lt") {
+    if path.extension().is_none_or(|ext| ext != "lt") {
         return Err(Error::InvalidFileExtension);
     }
 
@@ -0,0 +1,169 @@
+/// A range of bytes in the source code.
+///
+/// # Fields
+///
+/// * `start` - The offset of the first byte.
+/// * `end` - The offset one past the last byte.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates a new span.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The offset of the first byte.
+    /// * `end` - The offset one past the last byte.
+    ///
+    /// # Returns
+    ///
+    /// * `Span` - The new span.
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates a span covering both this span and another one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other span.
+    ///
+    /// # Returns
+    ///
+    /// * `Span` - The combined span.
+    #[must_use]
+    pub const fn to(self, other: Self) -> Self {
+        let start = if self.start < other.start {
+            self.start
+        } else {
+            other.start
+        };
+        let end = if self.end > other.end {
+            self.end
+        } else {
+            other.end
+        };
+
+        Self { start, end }
+    }
+
+    /// Gets the number of bytes in the span.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The length of the span.
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Checks whether the span covers no bytes, such as the end of the file.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the span is empty.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Maps byte offsets in the source code to lines and columns.
+///
+/// # Fields
+///
+/// * `source` - The source code.
+/// * `line_starts` - The offset of the first byte of each line.
+#[derive(Debug, Clone)]
+pub struct SourceMap<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceMap<'a> {
+    /// Creates a new source map.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source code.
+    ///
+    /// # Returns
+    ///
+    /// * `SourceMap<'a>` - The new source map.
+    #[must_use]
+    pub fn new(source: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    /// Gets the line and column of a byte offset, both starting at 1.
+    ///
+    /// Columns are counted in characters, so they match what an editor shows.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset, which is clamped to the end of the source.
+    ///
+    /// # Returns
+    ///
+    /// * `(usize, usize)` - The line and column.
+    #[must_use]
+    pub fn location(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.source.len());
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let start = self.line_starts[line];
+
+        let column = self
+            .source
+            .get(start..offset)
+            .map_or(offset - start, |text| text.chars().count());
+
+        (line + 1, column + 1)
+    }
+
+    /// Gets the text of a line, without its new-line.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line number, starting at 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&'a str>` - The text of the line, or `None` if there's no such line.
+    #[must_use]
+    pub fn line(&self, line: usize) -> Option<&'a str> {
+        let start = *self.line_starts.get(line.checked_sub(1)?)?;
+        let end = self
+            .line_starts
+            .get(line)
+            .map_or(self.source.len(), |end| end - 1);
+
+        self.source
+            .get(start..end)
+            .map(|text| text.trim_end_matches('\r'))
+    }
+
+    /// Gets the source code a span covers.
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The span.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&'a str>` - The text, or `None` if the span is out of bounds.
+    #[must_use]
+    pub fn text(&self, span: Span) -> Option<&'a str> {
+        self.source.get(span.start..span.end)
+    }
+}
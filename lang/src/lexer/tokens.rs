@@ -1,3 +1,4 @@
+use crate::lexer::span::Span;
 use std::fmt::{Display, Formatter};
 
 /// A token kind, which is a type of token.
@@ -8,6 +9,7 @@ use std::fmt::{Display, Formatter};
 /// * `Integer(i64)` - An integer, such as `123` or `456`.
 /// * `Float(f64)` - A float, such as `1.23` or `4.56`.
 /// * `String(String)` - A string, such as `"foo"` or `"bar"`.
+/// * `Label(String)` - The label of a loop, such as `'outer`, without its apostrophe.
 /// * `Plus` - A plus sign, `+`.
 /// * `Increment` - An increment operator, `++`.
 /// * `AddAssign` - An add-assign operator, `+=`.
@@ -50,8 +52,10 @@ use std::fmt::{Display, Formatter};
 /// * `Comma` - A comma, `,`.
 /// * `Dot` - A dot, `.`.
 /// * `Colon` - A colon, `:`.
+/// * `DoubleColon` - A double colon, `::`.
 /// * `Semicolon` - A semicolon, `;`.
 /// * `Arrow` - An arrow, `->`.
+/// * `FatArrow` - A fat arrow, `=>`.
 /// * `True` - A true keyword, `true`.
 /// * `False` - A false keyword, `false`.
 /// * `If` - An if keyword, `if`.
@@ -65,6 +69,9 @@ use std::fmt::{Display, Formatter};
 /// * `Return` - A return keyword, `return`.
 /// * `Function` - A function keyword, `function`.
 /// * `Variable` - A variable keyword, `variable`.
+/// * `Struct` - A struct keyword, `struct`.
+/// * `Enum` - An enum keyword, `enum`.
+/// * `Match` - A match keyword, `match`.
 /// * `Comment` - A comment, such as `// ...` or `/* ... */`.
 /// * `EndOfFile` - An end-of-file token.
 #[derive(Debug, Clone, PartialEq)]
@@ -73,6 +80,7 @@ pub enum TokenKind {
     Integer(i64),
     Float(f64),
     String(String),
+    Label(String),
     Plus,
     Increment,
     AddAssign,
@@ -115,8 +123,10 @@ pub enum TokenKind {
     Comma,
     Dot,
     Colon,
+    DoubleColon,
     Semicolon,
     Arrow,
+    FatArrow,
     True,
     False,
     If,
@@ -130,6 +140,9 @@ pub enum TokenKind {
     Return,
     Function,
     Variable,
+    Struct,
+    Enum,
+    Match,
     Comment,
     EndOfFile,
 }
@@ -141,6 +154,7 @@ impl Display for TokenKind {
             Self::Integer(integer) => write!(f, "{integer}"),
             Self::Float(float) => write!(f, "{float}"),
             Self::String(string) => write!(f, "{string}"),
+            Self::Label(label) => write!(f, "'{label}"),
             Self::Plus => write!(f, "+"),
             Self::Increment => write!(f, "++"),
             Self::AddAssign => write!(f, "+="),
@@ -183,8 +197,10 @@ impl Display for TokenKind {
             Self::Comma => write!(f, ","),
             Self::Dot => write!(f, "."),
             Self::Colon => write!(f, ":"),
+            Self::DoubleColon => write!(f, "::"),
             Self::Semicolon => write!(f, ";"),
             Self::Arrow => write!(f, "->"),
+            Self::FatArrow => write!(f, "=>"),
             Self::True => write!(f, "true"),
             Self::False => write!(f, "false"),
             Self::If => write!(f, "if"),
@@ -198,23 +214,53 @@ impl Display for TokenKind {
             Self::Return => write!(f, "return"),
             Self::Function => write!(f, "function"),
             Self::Variable => write!(f, "variable"),
+            Self::Struct => write!(f, "struct"),
+            Self::Enum => write!(f, "enum"),
+            Self::Match => write!(f, "match"),
             Self::Comment | Self::EndOfFile => Ok(()),
         }
     }
 }
 
+impl TokenKind {
+    /// Gets the binary operator a compound assignment operator applies, such as `+` for `+=`.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<TokenKind>` - The binary operator, or `None` if this isn't a compound assignment
+    ///   operator.
+    #[must_use]
+    pub const fn compound_operator(&self) -> Option<Self> {
+        Some(match self {
+            Self::AddAssign => Self::Plus,
+            Self::SubtractAssign => Self::Minus,
+            Self::MultiplyAssign => Self::Star,
+            Self::DivisionAssign => Self::Slash,
+            Self::ModuloAssign => Self::Percent,
+            Self::BitwiseAndAssign => Self::BitwiseAnd,
+            Self::BitwiseOrAssign => Self::BitwiseOr,
+            Self::BitwiseXorAssign => Self::BitwiseXor,
+            Self::BitwiseLeftShiftAssign => Self::BitwiseLeftShift,
+            Self::BitwiseRightShiftAssign => Self::BitwiseRightShift,
+            _ => return None,
+        })
+    }
+}
+
 /// A token, which is a single unit of a program.
 ///
 /// # Fields
 ///
-/// * `line` - The line number of the token.
-/// * `column` - The column number of the token.
+/// * `line` - The line number of the start of the token.
+/// * `column` - The column number of the start of the token.
 /// * `token_kind` - The kind of token.
+/// * `span` - The bytes of the source code the token covers.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Token {
     pub line: usize,
     pub column: usize,
     pub token_kind: TokenKind,
+    pub span: Span,
 }
 
 impl Token {
@@ -223,18 +269,20 @@ impl Token {
     /// # Arguments
     ///
     /// * `kind` - The kind of token.
-    /// * `line` - The line number of the token.
-    /// * `column` - The column number of the token.
+    /// * `line` - The line number of the start of the token.
+    /// * `column` - The column number of the start of the token.
+    /// * `span` - The bytes of the source code the token covers.
     ///
     /// # Returns
     ///
     /// * `Token` - The new token.
     #[must_use]
-    pub const fn new(kind: TokenKind, line: usize, column: usize) -> Self {
+    pub const fn new(kind: TokenKind, line: usize, column: usize, span: Span) -> Self {
         Self {
             token_kind: kind,
             line,
             column,
+            span,
         }
     }
 }
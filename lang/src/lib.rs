@@ -1,8 +1,25 @@
 #[cfg(feature = "lexer")]
 pub mod lexer;
 
+#[cfg(feature = "lexer")]
+pub mod diagnostics;
+
 #[cfg(feature = "parser")]
 pub mod parser;
 
 #[cfg(feature = "semantics")]
 pub mod semantics;
+
+#[cfg(feature = "interpreter")]
+pub mod interpreter;
+
+/// The maximum number of nested function calls a program can make, on either backend.
+///
+/// The tree-walking interpreter recurses on the native stack for every call, so this is low enough
+/// for it to fail with an error rather than overflow a main thread's default stack, even in an
+/// unoptimized build.
+#[cfg(any(feature = "interpreter", feature = "bytecode"))]
+pub const MAX_CALL_DEPTH: usize = 256;
+
+#[cfg(feature = "bytecode")]
+pub mod bytecode;
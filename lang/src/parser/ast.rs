@@ -12,7 +12,7 @@ pub struct AST {
 
 impl AST {
     #[must_use]
-    pub fn new(statements: Vec<Statement>) -> Self {
+    pub const fn new(statements: Vec<Statement>) -> Self {
         Self { statements }
     }
 }
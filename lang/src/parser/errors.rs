@@ -1,3 +1,5 @@
+use crate::diagnostics::{Diagnostic, Label};
+use crate::lexer::span::Span;
 use std::num::ParseFloatError;
 use thiserror::Error;
 
@@ -10,6 +12,7 @@ use thiserror::Error;
 /// * `InvalidAssignmentTarget` - An invalid assignment target was encountered.
 /// * `ParseFloat` - Failed to parse a float.
 /// * `UnexpectedEndOfFile` - Unexpected end of file.
+/// * `Lexer` - The tokens being parsed couldn't be lexed.
 #[derive(Debug, Error, Eq, PartialEq)]
 pub enum Error {
     #[error("Unexpected token at line {line} and column {column}: {message}")]
@@ -17,13 +20,52 @@ pub enum Error {
         line: usize,
         column: usize,
         message: String,
+        span: Span,
     },
     #[error("Invalid function parameter length at line {line} and column {column}")]
-    FunctionParameterLength { line: usize, column: usize },
+    FunctionParameterLength {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
     #[error("Invalid assignment target at line {line} and column {column}!")]
-    InvalidAssignmentTarget { line: usize, column: usize },
+    InvalidAssignmentTarget {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
     #[error("Failed to parse float!")]
     ParseFloat(#[from] ParseFloatError),
     #[error("Unexpected end of file!")]
     UnexpectedEndOfFile,
+    #[error(transparent)]
+    Lexer(#[from] crate::lexer::errors::Error),
+}
+
+impl From<&Error> for Diagnostic {
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::UnexpectedToken { message, span, .. } if span.is_empty() => {
+                Self::error("E0101", message.clone())
+                    .with_label(Label::primary(*span, "unexpected end of file"))
+            }
+            Error::UnexpectedToken { message, span, .. } => Self::error("E0101", message.clone())
+                .with_label(Label::primary(*span, "unexpected token")),
+            Error::FunctionParameterLength { span, .. } => {
+                Self::error("E0102", "The function has too many parameters.")
+                    .with_label(Label::primary(*span, "too many parameters"))
+            }
+            Error::InvalidAssignmentTarget { span, .. } => {
+                Self::error("E0103", "Invalid assignment target.")
+                    .with_label(Label::primary(*span, "cannot assign to the left-hand side"))
+                    .with_help("only variables, array elements and fields can be assigned to")
+                    .with_note("compound assignments, '++' and '--' only apply to variables")
+            }
+            Error::ParseFloat(why) => {
+                Self::error("E0104", format!("Failed to parse float: {why}."))
+            }
+            Error::UnexpectedEndOfFile => Self::error("E0105", "Unexpected end of file."),
+            Error::Lexer(why) => Self::from(why),
+        }
+    }
 }
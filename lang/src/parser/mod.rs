@@ -1,26 +1,111 @@
 pub mod ast;
 pub mod errors;
 
+use crate::lexer::span::Span;
 use crate::lexer::tokens::{Token, TokenKind};
 use crate::parser::ast::AST;
 use errors::Error;
+use std::fmt::{self, Debug, Formatter};
 
 /// A literal expression.
 ///
 /// # Variants
 ///
 /// * `String` - A string literal.
-/// * `Number` - A number literal.
+/// * `Integer` - An integer literal.
+/// * `Float` - A float literal.
 /// * `Boolean` - A boolean literal.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Literal {
     String(String),
-    Number(f64),
+    Integer(i64),
+    Float(f64),
     Boolean(bool),
 }
 
+/// A type, as written in the source.
+///
+/// # Variants
+///
+/// * `Named` - A type referred to by its name, such as `int`.
+/// * `Array` - An array type, such as `[int]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeAnnotation {
+    Named(Token),
+    Array { element: Box<Self>, span: Span },
+}
+
+impl TypeAnnotation {
+    /// Gets the span of source code the type was parsed from.
+    ///
+    /// # Returns
+    ///
+    /// * `Span` - The span of the type.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::Named(token) => token.span,
+            Self::Array { span, .. } => *span,
+        }
+    }
+}
+
+/// A pattern which an arm of a `match` expression compares its value against.
+///
+/// # Variants
+///
+/// * `Literal` - A literal, which matches the values equal to it, such as `1` or `"a"`.
+/// * `Variant` - A variant of an enum, binding its values to names, such as `Shape::Circle(r)`.
+/// * `Wildcard` - A wildcard, `_`, which matches anything.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    Literal {
+        value: Literal,
+        span: Span,
+    },
+    Variant {
+        enumeration: Token,
+        variant: Token,
+        bindings: Vec<Token>,
+        span: Span,
+    },
+    Wildcard {
+        span: Span,
+    },
+}
+
+impl Pattern {
+    /// Gets the span of source code the pattern was parsed from.
+    ///
+    /// # Returns
+    ///
+    /// * `Span` - The span of the pattern.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::Literal { span, .. } | Self::Variant { span, .. } | Self::Wildcard { span } => {
+                *span
+            }
+        }
+    }
+}
+
+/// An arm of a `match` expression, such as `1 => "one"`.
+///
+/// # Fields
+///
+/// * `pattern` - The pattern the value is compared against.
+/// * `body` - The expression the arm evaluates to when its pattern matches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Arm {
+    pub pattern: Pattern,
+    pub body: Expression,
+}
+
 /// An expression.
 ///
+/// Every expression knows the span of source code it was parsed from.
+///
 /// # Variants
 ///
 /// * `Literal` - A literal expression.
@@ -28,105 +113,344 @@ pub enum Literal {
 /// * `Binary` - A binary expression.
 /// * `Grouping` - A grouping expression.
 /// * `Assignment` - An assignment expression.
+/// * `CompoundAssignment` - An assignment which applies an operator to the variable, such as `+=`.
+/// * `Update` - An increment or decrement of a variable, such as `i++` or `--i`.
 /// * `Variable` - A variable expression.
 /// * `Call` - A call expression.
+/// * `Array` - An array literal, such as `[1, 2, 3]`.
+/// * `Index` - An index into an array, such as `a[i]`.
+/// * `IndexAssignment` - An assignment to an element of an array, such as `a[i] = v`.
+/// * `Struct` - A struct literal, such as `Point { x: 1.0, y: 2.0 }`.
+/// * `Field` - A read of a field of a struct, such as `p.x`.
+/// * `MethodCall` - A call of a function on a receiver, such as `name.shout()`, which is the same
+///   as `shout(name)`.
+/// * `FieldAssignment` - An assignment to a field of a struct, such as `p.x = 1.0`.
+/// * `Variant` - A variant of an enum, along with its values, such as `Shape::Circle(1.0)`.
+/// * `Match` - A match expression, such as `match n { 1 => "one", _ => "many" }`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expression {
-    Literal(Literal),
+    Literal {
+        value: Literal,
+        span: Span,
+    },
     Unary {
         operator: Token,
-        right: Box<Expression>,
+        right: Box<Self>,
+        span: Span,
     },
     Binary {
-        left: Box<Expression>,
+        left: Box<Self>,
         operator: Token,
-        right: Box<Expression>,
+        right: Box<Self>,
+        span: Span,
     },
     Grouping {
-        expression: Box<Expression>,
+        expression: Box<Self>,
+        span: Span,
     },
     Assignment {
         name: Token,
-        value: Box<Expression>,
+        value: Box<Self>,
+        span: Span,
+    },
+    CompoundAssignment {
+        name: Token,
+        operator: Token,
+        value: Box<Self>,
+        span: Span,
+    },
+    Update {
+        name: Token,
+        operator: Token,
+        is_prefix: bool,
+        span: Span,
     },
     Variable {
         name: Token,
+        span: Span,
     },
     Call {
-        callee: Box<Expression>,
-        arguments: Vec<Expression>,
+        callee: Box<Self>,
+        arguments: Vec<Self>,
+        span: Span,
+    },
+    Array {
+        elements: Vec<Self>,
+        span: Span,
+    },
+    Index {
+        array: Box<Self>,
+        bracket: Token,
+        index: Box<Self>,
+        span: Span,
+    },
+    IndexAssignment {
+        array: Box<Self>,
+        bracket: Token,
+        index: Box<Self>,
+        value: Box<Self>,
+        span: Span,
+    },
+    Struct {
+        name: Token,
+        fields: Vec<(Token, Self)>,
+        span: Span,
+    },
+    Field {
+        object: Box<Self>,
+        name: Token,
+        span: Span,
+    },
+    FieldAssignment {
+        object: Box<Self>,
+        name: Token,
+        value: Box<Self>,
+        span: Span,
+    },
+    MethodCall {
+        receiver: Box<Self>,
+        name: Token,
+        arguments: Vec<Self>,
+        span: Span,
+    },
+    Variant {
+        enumeration: Token,
+        variant: Token,
+        arguments: Vec<Self>,
+        span: Span,
+    },
+    Match {
+        keyword: Token,
+        scrutinee: Box<Self>,
+        arms: Vec<Arm>,
+        span: Span,
     },
 }
 
+impl Expression {
+    /// Gets the span of source code the expression was parsed from.
+    ///
+    /// # Returns
+    ///
+    /// * `Span` - The span of the expression.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::Literal { span, .. }
+            | Self::Unary { span, .. }
+            | Self::Binary { span, .. }
+            | Self::Grouping { span, .. }
+            | Self::Assignment { span, .. }
+            | Self::CompoundAssignment { span, .. }
+            | Self::Update { span, .. }
+            | Self::Variable { span, .. }
+            | Self::Call { span, .. }
+            | Self::Array { span, .. }
+            | Self::Index { span, .. }
+            | Self::IndexAssignment { span, .. }
+            | Self::Struct { span, .. }
+            | Self::Field { span, .. }
+            | Self::FieldAssignment { span, .. }
+            | Self::MethodCall { span, .. }
+            | Self::Variant { span, .. }
+            | Self::Match { span, .. } => *span,
+        }
+    }
+
+    /// Gets the leftmost token of the expression, if it has one.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&Token>` - The token, or `None` for literals and array literals.
+    #[must_use]
+    pub fn first_token(&self) -> Option<&Token> {
+        match self {
+            Self::Literal { .. } | Self::Array { .. } => None,
+            Self::Unary { operator, .. } => Some(operator),
+            Self::Binary { left, operator, .. } => left.first_token().or(Some(operator)),
+            Self::Grouping { expression, .. } => expression.first_token(),
+            Self::Update {
+                name,
+                operator,
+                is_prefix,
+                ..
+            } => Some(if *is_prefix { operator } else { name }),
+            Self::Assignment { name, .. }
+            | Self::CompoundAssignment { name, .. }
+            | Self::Variable { name, .. }
+            | Self::Struct { name, .. }
+            | Self::Variant {
+                enumeration: name, ..
+            }
+            | Self::Match { keyword: name, .. } => Some(name),
+            Self::Call { callee, .. } => callee.first_token(),
+            Self::Index { array, bracket, .. } | Self::IndexAssignment { array, bracket, .. } => {
+                array.first_token().or(Some(bracket))
+            }
+            Self::Field { object, name, .. }
+            | Self::FieldAssignment { object, name, .. }
+            | Self::MethodCall {
+                receiver: object,
+                name,
+                ..
+            } => object.first_token().or(Some(name)),
+        }
+    }
+}
+
 /// A statement.
 ///
+/// Every statement knows the span of source code it was parsed from.
+///
 /// # Variants
 ///
 /// * `Expression` - An expression statement.
-/// * `Variable` - A variable statement.
+/// * `Variable` - A variable statement, such as `let x: int = 5;`, whose type is optional.
 /// * `Block` - A block statement.
 /// * `If` - An if statement.
-/// * `While` - A while statement.
-/// * `For` - A for statement.
-/// * `Break` - A break statement.
-/// * `Continue` - A continue statement.
+/// * `While` - A while statement, which may be labeled, such as `'outer: while (true) { ... }`.
+/// * `For` - A for statement, which may be labeled.
+/// * `ForRange` - A for statement over a range of numbers which includes both ends, such as
+///   `for i in 0 to 10 step 2`, which may be labeled.
+/// * `Break` - A break statement, which may name the loop it leaves, such as `break 'outer;`.
+/// * `Continue` - A continue statement, which may name the loop it continues.
 /// * `Return` - A return statement.
 /// * `Function` - A function statement.
+/// * `Struct` - A struct declaration, such as `struct Point { x: float, y: float }`.
+/// * `Enum` - An enum declaration, such as `enum Shape { Circle(float), Empty }`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Statement {
     Expression {
         expression: Expression,
+        span: Span,
     },
     Variable {
         name: Token,
+        type_annotation: Option<TypeAnnotation>,
         initializer: Option<Expression>,
+        span: Span,
     },
     Block {
-        statements: Vec<Statement>,
+        statements: Vec<Self>,
+        span: Span,
     },
     If {
         condition: Expression,
-        then_branch: Box<Statement>,
-        else_branch: Option<Box<Statement>>,
+        then_branch: Box<Self>,
+        else_branch: Option<Box<Self>>,
+        span: Span,
     },
     While {
+        label: Option<Token>,
         condition: Expression,
-        body: Box<Statement>,
+        body: Box<Self>,
+        span: Span,
     },
     For {
-        initializer: Option<Box<Statement>>,
+        label: Option<Token>,
+        initializer: Option<Box<Self>>,
         condition: Option<Expression>,
         increment: Option<Expression>,
-        body: Box<Statement>,
+        body: Box<Self>,
+        span: Span,
+    },
+    ForRange {
+        label: Option<Token>,
+        variable: Token,
+        start: Expression,
+        end: Expression,
+        step: Option<Box<Expression>>,
+        body: Box<Self>,
+        span: Span,
+    },
+    Break {
+        keyword: Token,
+        label: Option<Token>,
+        span: Span,
+    },
+    Continue {
+        keyword: Token,
+        label: Option<Token>,
+        span: Span,
     },
-    Break,
-    Continue,
     Return {
         keyword: Token,
         value: Option<Expression>,
+        span: Span,
     },
     Function {
         name: Token,
-        parameters: Vec<(Token, Token)>,
-        return_type: Option<Token>,
-        body: Box<Statement>,
+        parameters: Vec<(Token, TypeAnnotation)>,
+        return_type: Option<TypeAnnotation>,
+        body: Box<Self>,
+        span: Span,
+    },
+    Struct {
+        name: Token,
+        fields: Vec<(Token, TypeAnnotation)>,
+        span: Span,
+    },
+    Enum {
+        name: Token,
+        variants: Vec<(Token, Vec<TypeAnnotation>)>,
+        span: Span,
     },
 }
 
+impl Statement {
+    /// Gets the span of source code the statement was parsed from.
+    ///
+    /// # Returns
+    ///
+    /// * `Span` - The span of the statement.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::Expression { span, .. }
+            | Self::Variable { span, .. }
+            | Self::Block { span, .. }
+            | Self::If { span, .. }
+            | Self::While { span, .. }
+            | Self::For { span, .. }
+            | Self::ForRange { span, .. }
+            | Self::Break { span, .. }
+            | Self::Continue { span, .. }
+            | Self::Return { span, .. }
+            | Self::Function { span, .. }
+            | Self::Struct { span, .. }
+            | Self::Enum { span, .. } => *span,
+        }
+    }
+}
+
+/// A stream of tokens for the parser to pull from, such as a lexer.
+type Tokens<'a> = Box<dyn Iterator<Item = Result<Token, crate::lexer::errors::Error>> + 'a>;
+
 /// A parser for Lithium.
 ///
+/// Tokens are pulled from their source as they're needed, with a single token of lookahead, so
+/// only the current and previous tokens are kept in memory.
+///
 /// # Fields
 ///
-/// * `tokens` - The tokens to parse.
-/// * `current` - The index of the current token.
-#[derive(Debug)]
+/// * `tokens` - The tokens still to parse.
+/// * `current` - The current token, which hasn't been consumed yet.
+/// * `previous` - The last token consumed.
+/// * `consumed` - How many tokens have been consumed.
+/// * `struct_literals` - Whether a name followed by `{` starts a struct literal, which isn't the
+///   case where a block follows the expression, such as in the header of a range loop.
+/// * `errors` - The errors found so far.
 pub struct Parser<'a> {
-    tokens: &'a [Token],
-    current: usize,
+    tokens: Tokens<'a>,
+    current: Token,
+    previous: Token,
+    consumed: usize,
+    struct_literals: bool,
+    errors: Vec<Error>,
 }
 
 impl<'a> Parser<'a> {
-    /// Creates a new parser.
+    /// Creates a new parser for tokens which have already been lexed.
     ///
     /// # Arguments
     ///
@@ -136,28 +460,156 @@ impl<'a> Parser<'a> {
     ///
     /// * The new parser.
     #[must_use]
-    pub const fn new(tokens: &'a [Token]) -> Self {
-        Self { tokens, current: 0 }
+    pub fn new(tokens: &'a [Token]) -> Self {
+        Self::streaming(tokens.iter().cloned().map(Ok))
     }
 
-    /// Parses the tokens into an AST.
+    /// Creates a new parser which pulls its tokens from a stream as it goes, such as a lexer.
+    ///
+    /// An error in the stream is reported as an error of the parser, after which the stream is
+    /// treated as having ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The stream of tokens to parse.
     ///
     /// # Returns
     ///
-    /// * `Result<AST, Error>` - The generated AST, or an error.
+    /// * The new parser.
+    #[must_use]
+    pub fn streaming(
+        tokens: impl Iterator<Item = Result<Token, crate::lexer::errors::Error>> + 'a,
+    ) -> Self {
+        let start = Token::new(TokenKind::EndOfFile, 1, 1, Span::default());
+        let mut parser = Self {
+            tokens: Box::new(tokens),
+            current: start.clone(),
+            previous: start,
+            consumed: 0,
+            struct_literals: true,
+            errors: Vec::new(),
+        };
+        parser.current = parser.pull();
+
+        parser
+    }
+
+    /// Pulls the next token from the stream, or an end-of-file token if the stream has ended.
+    fn pull(&mut self) -> Token {
+        match self.tokens.next() {
+            Some(Ok(token)) => token,
+            Some(Err(why)) => {
+                self.errors.push(Error::Lexer(why));
+
+                // Nothing after a lexing error can be trusted, so stop there.
+                self.tokens = Box::new(std::iter::empty());
+                self.end_of_file()
+            }
+            None => self.end_of_file(),
+        }
+    }
+
+    fn end_of_file(&self) -> Token {
+        let end = self.current.span.end.max(self.previous.span.end);
+
+        Token::new(
+            TokenKind::EndOfFile,
+            self.current.line,
+            self.current.column,
+            Span::new(end, end),
+        )
+    }
+
+    /// Parses the tokens into an AST.
+    ///
+    /// When a statement fails to parse, the error is recorded and the parser skips ahead to the
+    /// next statement, so every error in the input is found in one go. The statements which did
+    /// parse make up a partial AST.
     ///
-    /// # Errors
+    /// # Returns
     ///
-    /// * `Error::UnexpectedToken` - The parser encountered an unexpected token.
-    /// * `Error::InvalidAssignmentTarget` - The parser encountered an invalid assignment target.
-    pub fn parse(&mut self) -> Result<AST, Error> {
+    /// * `(AST, Vec<Error>)` - The generated AST, and the errors found, which is empty if the input
+    ///   is valid. The errors can be:
+    ///   * `Error::UnexpectedToken` - The parser encountered an unexpected token.
+    ///   * `Error::InvalidAssignmentTarget` - The parser encountered an invalid assignment target.
+    pub fn parse(&mut self) -> (AST, Vec<Error>) {
         let mut statements = Vec::new();
 
         while !self.is_at_end() {
-            statements.push(self.declaration()?);
+            if let Some(statement) = self.recovering_declaration() {
+                statements.push(statement);
+            }
+        }
+
+        (AST::new(statements), std::mem::take(&mut self.errors))
+    }
+
+    /// Parses a declaration, recording the error and synchronizing if it fails.
+    fn recovering_declaration(&mut self) -> Option<Statement> {
+        let start = self.consumed;
+
+        match self.declaration() {
+            Ok(statement) => Some(statement),
+            Err(why) => {
+                // A statement cut short by a lexing error only fails because of that error.
+                let is_cut_short =
+                    self.is_at_end() && matches!(self.errors.last(), Some(Error::Lexer(_)));
+                if !is_cut_short {
+                    self.errors.push(why);
+                }
+
+                // Always make progress, even if the declaration failed on its first token.
+                if self.consumed == start {
+                    self.advance();
+                }
+                self.synchronize();
+
+                None
+            }
+        }
+    }
+
+    /// Skips tokens until the start of the next statement, to recover from an error.
+    fn synchronize(&mut self) {
+        while !self.is_at_end() {
+            if self.previous().token_kind == TokenKind::Semicolon {
+                return;
+            }
+
+            match self.peek().token_kind {
+                // The end of the enclosing block, which the block itself consumes.
+                TokenKind::RightCurlyBrace
+                | TokenKind::Function
+                | TokenKind::Variable
+                | TokenKind::Struct
+                | TokenKind::Enum
+                | TokenKind::If
+                | TokenKind::While
+                | TokenKind::For
+                | TokenKind::Return => return,
+                // A block belonging to the broken statement, which is skipped entirely.
+                TokenKind::LeftCurlyBrace => {
+                    self.skip_block();
+
+                    return;
+                }
+                _ => {
+                    self.advance();
+                }
+            }
         }
+    }
 
-        Ok(AST::new(statements))
+    fn skip_block(&mut self) {
+        let mut depth = 0;
+        while !self.is_at_end() {
+            match self.advance().token_kind {
+                TokenKind::LeftCurlyBrace => depth += 1,
+                TokenKind::RightCurlyBrace if depth == 1 => return,
+                TokenKind::RightCurlyBrace => depth -= 1,
+                _ => {}
+            }
+        }
     }
 
     fn declaration(&mut self) -> Result<Statement, Error> {
@@ -165,21 +617,26 @@ impl<'a> Parser<'a> {
             self.variable_declaration()
         } else if self.matches(&[TokenKind::Function]) {
             self.function_declaration()
+        } else if self.matches(&[TokenKind::Struct]) {
+            self.struct_declaration()
+        } else if self.matches(&[TokenKind::Enum]) {
+            self.enum_declaration()
         } else {
             self.statement()
         }
     }
 
     fn variable_declaration(&mut self) -> Result<Statement, Error> {
+        let keyword = self.previous().span;
         let name = match self.peek().token_kind {
             TokenKind::Identifier(_) => self.advance(),
-            _ => {
-                return Err(Error::UnexpectedToken {
-                    line: self.peek().line,
-                    column: self.peek().column,
-                    message: "Expected variable name.".to_string(),
-                })
-            }
+            _ => return Err(self.unexpected("Expected variable name.")),
+        };
+
+        let type_annotation = if self.matches(&[TokenKind::Colon]) {
+            Some(self.type_annotation("Expected variable type.")?)
+        } else {
+            None
         };
 
         let initializer = if self.matches(&[TokenKind::Assign]) {
@@ -188,24 +645,24 @@ impl<'a> Parser<'a> {
             None
         };
 
-        self.consume(
+        let semicolon = self.consume(
             &TokenKind::Semicolon,
             "Expected ';' after variable declaration.",
         )?;
 
-        Ok(Statement::Variable { name, initializer })
+        Ok(Statement::Variable {
+            name,
+            type_annotation,
+            initializer,
+            span: keyword.to(semicolon.span),
+        })
     }
 
     fn function_declaration(&mut self) -> Result<Statement, Error> {
+        let keyword = self.previous().span;
         let name = match self.peek().token_kind {
             TokenKind::Identifier(_) => self.advance(),
-            _ => {
-                return Err(Error::UnexpectedToken {
-                    line: self.peek().line,
-                    column: self.peek().column,
-                    message: "Expected function name.".to_string(),
-                })
-            }
+            _ => return Err(self.unexpected("Expected function name.")),
         };
         self.consume(
             &TokenKind::LeftParenthesis,
@@ -217,25 +674,10 @@ impl<'a> Parser<'a> {
             loop {
                 let name = match self.peek().token_kind {
                     TokenKind::Identifier(_) => self.advance(),
-                    _ => {
-                        return Err(Error::UnexpectedToken {
-                            line: self.peek().line,
-                            column: self.peek().column,
-                            message: "Expected parameter name.".to_string(),
-                        })
-                    }
+                    _ => return Err(self.unexpected("Expected parameter name.")),
                 };
                 self.consume(&TokenKind::Colon, "Expected ':' after parameter name.")?;
-                let r#type = match self.peek().token_kind {
-                    TokenKind::Identifier(_) => self.advance(),
-                    _ => {
-                        return Err(Error::UnexpectedToken {
-                            line: self.peek().line,
-                            column: self.peek().column,
-                            message: "Expected parameter type.".to_string(),
-                        })
-                    }
-                };
+                let r#type = self.type_annotation("Expected parameter type.")?;
 
                 parameters.push((name, r#type));
 
@@ -252,16 +694,7 @@ impl<'a> Parser<'a> {
 
         // Return type, if any.
         let return_type = if self.matches(&[TokenKind::Arrow]) {
-            Some(match self.peek().token_kind {
-                TokenKind::Identifier(_) => self.advance(),
-                _ => {
-                    return Err(Error::UnexpectedToken {
-                        line: self.peek().line,
-                        column: self.peek().column,
-                        message: "Expected return type.".to_string(),
-                    })
-                }
-            })
+            Some(self.type_annotation("Expected return type.")?)
         } else {
             None
         };
@@ -277,19 +710,163 @@ impl<'a> Parser<'a> {
             name,
             parameters,
             return_type,
+            span: keyword.to(body.span()),
             body: Box::new(body),
         })
     }
 
+    fn struct_declaration(&mut self) -> Result<Statement, Error> {
+        let keyword = self.previous().span;
+        let name = match self.peek().token_kind {
+            TokenKind::Identifier(_) => self.advance(),
+            _ => return Err(self.unexpected("Expected struct name.")),
+        };
+        self.consume(
+            &TokenKind::LeftCurlyBrace,
+            "Expected '{' after struct name.",
+        )?;
+
+        let mut fields = Vec::new();
+        while !self.check(&TokenKind::RightCurlyBrace) {
+            match self.struct_field() {
+                Ok(field) => fields.push(field),
+                Err(why) => {
+                    // The rest of the fields are skipped, so that the struct itself still parses.
+                    self.errors.push(why);
+                    while !self.is_at_end() && !self.check(&TokenKind::RightCurlyBrace) {
+                        self.advance();
+                    }
+
+                    break;
+                }
+            }
+
+            // The last field may be followed by a comma too.
+            if !self.matches(&[TokenKind::Comma]) {
+                break;
+            }
+        }
+
+        let right_brace =
+            self.consume(&TokenKind::RightCurlyBrace, "Expected '}' after fields.")?;
+
+        Ok(Statement::Struct {
+            name,
+            fields,
+            span: keyword.to(right_brace.span),
+        })
+    }
+
+    /// Parses a field of a struct declaration, such as `x: float`.
+    fn struct_field(&mut self) -> Result<(Token, TypeAnnotation), Error> {
+        let field = match self.peek().token_kind {
+            TokenKind::Identifier(_) => self.advance(),
+            _ => return Err(self.unexpected("Expected field name.")),
+        };
+        self.consume(&TokenKind::Colon, "Expected ':' after field name.")?;
+        let r#type = self.type_annotation("Expected field type.")?;
+
+        Ok((field, r#type))
+    }
+
+    fn enum_declaration(&mut self) -> Result<Statement, Error> {
+        let keyword = self.previous().span;
+        let name = match self.peek().token_kind {
+            TokenKind::Identifier(_) => self.advance(),
+            _ => return Err(self.unexpected("Expected enum name.")),
+        };
+        self.consume(&TokenKind::LeftCurlyBrace, "Expected '{' after enum name.")?;
+
+        let mut variants = Vec::new();
+        while !self.check(&TokenKind::RightCurlyBrace) {
+            match self.enum_variant() {
+                Ok(variant) => variants.push(variant),
+                Err(why) => {
+                    // The rest of the variants are skipped, so that the enum itself still parses.
+                    self.errors.push(why);
+                    while !self.is_at_end() && !self.check(&TokenKind::RightCurlyBrace) {
+                        self.advance();
+                    }
+
+                    break;
+                }
+            }
+
+            // The last variant may be followed by a comma too.
+            if !self.matches(&[TokenKind::Comma]) {
+                break;
+            }
+        }
+
+        let right_brace =
+            self.consume(&TokenKind::RightCurlyBrace, "Expected '}' after variants.")?;
+
+        Ok(Statement::Enum {
+            name,
+            variants,
+            span: keyword.to(right_brace.span),
+        })
+    }
+
+    /// Parses a variant of an enum declaration, such as `Circle(float)` or `Empty`.
+    fn enum_variant(&mut self) -> Result<(Token, Vec<TypeAnnotation>), Error> {
+        let variant = match self.peek().token_kind {
+            TokenKind::Identifier(_) => self.advance(),
+            _ => return Err(self.unexpected("Expected variant name.")),
+        };
+
+        let mut payload = Vec::new();
+        if self.matches(&[TokenKind::LeftParenthesis]) {
+            loop {
+                payload.push(self.type_annotation("Expected variant type.")?);
+
+                if !self.matches(&[TokenKind::Comma]) {
+                    break;
+                }
+            }
+
+            self.consume(
+                &TokenKind::RightParenthesis,
+                "Expected ')' after variant types.",
+            )?;
+        }
+
+        Ok((variant, payload))
+    }
+
+    /// Parses a type, such as `int` or `[str]`.
+    fn type_annotation(&mut self, message: &str) -> Result<TypeAnnotation, Error> {
+        if self.matches(&[TokenKind::LeftBracket]) {
+            let left_bracket = self.previous().span;
+            let element = self.type_annotation(message)?;
+            let right_bracket = self.consume(
+                &TokenKind::RightBracket,
+                "Expected ']' after array element type.",
+            )?;
+
+            return Ok(TypeAnnotation::Array {
+                element: Box::new(element),
+                span: left_bracket.to(right_bracket.span),
+            });
+        }
+
+        match self.peek().token_kind {
+            TokenKind::Identifier(_) => Ok(TypeAnnotation::Named(self.advance())),
+            _ => Err(self.unexpected(message)),
+        }
+    }
+
     fn statement(&mut self) -> Result<Statement, Error> {
         if self.matches(&[TokenKind::LeftCurlyBrace]) {
             self.block()
         } else if self.matches(&[TokenKind::If]) {
             self.if_statement()
         } else if self.matches(&[TokenKind::While]) {
-            self.while_statement()
+            self.while_statement(None)
         } else if self.matches(&[TokenKind::For]) {
-            self.for_statement()
+            self.for_statement(None)
+        } else if matches!(self.peek().token_kind, TokenKind::Label(_)) {
+            self.labeled_loop()
         } else if self.matches(&[TokenKind::Break]) {
             self.break_statement()
         } else if self.matches(&[TokenKind::Continue]) {
@@ -302,18 +879,25 @@ impl<'a> Parser<'a> {
     }
 
     fn block(&mut self) -> Result<Statement, Error> {
+        let left_brace = self.previous().span;
         let mut statements = Vec::new();
 
         while !self.check(&TokenKind::RightCurlyBrace) && !self.is_at_end() {
-            statements.push(self.declaration()?);
+            if let Some(statement) = self.recovering_declaration() {
+                statements.push(statement);
+            }
         }
 
-        self.consume(&TokenKind::RightCurlyBrace, "Expected '}' after block.")?;
+        let right_brace = self.consume(&TokenKind::RightCurlyBrace, "Expected '}' after block.")?;
 
-        Ok(Statement::Block { statements })
+        Ok(Statement::Block {
+            statements,
+            span: left_brace.to(right_brace.span),
+        })
     }
 
     fn if_statement(&mut self) -> Result<Statement, Error> {
+        let keyword = self.previous().span;
         self.consume(&TokenKind::LeftParenthesis, "Expected '(' after 'if'.")?;
 
         let condition = self.expression()?;
@@ -330,14 +914,36 @@ impl<'a> Parser<'a> {
             None
         };
 
+        let span = keyword.to(else_branch.as_ref().unwrap_or(&then_branch).span());
+
         Ok(Statement::If {
             condition,
             then_branch,
             else_branch,
+            span,
         })
     }
 
-    fn while_statement(&mut self) -> Result<Statement, Error> {
+    /// Parses a loop with a label, such as `'outer: while (true) { ... }`.
+    fn labeled_loop(&mut self) -> Result<Statement, Error> {
+        let label = self.advance();
+        self.consume(&TokenKind::Colon, "Expected ':' after label.")?;
+
+        if self.matches(&[TokenKind::While]) {
+            self.while_statement(Some(label))
+        } else if self.matches(&[TokenKind::For]) {
+            self.for_statement(Some(label))
+        } else {
+            Err(self.unexpected("Expected loop after label."))
+        }
+    }
+
+    /// Parses a while loop after the `while` keyword, where the loop starts at its label if it
+    /// has one.
+    fn while_statement(&mut self, label: Option<Token>) -> Result<Statement, Error> {
+        let keyword = label
+            .as_ref()
+            .map_or_else(|| self.previous().span, |label| label.span);
         self.consume(&TokenKind::LeftParenthesis, "Expected '(' after 'while'.")?;
 
         let condition = self.expression()?;
@@ -349,10 +955,24 @@ impl<'a> Parser<'a> {
 
         let body = Box::new(self.statement()?);
 
-        Ok(Statement::While { condition, body })
+        Ok(Statement::While {
+            label,
+            condition,
+            span: keyword.to(body.span()),
+            body,
+        })
     }
 
-    fn for_statement(&mut self) -> Result<Statement, Error> {
+    /// Parses a for loop after the `for` keyword, where the loop starts at its label if it has
+    /// one.
+    fn for_statement(&mut self, label: Option<Token>) -> Result<Statement, Error> {
+        let keyword = label
+            .as_ref()
+            .map_or_else(|| self.previous().span, |label| label.span);
+        if matches!(self.peek().token_kind, TokenKind::Identifier(_)) {
+            return self.for_range_statement(keyword, label);
+        }
+
         self.consume(&TokenKind::LeftParenthesis, "Expected '(' after 'for'.")?;
 
         let initializer = if self.matches(&[TokenKind::Semicolon]) {
@@ -385,23 +1005,78 @@ impl<'a> Parser<'a> {
         let body = Box::new(self.statement()?);
 
         Ok(Statement::For {
+            label,
             initializer,
             condition,
             increment,
+            span: keyword.to(body.span()),
+            body,
+        })
+    }
+
+    /// Parses the rest of a `for variable in start to end step step` loop, where `step` is a
+    /// contextual keyword so that it can still be used as a name.
+    fn for_range_statement(
+        &mut self,
+        keyword: Span,
+        label: Option<Token>,
+    ) -> Result<Statement, Error> {
+        let variable = self.advance();
+        self.consume(&TokenKind::Range, "Expected 'in' after loop variable.")?;
+
+        // The body follows the range, so a name followed by `{` isn't a struct literal there.
+        let start = self.expression_with_struct_literals(false)?;
+        self.consume(&TokenKind::To, "Expected 'to' after range start.")?;
+        let end = self.expression_with_struct_literals(false)?;
+
+        let step = if self.check(&TokenKind::Identifier("step".to_string())) {
+            self.advance();
+
+            Some(Box::new(self.expression_with_struct_literals(false)?))
+        } else {
+            None
+        };
+
+        let body = Box::new(self.statement()?);
+
+        Ok(Statement::ForRange {
+            label,
+            variable,
+            start,
+            end,
+            step,
+            span: keyword.to(body.span()),
             body,
         })
     }
 
     fn break_statement(&mut self) -> Result<Statement, Error> {
-        self.consume(&TokenKind::Semicolon, "Expected ';' after 'break'.")?;
+        let keyword = self.previous().clone();
+        let label = self.label();
+        let semicolon = self.consume(&TokenKind::Semicolon, "Expected ';' after 'break'.")?;
 
-        Ok(Statement::Break)
+        Ok(Statement::Break {
+            span: keyword.span.to(semicolon.span),
+            keyword,
+            label,
+        })
     }
 
     fn continue_statement(&mut self) -> Result<Statement, Error> {
-        self.consume(&TokenKind::Semicolon, "Expected ';' after 'continue'.")?;
+        let keyword = self.previous().clone();
+        let label = self.label();
+        let semicolon = self.consume(&TokenKind::Semicolon, "Expected ';' after 'continue'.")?;
+
+        Ok(Statement::Continue {
+            span: keyword.span.to(semicolon.span),
+            keyword,
+            label,
+        })
+    }
 
-        Ok(Statement::Continue)
+    /// Parses the label a `break` or `continue` targets, if it names one.
+    fn label(&mut self) -> Option<Token> {
+        matches!(self.peek().token_kind, TokenKind::Label(_)).then(|| self.advance())
     }
 
     fn return_statement(&mut self) -> Result<Statement, Error> {
@@ -413,41 +1088,122 @@ impl<'a> Parser<'a> {
             Some(self.expression()?)
         };
 
-        self.consume(&TokenKind::Semicolon, "Expected ';' after return value.")?;
+        let semicolon = self.consume(&TokenKind::Semicolon, "Expected ';' after return value.")?;
 
-        Ok(Statement::Return { keyword, value })
+        Ok(Statement::Return {
+            span: keyword.span.to(semicolon.span),
+            keyword,
+            value,
+        })
     }
 
     fn expression_statement(&mut self) -> Result<Statement, Error> {
         let expression = self.expression()?;
 
-        self.consume(&TokenKind::Semicolon, "Expected ';' after expression.")?;
+        // A match on its own ends with a brace, so it doesn't need a semicolon, just like a block.
+        if matches!(expression, Expression::Match { .. }) && !self.check(&TokenKind::Semicolon) {
+            return Ok(Statement::Expression {
+                span: expression.span(),
+                expression,
+            });
+        }
+
+        let semicolon = self.consume(&TokenKind::Semicolon, "Expected ';' after expression.")?;
 
-        Ok(Statement::Expression { expression })
+        Ok(Statement::Expression {
+            span: expression.span().to(semicolon.span),
+            expression,
+        })
     }
 
     fn expression(&mut self) -> Result<Expression, Error> {
         self.assignment()
     }
 
+    /// Parses an expression where struct literals are allowed or not, such as between brackets,
+    /// restoring whether they were allowed before afterwards.
+    fn expression_with_struct_literals(&mut self, allowed: bool) -> Result<Expression, Error> {
+        let previous = std::mem::replace(&mut self.struct_literals, allowed);
+        let expression = self.expression();
+        self.struct_literals = previous;
+
+        expression
+    }
+
     fn assignment(&mut self) -> Result<Expression, Error> {
         let expression = self.or()?;
 
-        if self.matches(&[TokenKind::Assign]) {
-            let equals = self.previous().clone();
+        if self.matches(&[
+            TokenKind::Assign,
+            TokenKind::AddAssign,
+            TokenKind::SubtractAssign,
+            TokenKind::MultiplyAssign,
+            TokenKind::DivisionAssign,
+            TokenKind::ModuloAssign,
+            TokenKind::BitwiseAndAssign,
+            TokenKind::BitwiseOrAssign,
+            TokenKind::BitwiseXorAssign,
+            TokenKind::BitwiseLeftShiftAssign,
+            TokenKind::BitwiseRightShiftAssign,
+        ]) {
+            let operator = self.previous().clone();
             let value = self.assignment()?;
 
-            if let Expression::Variable { name } = expression {
-                return Ok(Expression::Assignment {
-                    name,
-                    value: Box::new(value),
+            // Elements of arrays and fields of structs can only be assigned to with a plain `=`.
+            if operator.token_kind == TokenKind::Assign {
+                let span = expression.span().to(value.span());
+
+                match expression {
+                    Expression::Index {
+                        array,
+                        bracket,
+                        index,
+                        ..
+                    } => {
+                        return Ok(Expression::IndexAssignment {
+                            array,
+                            bracket,
+                            index,
+                            value: Box::new(value),
+                            span,
+                        });
+                    }
+                    Expression::Field { object, name, .. } => {
+                        return Ok(Expression::FieldAssignment {
+                            object,
+                            name,
+                            value: Box::new(value),
+                            span,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Expression::Variable { name, span } = expression {
+                let span = span.to(value.span());
+                let value = Box::new(value);
+
+                return Ok(if operator.token_kind == TokenKind::Assign {
+                    Expression::Assignment { name, value, span }
+                } else {
+                    Expression::CompoundAssignment {
+                        name,
+                        operator,
+                        value,
+                        span,
+                    }
                 });
             }
 
-            return Err(Error::InvalidAssignmentTarget {
-                line: equals.line,
-                column: equals.column,
+            // The parser isn't confused by this, so there's no need to synchronize.
+            self.errors.push(Error::InvalidAssignmentTarget {
+                line: operator.line,
+                column: operator.column,
+                span: operator.span,
             });
+
+            return Ok(expression);
         }
 
         Ok(expression)
@@ -461,6 +1217,7 @@ impl<'a> Parser<'a> {
             let right = self.and()?;
 
             expression = Expression::Binary {
+                span: expression.span().to(right.span()),
                 left: Box::new(expression),
                 operator,
                 right: Box::new(right),
@@ -471,13 +1228,68 @@ impl<'a> Parser<'a> {
     }
 
     fn and(&mut self) -> Result<Expression, Error> {
-        let mut expression = self.equality()?;
+        let mut expression = self.bitwise_or()?;
 
         while self.matches(&[TokenKind::LogicalAnd]) {
+            let operator = self.previous().clone();
+            let right = self.bitwise_or()?;
+
+            expression = Expression::Binary {
+                span: expression.span().to(right.span()),
+                left: Box::new(expression),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expression)
+    }
+
+    fn bitwise_or(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.bitwise_xor()?;
+
+        while self.matches(&[TokenKind::BitwiseOr]) {
+            let operator = self.previous().clone();
+            let right = self.bitwise_xor()?;
+
+            expression = Expression::Binary {
+                span: expression.span().to(right.span()),
+                left: Box::new(expression),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expression)
+    }
+
+    fn bitwise_xor(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.bitwise_and()?;
+
+        while self.matches(&[TokenKind::BitwiseXor]) {
+            let operator = self.previous().clone();
+            let right = self.bitwise_and()?;
+
+            expression = Expression::Binary {
+                span: expression.span().to(right.span()),
+                left: Box::new(expression),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expression)
+    }
+
+    fn bitwise_and(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.equality()?;
+
+        while self.matches(&[TokenKind::BitwiseAnd]) {
             let operator = self.previous().clone();
             let right = self.equality()?;
 
             expression = Expression::Binary {
+                span: expression.span().to(right.span()),
                 left: Box::new(expression),
                 operator,
                 right: Box::new(right),
@@ -495,6 +1307,7 @@ impl<'a> Parser<'a> {
             let right = self.comparison()?;
 
             expression = Expression::Binary {
+                span: expression.span().to(right.span()),
                 left: Box::new(expression),
                 operator,
                 right: Box::new(right),
@@ -505,7 +1318,7 @@ impl<'a> Parser<'a> {
     }
 
     fn comparison(&mut self) -> Result<Expression, Error> {
-        let mut expression = self.term()?;
+        let mut expression = self.shift()?;
 
         while self.matches(&[
             TokenKind::LessThan,
@@ -513,10 +1326,29 @@ impl<'a> Parser<'a> {
             TokenKind::GreaterThan,
             TokenKind::GreaterThanOrEqual,
         ]) {
+            let operator = self.previous().clone();
+            let right = self.shift()?;
+
+            expression = Expression::Binary {
+                span: expression.span().to(right.span()),
+                left: Box::new(expression),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expression)
+    }
+
+    fn shift(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.term()?;
+
+        while self.matches(&[TokenKind::BitwiseLeftShift, TokenKind::BitwiseRightShift]) {
             let operator = self.previous().clone();
             let right = self.term()?;
 
             expression = Expression::Binary {
+                span: expression.span().to(right.span()),
                 left: Box::new(expression),
                 operator,
                 right: Box::new(right),
@@ -534,6 +1366,7 @@ impl<'a> Parser<'a> {
             let right = self.factor()?;
 
             expression = Expression::Binary {
+                span: expression.span().to(right.span()),
                 left: Box::new(expression),
                 operator,
                 right: Box::new(right),
@@ -551,6 +1384,7 @@ impl<'a> Parser<'a> {
             let right = self.unary()?;
 
             expression = Expression::Binary {
+                span: expression.span().to(right.span()),
                 left: Box::new(expression),
                 operator,
                 right: Box::new(right),
@@ -566,12 +1400,92 @@ impl<'a> Parser<'a> {
             let right = self.unary()?;
 
             return Ok(Expression::Unary {
+                span: operator.span.to(right.span()),
                 operator,
                 right: Box::new(right),
             });
         }
 
-        self.call()
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Expression, Error> {
+        let first = self.peek().clone();
+        let base = self.prefix()?;
+
+        if !self.matches(&[TokenKind::Power]) {
+            return Ok(base);
+        }
+
+        let operator = self.previous().clone();
+        // The exponent is parsed as a unary expression, which makes the operator right-associative.
+        let exponent = self.unary()?;
+
+        // The lexer folds a leading minus into number literals, but `-2 ** 2` means `-(2 ** 2)`.
+        let (sign, base) = split_sign(&first, base);
+        let power = Expression::Binary {
+            span: base.span().to(exponent.span()),
+            left: Box::new(base),
+            operator,
+            right: Box::new(exponent),
+        };
+
+        Ok(match sign {
+            Some(operator) => Expression::Unary {
+                span: operator.span.to(power.span()),
+                operator,
+                right: Box::new(power),
+            },
+            None => power,
+        })
+    }
+
+    fn prefix(&mut self) -> Result<Expression, Error> {
+        if self.matches(&[TokenKind::Increment, TokenKind::Decrement]) {
+            let operator = self.previous().clone();
+            let target = self.prefix()?;
+
+            return Ok(self.update(operator, target, true));
+        }
+
+        self.postfix()
+    }
+
+    fn postfix(&mut self) -> Result<Expression, Error> {
+        let expression = self.call()?;
+
+        if self.matches(&[TokenKind::Increment, TokenKind::Decrement]) {
+            let operator = self.previous().clone();
+
+            return Ok(self.update(operator, expression, false));
+        }
+
+        Ok(expression)
+    }
+
+    /// Builds an increment or decrement of a target, which has to be a variable.
+    fn update(&mut self, operator: Token, target: Expression, is_prefix: bool) -> Expression {
+        let Expression::Variable { name, span } = target else {
+            // The parser isn't confused by this, so there's no need to synchronize.
+            self.errors.push(Error::InvalidAssignmentTarget {
+                line: operator.line,
+                column: operator.column,
+                span: operator.span,
+            });
+
+            return target;
+        };
+
+        Expression::Update {
+            span: if is_prefix {
+                operator.span.to(span)
+            } else {
+                span.to(operator.span)
+            },
+            name,
+            operator,
+            is_prefix,
+        }
     }
 
     fn call(&mut self) -> Result<Expression, Error> {
@@ -580,6 +1494,40 @@ impl<'a> Parser<'a> {
         loop {
             if self.matches(&[TokenKind::LeftParenthesis]) {
                 expression = self.finish_call(expression)?;
+            } else if self.matches(&[TokenKind::LeftBracket]) {
+                let bracket = self.previous().clone();
+                let index = self.expression_with_struct_literals(true)?;
+                let right_bracket =
+                    self.consume(&TokenKind::RightBracket, "Expected ']' after index.")?;
+
+                expression = Expression::Index {
+                    span: expression.span().to(right_bracket.span),
+                    array: Box::new(expression),
+                    bracket,
+                    index: Box::new(index),
+                };
+            } else if self.matches(&[TokenKind::Dot]) {
+                let name = match self.peek().token_kind {
+                    TokenKind::Identifier(_) => self.advance(),
+                    _ => return Err(self.unexpected("Expected field name after '.'.")),
+                };
+
+                expression = if self.matches(&[TokenKind::LeftParenthesis]) {
+                    let (arguments, right_parenthesis) = self.arguments()?;
+
+                    Expression::MethodCall {
+                        span: expression.span().to(right_parenthesis.span),
+                        receiver: Box::new(expression),
+                        name,
+                        arguments,
+                    }
+                } else {
+                    Expression::Field {
+                        span: expression.span().to(name.span),
+                        object: Box::new(expression),
+                        name,
+                    }
+                };
             } else {
                 break;
             }
@@ -589,11 +1537,22 @@ impl<'a> Parser<'a> {
     }
 
     fn finish_call(&mut self, callee: Expression) -> Result<Expression, Error> {
+        let (arguments, right_parenthesis) = self.arguments()?;
+
+        Ok(Expression::Call {
+            span: callee.span().to(right_parenthesis.span),
+            callee: Box::new(callee),
+            arguments,
+        })
+    }
+
+    /// Parses the arguments of a call after the `(`, returning them along with the `)`.
+    fn arguments(&mut self) -> Result<(Vec<Expression>, Token), Error> {
         let mut arguments = Vec::new();
 
         if !self.check(&TokenKind::RightParenthesis) {
             loop {
-                arguments.push(self.expression()?);
+                arguments.push(self.expression_with_struct_literals(true)?);
 
                 if !self.matches(&[TokenKind::Comma]) {
                     break;
@@ -601,73 +1560,258 @@ impl<'a> Parser<'a> {
             }
         }
 
-        self.consume(
+        let right_parenthesis = self.consume(
             &TokenKind::RightParenthesis,
             "Expected ')' after arguments.",
         )?;
 
-        Ok(Expression::Call {
-            callee: Box::new(callee),
-            arguments,
-        })
+        Ok((arguments, right_parenthesis))
     }
 
-    #[allow(clippy::cast_precision_loss)]
     fn primary(&mut self) -> Result<Expression, Error> {
         let next = self.peek().clone();
-        match next.token_kind {
-            TokenKind::False => {
+        let value = match next.token_kind {
+            TokenKind::False => Literal::Boolean(false),
+            TokenKind::True => Literal::Boolean(true),
+            TokenKind::Float(value) => Literal::Float(value),
+            TokenKind::Integer(value) => Literal::Integer(value),
+            TokenKind::String(value) => Literal::String(value),
+            TokenKind::Identifier(_) => {
                 self.advance();
 
-                Ok(Expression::Literal(Literal::Boolean(false)))
+                if self.matches(&[TokenKind::DoubleColon]) {
+                    return self.variant(next);
+                }
+                if self.struct_literals && self.matches(&[TokenKind::LeftCurlyBrace]) {
+                    return self.struct_literal(next);
+                }
+
+                return Ok(Expression::Variable {
+                    span: next.span,
+                    name: next,
+                });
             }
-            TokenKind::True => {
+            TokenKind::LeftBracket => {
                 self.advance();
 
-                Ok(Expression::Literal(Literal::Boolean(true)))
+                let mut elements = Vec::new();
+                if !self.check(&TokenKind::RightBracket) {
+                    loop {
+                        elements.push(self.expression_with_struct_literals(true)?);
+
+                        if !self.matches(&[TokenKind::Comma]) {
+                            break;
+                        }
+                    }
+                }
+
+                let right_bracket = self.consume(
+                    &TokenKind::RightBracket,
+                    "Expected ']' after array elements.",
+                )?;
+
+                return Ok(Expression::Array {
+                    elements,
+                    span: next.span.to(right_bracket.span),
+                });
             }
-            TokenKind::Float(value) => {
+            TokenKind::LeftParenthesis => {
                 self.advance();
 
-                Ok(Expression::Literal(Literal::Number(value)))
+                let expression = self.expression_with_struct_literals(true)?;
+
+                let right_parenthesis = self.consume(
+                    &TokenKind::RightParenthesis,
+                    "Expected ')' after expression.",
+                )?;
+
+                return Ok(Expression::Grouping {
+                    expression: Box::new(expression),
+                    span: next.span.to(right_parenthesis.span),
+                });
             }
-            TokenKind::Integer(value) => {
+            TokenKind::Match => {
                 self.advance();
 
-                Ok(Expression::Literal(Literal::Number(value as f64)))
+                return self.match_expression(next);
+            }
+            _ => return Err(self.unexpected("Expected expression.")),
+        };
+
+        self.advance();
+
+        Ok(Expression::Literal {
+            value,
+            span: next.span,
+        })
+    }
+
+    /// Parses the fields of a struct literal, after its name and the `{`.
+    fn struct_literal(&mut self, name: Token) -> Result<Expression, Error> {
+        let mut fields = Vec::new();
+        while !self.check(&TokenKind::RightCurlyBrace) {
+            let field = match self.peek().token_kind {
+                TokenKind::Identifier(_) => self.advance(),
+                _ => return Err(self.unexpected("Expected field name.")),
+            };
+            self.consume(&TokenKind::Colon, "Expected ':' after field name.")?;
+            let value = self.expression_with_struct_literals(true)?;
+
+            fields.push((field, value));
+
+            // The last field may be followed by a comma too.
+            if !self.matches(&[TokenKind::Comma]) {
+                break;
+            }
+        }
+
+        let right_brace =
+            self.consume(&TokenKind::RightCurlyBrace, "Expected '}' after fields.")?;
+
+        Ok(Expression::Struct {
+            span: name.span.to(right_brace.span),
+            name,
+            fields,
+        })
+    }
+
+    /// Parses a variant of an enum and its values, after the name of the enum and the `::`.
+    fn variant(&mut self, enumeration: Token) -> Result<Expression, Error> {
+        let variant = match self.peek().token_kind {
+            TokenKind::Identifier(_) => self.advance(),
+            _ => return Err(self.unexpected("Expected variant name after '::'.")),
+        };
+
+        let mut arguments = Vec::new();
+        let end = if self.matches(&[TokenKind::LeftParenthesis]) {
+            if !self.check(&TokenKind::RightParenthesis) {
+                loop {
+                    arguments.push(self.expression_with_struct_literals(true)?);
+
+                    if !self.matches(&[TokenKind::Comma]) {
+                        break;
+                    }
+                }
+            }
+
+            self.consume(
+                &TokenKind::RightParenthesis,
+                "Expected ')' after variant values.",
+            )?
+            .span
+        } else {
+            variant.span
+        };
+
+        Ok(Expression::Variant {
+            span: enumeration.span.to(end),
+            enumeration,
+            variant,
+            arguments,
+        })
+    }
+
+    /// Parses the value and the arms of a match expression, after the `match` keyword.
+    fn match_expression(&mut self, keyword: Token) -> Result<Expression, Error> {
+        // The brace after the value starts the arms, not a struct literal.
+        let scrutinee = self.expression_with_struct_literals(false)?;
+        self.consume(
+            &TokenKind::LeftCurlyBrace,
+            "Expected '{' after match value.",
+        )?;
+
+        let mut arms = Vec::new();
+        while !self.check(&TokenKind::RightCurlyBrace) {
+            let pattern = self.pattern()?;
+            self.consume(&TokenKind::FatArrow, "Expected '=>' after pattern.")?;
+            let body = self.expression_with_struct_literals(true)?;
+
+            arms.push(Arm { pattern, body });
+
+            // The last arm may be followed by a comma too.
+            if !self.matches(&[TokenKind::Comma]) {
+                break;
             }
-            TokenKind::String(value) => {
+        }
+
+        let right_brace = self.consume(
+            &TokenKind::RightCurlyBrace,
+            "Expected '}' after match arms.",
+        )?;
+
+        Ok(Expression::Match {
+            span: keyword.span.to(right_brace.span),
+            keyword,
+            scrutinee: Box::new(scrutinee),
+            arms,
+        })
+    }
+
+    /// Parses the pattern of an arm of a match expression.
+    fn pattern(&mut self) -> Result<Pattern, Error> {
+        let next = self.peek().clone();
+        let value = match next.token_kind {
+            TokenKind::False => Literal::Boolean(false),
+            TokenKind::True => Literal::Boolean(true),
+            TokenKind::Float(value) => Literal::Float(value),
+            TokenKind::Integer(value) => Literal::Integer(value),
+            TokenKind::String(value) => Literal::String(value),
+            TokenKind::Identifier(ref name) if name == "_" => {
                 self.advance();
 
-                Ok(Expression::Literal(Literal::String(value)))
+                return Ok(Pattern::Wildcard { span: next.span });
             }
             TokenKind::Identifier(_) => {
                 self.advance();
 
-                Ok(Expression::Variable {
-                    name: self.previous().clone(),
-                })
+                return self.variant_pattern(next);
             }
-            TokenKind::LeftParenthesis => {
-                self.advance();
+            _ => return Err(self.unexpected("Expected pattern.")),
+        };
 
-                let expression = self.expression()?;
+        self.advance();
 
-                self.consume(
-                    &TokenKind::RightParenthesis,
-                    "Expected ')' after expression.",
-                )?;
+        Ok(Pattern::Literal {
+            value,
+            span: next.span,
+        })
+    }
 
-                Ok(Expression::Grouping {
-                    expression: Box::new(expression),
-                })
+    /// Parses a variant pattern and the names it binds, after the name of the enum.
+    fn variant_pattern(&mut self, enumeration: Token) -> Result<Pattern, Error> {
+        self.consume(&TokenKind::DoubleColon, "Expected '::' after enum name.")?;
+        let variant = match self.peek().token_kind {
+            TokenKind::Identifier(_) => self.advance(),
+            _ => return Err(self.unexpected("Expected variant name after '::'.")),
+        };
+
+        let mut bindings = Vec::new();
+        let end = if self.matches(&[TokenKind::LeftParenthesis]) {
+            if !self.check(&TokenKind::RightParenthesis) {
+                loop {
+                    match self.peek().token_kind {
+                        TokenKind::Identifier(_) => bindings.push(self.advance()),
+                        _ => return Err(self.unexpected("Expected binding name.")),
+                    }
+
+                    if !self.matches(&[TokenKind::Comma]) {
+                        break;
+                    }
+                }
             }
-            _ => Err(Error::UnexpectedToken {
-                line: self.peek().line,
-                column: self.peek().column,
-                message: "Expected expression.".to_string(),
-            }),
-        }
+
+            self.consume(&TokenKind::RightParenthesis, "Expected ')' after bindings.")?
+                .span
+        } else {
+            variant.span
+        };
+
+        Ok(Pattern::Variant {
+            span: enumeration.span.to(end),
+            enumeration,
+            variant,
+            bindings,
+        })
     }
 
     fn consume(&mut self, kind: &TokenKind, message: &str) -> Result<Token, Error> {
@@ -675,11 +1819,18 @@ impl<'a> Parser<'a> {
             return Ok(self.advance());
         }
 
-        Err(Error::UnexpectedToken {
-            line: self.peek().line,
-            column: self.peek().column,
+        Err(self.unexpected(message))
+    }
+
+    fn unexpected(&self, message: &str) -> Error {
+        let token = self.peek();
+
+        Error::UnexpectedToken {
+            line: token.line,
+            column: token.column,
             message: message.to_string(),
-        })
+            span: token.span,
+        }
     }
 
     fn matches(&mut self, kinds: &[TokenKind]) -> bool {
@@ -704,7 +1855,9 @@ impl<'a> Parser<'a> {
 
     fn advance(&mut self) -> Token {
         if !self.is_at_end() {
-            self.current += 1;
+            let next = self.pull();
+            self.previous = std::mem::replace(&mut self.current, next);
+            self.consumed += 1;
         }
 
         self.previous().clone()
@@ -715,10 +1868,62 @@ impl<'a> Parser<'a> {
     }
 
     const fn peek(&self) -> &Token {
-        &self.tokens[self.current]
+        &self.current
     }
 
     const fn previous(&self) -> &Token {
-        &self.tokens[self.current - 1]
+        &self.previous
+    }
+}
+
+impl Debug for Parser<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Parser")
+            .field("current", &self.current)
+            .field("previous", &self.previous)
+            .field("consumed", &self.consumed)
+            .field("struct_literals", &self.struct_literals)
+            .field("errors", &self.errors)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Splits the sign off a negative number literal, turning it into a minus operator.
+///
+/// # Arguments
+///
+/// * `token` - The first token of the expression.
+/// * `expression` - The expression.
+///
+/// # Returns
+///
+/// * `(Option<Token>, Expression)` - The minus operator, if the expression is a negative number
+///   literal, and the expression without its sign.
+fn split_sign(token: &Token, expression: Expression) -> (Option<Token>, Expression) {
+    // `i64::MIN` has no positive counterpart, so it keeps its sign.
+    let magnitude = match token.token_kind {
+        TokenKind::Integer(value) if value < 0 => value.checked_neg().map(Literal::Integer),
+        TokenKind::Float(value) if value.is_sign_negative() => Some(Literal::Float(-value)),
+        _ => None,
+    };
+
+    match (expression, magnitude) {
+        (Expression::Literal { span, .. }, Some(magnitude)) if span == token.span => {
+            let sign = Token::new(
+                TokenKind::Minus,
+                token.line,
+                token.column,
+                Span::new(span.start, span.start + 1),
+            );
+
+            (
+                Some(sign),
+                Expression::Literal {
+                    value: magnitude,
+                    span: Span::new(span.start + 1, span.end),
+                },
+            )
+        }
+        (expression, _) => (None, expression),
     }
 }
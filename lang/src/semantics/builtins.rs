@@ -0,0 +1,86 @@
+use crate::semantics::types::Type;
+
+/// A function which is built into the language.
+///
+/// # Variants
+///
+/// * `Print` - Prints a value followed by a new-line.
+/// * `Len` - Gets the number of elements of an array, or of characters of a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Builtin {
+    Print,
+    Len,
+}
+
+impl Builtin {
+    /// All built-in functions.
+    pub const ALL: &'static [Self] = &[Self::Print, Self::Len];
+
+    /// Gets the name the built-in is called by.
+    ///
+    /// # Returns
+    ///
+    /// * `&'static str` - The name of the built-in.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Print => "print",
+            Self::Len => "len",
+        }
+    }
+
+    /// Gets the number of arguments the built-in takes.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The arity of the built-in.
+    #[must_use]
+    pub const fn arity(self) -> usize {
+        match self {
+            Self::Print | Self::Len => 1,
+        }
+    }
+
+    /// Gets the type of the value the built-in returns.
+    ///
+    /// # Returns
+    ///
+    /// * `Type` - The return type of the built-in.
+    #[must_use]
+    pub const fn return_type(self) -> Type {
+        match self {
+            Self::Print => Type::Unit,
+            Self::Len => Type::Integer,
+        }
+    }
+
+    /// Checks whether the built-in accepts an argument of the given type.
+    ///
+    /// # Arguments
+    ///
+    /// * `r#type` - The type of the argument.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the argument is accepted.
+    #[must_use]
+    pub const fn accepts(self, r#type: &Type) -> bool {
+        match self {
+            Self::Print => true,
+            Self::Len => matches!(r#type, Type::Array(_) | Type::String | Type::Unknown),
+        }
+    }
+
+    /// Describes the arguments the built-in accepts, for error messages.
+    ///
+    /// # Returns
+    ///
+    /// * `&'static str` - The description, such as `array or str`.
+    #[must_use]
+    pub const fn expected(self) -> &'static str {
+        match self {
+            Self::Print => "anything",
+            Self::Len => "array or str",
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::diagnostics::{Diagnostic, Label};
+use crate::lexer::span::Span;
 use thiserror::Error;
 
 /// A semantic error.
@@ -10,12 +12,14 @@ pub enum Error {
         name: String,
         line: usize,
         column: usize,
+        span: Span,
     },
     #[error("Cannot assign to '{name}' at line {line} and column {column}!")]
     InvalidAssignment {
         name: String,
         line: usize,
         column: usize,
+        span: Span,
     },
     #[error(
         "The operator '{operator}' is not a valid operator at line {line} and column {column}!"
@@ -24,23 +28,505 @@ pub enum Error {
         operator: String,
         line: usize,
         column: usize,
+        span: Span,
     },
     #[error("The variable '{name}' hasn't been initialized at line {line} and column {column}!")]
     UninitializedVariable {
         name: String,
         line: usize,
         column: usize,
+        span: Span,
     },
     #[error("The variable '{name}' is invalid at line {line} and column {column}!")]
     InvalidVariable {
         name: String,
         line: usize,
         column: usize,
+        span: Span,
     },
     #[error("The parameter '{name}' is invalid at line {line} and column {column}!")]
     InvalidParameterKind {
         name: String,
         line: usize,
         column: usize,
+        span: Span,
     },
+    #[error("Expected type '{expected}' but found '{found}' at line {line} and column {column}!")]
+    TypeMismatch {
+        expected: String,
+        found: String,
+        line: usize,
+        column: usize,
+        span: Span,
+        origin: Option<Span>,
+    },
+    #[error("The type '{name}' is unknown at line {line} and column {column}!")]
+    UnknownType {
+        name: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("Expected array elements of type '{expected}' but found '{found}' at line {line} and column {column}!")]
+    HeterogeneousArray {
+        expected: String,
+        found: String,
+        line: usize,
+        column: usize,
+        span: Span,
+        origin: Span,
+    },
+    #[error("The struct '{structure}' has no field '{field}' at line {line} and column {column}!")]
+    UnknownField {
+        structure: String,
+        field: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The field '{field}' of '{structure}' is missing at line {line} and column {column}!")]
+    MissingField {
+        structure: String,
+        field: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The field '{field}' of '{structure}' is given more than once at line {line} and column {column}!")]
+    DuplicateField {
+        structure: String,
+        field: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error(
+        "The enum '{enumeration}' has no variant '{variant}' at line {line} and column {column}!"
+    )]
+    UnknownVariant {
+        enumeration: String,
+        variant: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The variant '{variant}' of '{enumeration}' is declared more than once at line {line} and column {column}!")]
+    DuplicateVariant {
+        enumeration: String,
+        variant: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The variant '{variant}' has {expected} value(s) but {found} were given at line {line} and column {column}!")]
+    PayloadMismatch {
+        variant: String,
+        expected: usize,
+        found: usize,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The match doesn't cover {} at line {line} and column {column}!", .missing.join(", "))]
+    NonExhaustiveMatch {
+        missing: Vec<String>,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The variable '{name}' needs a type at line {line} and column {column}!")]
+    MissingTypeAnnotation {
+        name: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("'break' outside of a loop at line {line} and column {column}!")]
+    BreakOutsideLoop {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("'continue' outside of a loop at line {line} and column {column}!")]
+    ContinueOutsideLoop {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("'return' outside of a function at line {line} and column {column}!")]
+    ReturnOutsideFunction {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The label {label} is undefined at line {line} and column {column}!")]
+    UndefinedLabel {
+        label: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("Only functions can be called, found '{found}' at line {line} and column {column}!")]
+    NotCallable {
+        found: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error(
+        "Expected {expected} argument(s) but found {found} at line {line} and column {column}!"
+    )]
+    ArityMismatch {
+        expected: usize,
+        found: usize,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error(
+        "The {kind} '{name}' is already declared in this scope at line {line} and column {column}!"
+    )]
+    DuplicateDeclaration {
+        kind: String,
+        name: String,
+        line: usize,
+        column: usize,
+        span: Span,
+        previous: Option<Span>,
+    },
+    #[error("The parameter '{name}' is already declared at line {line} and column {column}!")]
+    DuplicateParameter {
+        name: String,
+        line: usize,
+        column: usize,
+        span: Span,
+        previous: Option<Span>,
+    },
+    #[error("The local variable '{name}' of an enclosing function can't be used at line {line} and column {column}!")]
+    CapturedVariable {
+        name: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The step of a range can't be zero at line {line} and column {column}!")]
+    ZeroStep {
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The function '{name}' doesn't return '{expected}' on every path at line {line} and column {column}!")]
+    MissingReturn {
+        name: String,
+        expected: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The type '{receiver}' has no method '{method}' at line {line} and column {column}!")]
+    UnknownMethod {
+        receiver: String,
+        method: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+}
+
+impl From<&Error> for Diagnostic {
+    // Every variant is matched explicitly, so that a new one can't be left without a diagnostic.
+    #[allow(clippy::too_many_lines)]
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::InvalidScope => Self::error("E0200", "The scope is invalid.")
+                .with_note("this is a bug in the compiler"),
+            Error::UndefinedSymbol { name, span, .. } => {
+                Self::error("E0201", format!("The symbol '{name}' is undefined."))
+                    .with_label(Label::primary(*span, "not found in this scope"))
+            }
+            Error::InvalidAssignment { name, span, .. } => {
+                Self::error("E0202", format!("Cannot assign to '{name}'."))
+                    .with_label(Label::primary(*span, "not a variable"))
+                    .with_help("only variables can be assigned to")
+            }
+            Error::InvalidOperator { operator, span, .. } => Self::error(
+                "E0203",
+                format!("'{operator}' is not a valid operator here."),
+            )
+            .with_label(Label::primary(*span, "invalid operator")),
+            Error::UninitializedVariable { name, span, .. } => Self::error(
+                "E0204",
+                format!("The variable '{name}' hasn't been initialized."),
+            )
+            .with_label(Label::primary(*span, "used before it has a value"))
+            .with_help(format!("assign a value to '{name}' before using it")),
+            Error::InvalidVariable { name, span, .. } => {
+                Self::error("E0205", format!("The variable '{name}' is invalid."))
+                    .with_label(Label::primary(*span, "invalid variable"))
+            }
+            Error::InvalidParameterKind { name, span, .. } => {
+                Self::error("E0206", format!("The parameter '{name}' is invalid."))
+                    .with_label(Label::primary(*span, "invalid parameter"))
+            }
+            Error::TypeMismatch {
+                expected,
+                found,
+                span,
+                origin,
+                ..
+            } => {
+                let mut diagnostic = Self::error("E0207", "Mismatched types.").with_label(
+                    Label::primary(*span, format!("expected '{expected}', found '{found}'")),
+                );
+                if expected == found {
+                    diagnostic = diagnostic
+                        .with_note("these are different types declared with the same name");
+                }
+
+                match origin {
+                    Some(origin) => {
+                        diagnostic.with_label(Label::secondary(*origin, "expected because of this"))
+                    }
+                    None => diagnostic,
+                }
+            }
+            Error::UnknownType { name, span, .. } => {
+                Self::error("E0208", format!("The type '{name}' is unknown."))
+                    .with_label(Label::primary(*span, "unknown type"))
+                    .with_note("the built-in types are 'str', 'int', 'float', 'bool' and 'void', and arrays of a type are written like '[int]'")
+            }
+            Error::HeterogeneousArray {
+                expected,
+                found,
+                span,
+                origin,
+                ..
+            } => Self::error("E0209", "The elements of an array have different types.")
+                .with_label(Label::primary(
+                    *span,
+                    format!("expected '{expected}', found '{found}'"),
+                ))
+                .with_label(Label::secondary(*origin, "expected because of this"))
+                .with_help("all the elements of an array must have the same type"),
+            Error::UnknownMethod {
+                receiver,
+                method,
+                span,
+                ..
+            } => Self::error(
+                "E0217",
+                format!("The type '{receiver}' has no method '{method}'."),
+            )
+            .with_label(Label::primary(*span, "method not found"))
+            .with_help(format!(
+                "a method is a function whose first parameter has the type '{receiver}'"
+            )),
+            Error::ZeroStep { span, .. } => Self::error("E0228", "The step of a range is zero.")
+                .with_label(Label::primary(*span, "the loop would never reach its end"))
+                .with_help("use a positive step to count up, or a negative one to count down"),
+            Error::MissingReturn {
+                name,
+                expected,
+                span,
+                ..
+            } => Self::error(
+                "E0229",
+                format!("The function '{name}' doesn't always return a value."),
+            )
+            .with_label(Label::primary(
+                *span,
+                format!("may end without returning '{expected}'"),
+            ))
+            .with_help("add a 'return' at the end of the function"),
+            Error::CapturedVariable { name, span, .. } => Self::error(
+                "E0227",
+                format!("The local variable '{name}' of an enclosing function can't be used."),
+            )
+            .with_label(Label::primary(*span, "captured from an enclosing function"))
+            .with_help(format!("pass '{name}' to the function as an argument instead")),
+            Error::MissingTypeAnnotation { name, span, .. } => {
+                Self::error("E0218", format!("The variable '{name}' needs a type."))
+                    .with_label(Label::primary(*span, "declared without a type or a value"))
+                    .with_help(format!("annotate its type, like `let {name}: int;`"))
+            }
+            Error::BreakOutsideLoop { span, .. } => {
+                Self::error("E0219", "'break' outside of a loop.")
+                    .with_label(Label::primary(*span, "cannot break out of this"))
+            }
+            Error::ContinueOutsideLoop { span, .. } => {
+                Self::error("E0220", "'continue' outside of a loop.")
+                    .with_label(Label::primary(*span, "cannot continue from here"))
+            }
+            Error::ReturnOutsideFunction { span, .. } => {
+                Self::error("E0221", "'return' outside of a function.")
+                    .with_label(Label::primary(*span, "not inside a function"))
+            }
+            Error::UndefinedLabel { label, span, .. } => {
+                Self::error("E0222", format!("The label {label} is undefined."))
+                    .with_label(Label::primary(*span, "no enclosing loop has this label"))
+            }
+            Error::NotCallable { found, span, .. } => Self::error(
+                "E0223",
+                format!("A value of type '{found}' can't be called."),
+            )
+            .with_label(Label::primary(*span, "not a function"))
+            .with_help("only functions can be called"),
+            Error::ArityMismatch {
+                expected,
+                found,
+                span,
+                ..
+            } => Self::error(
+                "E0224",
+                format!("Expected {expected} argument(s) but {found} were given."),
+            )
+            .with_label(Label::primary(
+                *span,
+                format!("expected {expected} argument(s)"),
+            )),
+            Error::DuplicateDeclaration {
+                kind,
+                name,
+                span,
+                previous,
+                ..
+            } => first_declared(
+                Self::error(
+                    "E0225",
+                    format!("The {kind} '{name}' is already declared in this scope."),
+                )
+                .with_label(Label::primary(*span, "declared again here"))
+                .with_help("give one of the declarations another name"),
+                *previous,
+            ),
+            Error::DuplicateParameter {
+                name,
+                span,
+                previous,
+                ..
+            } => first_declared(
+                Self::error(
+                    "E0226",
+                    format!("The parameter '{name}' is already declared."),
+                )
+                .with_label(Label::primary(*span, "declared again here"))
+                .with_help("give each parameter a different name"),
+                *previous,
+            ),
+            Error::UnknownField {
+                structure,
+                field,
+                span,
+                ..
+            } => unknown_field(structure, field, *span),
+            Error::MissingField {
+                structure,
+                field,
+                span,
+                ..
+            } => missing_field(structure, field, *span),
+            Error::DuplicateField {
+                structure,
+                field,
+                span,
+                ..
+            } => duplicate_field(structure, field, *span),
+            Error::UnknownVariant {
+                enumeration,
+                variant,
+                span,
+                ..
+            } => unknown_variant(enumeration, variant, *span),
+            Error::DuplicateVariant {
+                enumeration,
+                variant,
+                span,
+                ..
+            } => duplicate_variant(enumeration, variant, *span),
+            Error::PayloadMismatch {
+                variant,
+                expected,
+                found,
+                span,
+                ..
+            } => payload_mismatch(variant, *expected, *found, *span),
+            Error::NonExhaustiveMatch { missing, span, .. } => non_exhaustive_match(missing, *span),
+        }
+    }
+}
+
+/// Points a diagnostic about a name which is declared twice at its first declaration, if any.
+fn first_declared(diagnostic: Diagnostic, previous: Option<Span>) -> Diagnostic {
+    match previous {
+        Some(previous) => diagnostic.with_label(Label::secondary(previous, "first declared here")),
+        None => diagnostic,
+    }
+}
+
+/// Builds the diagnostic of a field which isn't declared by its struct.
+fn unknown_field(structure: &str, field: &str, span: Span) -> Diagnostic {
+    Diagnostic::error(
+        "E0210",
+        format!("The struct '{structure}' has no field '{field}'."),
+    )
+    .with_label(Label::primary(span, "unknown field"))
+}
+
+/// Builds the diagnostic of a field which a struct literal doesn't give a value.
+fn missing_field(structure: &str, field: &str, span: Span) -> Diagnostic {
+    Diagnostic::error(
+        "E0211",
+        format!("The field '{field}' of '{structure}' is missing."),
+    )
+    .with_label(Label::primary(span, format!("missing '{field}'")))
+    .with_help("every field of a struct must be given a value")
+}
+
+/// Builds the diagnostic of a field which is given or declared more than once.
+fn duplicate_field(structure: &str, field: &str, span: Span) -> Diagnostic {
+    Diagnostic::error(
+        "E0212",
+        format!("The field '{field}' of '{structure}' is given more than once."),
+    )
+    .with_label(Label::primary(span, "given again here"))
+}
+
+/// Builds the diagnostic of a variant which isn't declared by its enum.
+fn unknown_variant(enumeration: &str, variant: &str, span: Span) -> Diagnostic {
+    Diagnostic::error(
+        "E0213",
+        format!("The enum '{enumeration}' has no variant '{variant}'."),
+    )
+    .with_label(Label::primary(span, "unknown variant"))
+}
+
+/// Builds the diagnostic of a variant which is declared more than once.
+fn duplicate_variant(enumeration: &str, variant: &str, span: Span) -> Diagnostic {
+    Diagnostic::error(
+        "E0214",
+        format!("The variant '{variant}' of '{enumeration}' is declared more than once."),
+    )
+    .with_label(Label::primary(span, "declared again here"))
+}
+
+/// Builds the diagnostic of a variant which is given the wrong number of values.
+fn payload_mismatch(variant: &str, expected: usize, found: usize, span: Span) -> Diagnostic {
+    Diagnostic::error(
+        "E0215",
+        format!("The variant '{variant}' has {expected} value(s) but {found} were given."),
+    )
+    .with_label(Label::primary(
+        span,
+        format!("expected {expected} value(s)"),
+    ))
+}
+
+/// Builds the diagnostic of a match which doesn't cover every value.
+fn non_exhaustive_match(missing: &[String], span: Span) -> Diagnostic {
+    Diagnostic::error(
+        "E0216",
+        format!("The match doesn't cover {}.", missing.join(", ")),
+    )
+    .with_label(Label::primary(span, "not every value is covered"))
+    .with_help("add an arm for each missing case, or a `_` arm to match anything else")
 }
@@ -1,13 +1,21 @@
+use crate::lexer::span::Span;
 use crate::lexer::tokens::{Token, TokenKind};
 use crate::parser::ast::AST;
-use crate::parser::{Expression, Statement};
+use crate::parser::{Arm, Expression, Literal, Pattern, Statement, TypeAnnotation};
 use crate::semantics::errors::Error;
 use crate::semantics::scopes::Scope;
-use crate::semantics::symbols::SymbolKind;
+use crate::semantics::symbols::{Location, Resolution, SymbolKind};
+use crate::semantics::types::Type;
+use crate::semantics::warnings::Warning;
+use std::collections::HashMap;
 
+pub mod builtins;
 pub mod errors;
+pub mod numbers;
 pub mod scopes;
 pub mod symbols;
+pub mod types;
+pub mod warnings;
 
 pub trait Visitor {
     /// Visits a statement.
@@ -35,26 +43,60 @@ pub trait Visitor {
     ///
     /// # Returns
     ///
-    /// * `Result<(), Error>` - The result of the visit.
+    /// * `Result<Type, Error>` - The type of the expression.
     ///
     /// # Errors
     ///
     /// * If the scope is invalid.
     /// * If the symbol is undefined.
-    /// * If the symbol is already defined.
-    fn visit_expression(&mut self, expression: &Expression) -> Result<(), Error>;
+    /// * If the operands have the wrong types.
+    fn visit_expression(&mut self, expression: &Expression) -> Result<Type, Error>;
 }
 
 /// A semantic analyzer which analyzes the AST.
 ///
+/// Errors don't stop the analysis: each one is recorded and the analyzer carries on, treating the
+/// type of whatever failed as unknown, so that every problem in a program is found in one pass.
+///
 /// # Fields
 ///
 /// * `ast` - The AST to analyze.
 /// * `scopes` - The scopes found.
+/// * `frames` - The number of local slots used by each function being analyzed, where the first
+///   frame is the top-level code.
+/// * `globals` - The number of global slots used.
+/// * `functions` - The number of local slots used by each function, by id.
+/// * `resolutions` - What each name resolves to, by where its token starts.
+/// * `variable_types` - The type each variable was declared with, by where its name starts in its
+///   declaration and in each assignment to it.
+/// * `field_types` - The declared type of each field which is given or read, by where its name
+///   starts.
+/// * `payload_types` - The declared types of the values of each variant which is constructed, by
+///   where its name starts.
+/// * `return_types` - The declared return types of the functions being analyzed, and where they
+///   were declared.
+/// * `loops` - The labels of the loops the statement being analyzed is nested in, within its
+///   function, innermost last.
+/// * `errors` - The errors found so far.
+/// * `warnings` - The warnings found so far.
+/// * `warn_shadowing` - Whether to warn when a variable shadows a variable or a parameter of an
+///   enclosing scope.
 #[derive(Debug)]
 pub struct SemanticAnalyzer<'a> {
     ast: &'a AST,
     scopes: Vec<Scope>,
+    frames: Vec<usize>,
+    globals: usize,
+    functions: Vec<usize>,
+    resolutions: HashMap<usize, Resolution>,
+    variable_types: HashMap<usize, Type>,
+    field_types: HashMap<usize, Type>,
+    payload_types: HashMap<usize, Vec<Type>>,
+    return_types: Vec<(Type, Option<Span>)>,
+    loops: Vec<Option<String>>,
+    errors: Vec<Error>,
+    warnings: Vec<Warning>,
+    warn_shadowing: bool,
 }
 
 impl<'a> SemanticAnalyzer<'a> {
@@ -71,43 +113,291 @@ impl<'a> SemanticAnalyzer<'a> {
     pub fn new(ast: &'a AST) -> Self {
         Self {
             ast,
-            scopes: vec![Scope::default()],
+            scopes: vec![Scope::prelude(), Scope::default()],
+            frames: vec![0],
+            globals: 0,
+            functions: Vec::new(),
+            resolutions: HashMap::new(),
+            variable_types: HashMap::new(),
+            field_types: HashMap::new(),
+            payload_types: HashMap::new(),
+            return_types: Vec::new(),
+            loops: Vec::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            warn_shadowing: true,
         }
     }
+
+    /// Sets whether to warn when a variable shadows a variable or a parameter of an enclosing
+    /// scope, which it does by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to warn about shadowing.
+    ///
+    /// # Returns
+    ///
+    /// * `SemanticAnalyzer` - The semantic analyzer.
+    #[must_use]
+    pub const fn with_shadowing_warnings(mut self, enabled: bool) -> Self {
+        self.warn_shadowing = enabled;
+        self
+    }
+
+    /// Gets what a name resolves to, after the AST has been analyzed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The token of the name, either where it's declared or where it's used.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Resolution>` - The resolution, or `None` if the name was never resolved.
+    #[must_use]
+    pub fn resolution(&self, name: &Token) -> Option<Resolution> {
+        self.resolutions.get(&name.span.start).copied()
+    }
+
+    /// Gets the type a variable was declared with, after the AST has been analyzed.
+    ///
+    /// The type is unknown for variables declared without a type or an initializer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The token of the name where the variable is declared or assigned.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&Type>` - The type, or `None` if no variable is declared or assigned there.
+    #[must_use]
+    pub fn variable_type(&self, name: &Token) -> Option<&Type> {
+        self.variable_types.get(&name.span.start)
+    }
+
+    /// Gets the declared type of a field, after the AST has been analyzed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The token of the field name, either in a struct literal or where it's accessed.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&Type>` - The type, or `None` if the field couldn't be resolved.
+    #[must_use]
+    pub fn field_type(&self, name: &Token) -> Option<&Type> {
+        self.field_types.get(&name.span.start)
+    }
+
+    /// Gets the declared types of the values of a variant, after the AST has been analyzed.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The token of the variant name where the variant is constructed.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&[Type]>` - The types, or `None` if the variant couldn't be resolved.
+    #[must_use]
+    pub fn payload_types(&self, variant: &Token) -> Option<&[Type]> {
+        self.payload_types
+            .get(&variant.span.start)
+            .map(Vec::as_slice)
+    }
+
+    /// Gets the number of global slots used by the program.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of globals.
+    #[must_use]
+    pub const fn globals(&self) -> usize {
+        self.globals
+    }
+
+    /// Gets the number of local slots used by the top-level code.
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of locals.
+    #[must_use]
+    pub fn script_locals(&self) -> usize {
+        self.frames.first().copied().unwrap_or_default()
+    }
+
+    /// Gets the number of local slots used by a function, including its parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the function.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<usize>` - The number of locals, or `None` if there's no such function.
+    #[must_use]
+    pub fn function_locals(&self, id: usize) -> Option<usize> {
+        self.functions.get(id).copied()
+    }
+
+    /// Gets the warnings found while analyzing the AST, in the order they appear in the source.
+    ///
+    /// # Returns
+    ///
+    /// * `&[Warning]` - The warnings.
+    #[must_use]
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
     fn current_scope(&mut self) -> Result<&mut Scope, Error> {
         self.scopes.last_mut().ok_or(Error::InvalidScope)
     }
 
+    fn current_frame(&self) -> usize {
+        self.frames.len() - 1
+    }
+
     fn begin_scope(&mut self) {
-        self.scopes.push(Scope::default());
+        self.scopes.push(Scope::new(self.current_frame()));
     }
 
     fn end_scope(&mut self) {
-        self.scopes.pop();
+        if let Some(scope) = self.scopes.pop() {
+            self.warnings.extend(unused_variables(&scope));
+        }
     }
 
-    /// Analyzes the AST.
+    /// Analyzes the AST, carrying on past errors so that all of them are found.
     ///
     /// # Returns
     ///
-    /// * `Result<(), Error>` - The result of the analysis.
+    /// * `Result<(), Vec<Error>>` - Nothing if the AST is valid, warnings aside.
     ///
     /// # Errors
     ///
-    /// * If the scope is invalid.
-    pub fn analyze(&mut self) -> Result<(), Error> {
+    /// * Every error found, in the order they were found.
+    pub fn analyze(&mut self) -> Result<(), Vec<Error>> {
+        self.hoist(&self.ast.statements);
         for statement in &self.ast.statements {
-            self.visit_statement(statement)?;
+            let result = self.visit_statement(statement);
+            self.record(result);
+        }
+
+        // The global scope is never ended, so check it for unused variables here.
+        let globals = self.scopes.get(1).map(unused_variables);
+        self.warnings.extend(globals.into_iter().flatten());
+        self.warnings.sort_by_key(|warning| warning.span().start);
+
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(std::mem::take(&mut self.errors))
         }
+    }
+
+    /// Records an error, so that the analysis can carry on past it.
+    fn record<T>(&mut self, result: Result<T, Error>) -> Option<T> {
+        result.map_err(|why| self.errors.push(why)).ok()
+    }
+
+    /// Visits an expression, recording any error and treating the type of the expression as
+    /// unknown so that it doesn't cause further errors.
+    fn infer(&mut self, expression: &Expression) -> Type {
+        let result = self.visit_expression(expression);
+
+        self.record(result).unwrap_or(Type::Unknown)
+    }
+
+    fn declare_variable(
+        &mut self,
+        name: &Token,
+        is_initialized: bool,
+        r#type: Type,
+    ) -> Result<(), Error> {
+        self.declare(name, is_initialized, false, r#type)
+    }
+
+    /// Declares a parameter, which is never warned about when unused since it's part of the
+    /// signature of its function.
+    fn declare_parameter(&mut self, name: &Token, r#type: Type) -> Result<(), Error> {
+        self.declare(name, true, true, r#type)
+    }
+
+    fn declare(
+        &mut self,
+        name: &Token,
+        is_initialized: bool,
+        is_parameter: bool,
+        r#type: Type,
+    ) -> Result<(), Error> {
+        // Variables in the global scope are globals, everything else lives in a frame.
+        let location = if self.scopes.len() == 2 {
+            self.globals += 1;
+
+            Location::Global(self.globals - 1)
+        } else {
+            let frame = self.frames.last_mut().ok_or(Error::InvalidScope)?;
+            *frame += 1;
+
+            Location::Local(*frame - 1)
+        };
+
+        self.resolutions.insert(
+            name.span.start,
+            match location {
+                Location::Global(slot) => Resolution::Global(slot),
+                Location::Local(slot) => Resolution::Local(slot),
+            },
+        );
+        self.variable_types.insert(name.span.start, r#type.clone());
+        self.current_scope()?.define(
+            &name.token_kind.to_string(),
+            SymbolKind::Variable {
+                name: name.clone(),
+                is_initialized,
+                is_used: is_parameter,
+                is_parameter,
+                location,
+                r#type,
+            },
+        );
 
         Ok(())
     }
 
     fn get_symbol(&mut self, token: &Token) -> Result<&mut SymbolKind, Error> {
         let name = token.token_kind.to_string();
+        let current_frame = self.current_frame();
 
         for scope in self.scopes.iter_mut().rev() {
             if let Some(symbol) = scope.symbol_table.get_mut(&*name) {
+                let resolution = match symbol {
+                    SymbolKind::Variable {
+                        location: Location::Global(slot),
+                        ..
+                    } => Resolution::Global(*slot),
+                    SymbolKind::Variable {
+                        location: Location::Local(slot),
+                        ..
+                    } if scope.frame == current_frame => Resolution::Local(*slot),
+                    // Functions can't capture the local variables of the functions they're in.
+                    SymbolKind::Variable { is_used, .. } => {
+                        *is_used = true;
+
+                        return Err(Error::CapturedVariable {
+                            name,
+                            line: token.line,
+                            column: token.column,
+                            span: token.span,
+                        });
+                    }
+                    SymbolKind::Function { id, .. } => Resolution::Function(*id),
+                    SymbolKind::Builtin(builtin) => Resolution::Builtin(*builtin),
+                    SymbolKind::Struct { .. } => Resolution::Struct,
+                    SymbolKind::Enum { .. } => Resolution::Enum,
+                };
+                self.resolutions.insert(token.span.start, resolution);
+
                 return Ok(symbol);
             }
         }
@@ -116,211 +406,1618 @@ impl<'a> SemanticAnalyzer<'a> {
             name,
             line: token.line,
             column: token.column,
+            span: token.span,
         })
     }
-}
 
-impl<'a> Visitor for SemanticAnalyzer<'a> {
-    #[allow(clippy::expect_used)]
-    fn visit_statement(&mut self, statement: &Statement) -> Result<(), Error> {
-        match statement {
-            Statement::Expression { expression } => self.visit_expression(expression),
-            Statement::Variable { name, initializer } => {
-                let is_initialized = initializer.is_some();
-                if is_initialized {
-                    self.visit_expression(initializer.as_ref().expect("Initializer is None!"))?;
+    /// Finds the symbol a name refers to, in the innermost scope which defines it, without
+    /// resolving the name.
+    fn find_symbol(&self, name: &str) -> Option<&SymbolKind> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.symbol_table.get(name))
+    }
+
+    /// Finds the declaration of a struct or an enum, which may be shadowed by another one of the
+    /// same name.
+    fn find_declaration(&self, name: &str, id: usize) -> Option<&SymbolKind> {
+        self.scopes.iter().rev().find_map(|scope| {
+            scope
+                .symbol_table
+                .get(name)
+                .filter(|symbol| symbol.name().is_some_and(|token| token.span.start == id))
+        })
+    }
+
+    /// Finds the fields of a struct.
+    fn find_struct(&self, name: &str, id: usize) -> Option<&[(String, Type)]> {
+        match self.find_declaration(name, id)? {
+            SymbolKind::Struct { fields, .. } => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// Finds the variants of an enum.
+    fn find_enum(&self, name: &str, id: usize) -> Option<&[(String, Vec<Type>)]> {
+        match self.find_declaration(name, id)? {
+            SymbolKind::Enum { variants, .. } => Some(variants),
+            _ => None,
+        }
+    }
+
+    /// Gets the type of the struct or enum a name refers to in the current scope.
+    fn find_nominal(&self, name: &str) -> Option<Type> {
+        match self.find_symbol(name)? {
+            SymbolKind::Struct { name: token, .. } => {
+                Some(Type::Struct(name.to_string(), token.span.start))
+            }
+            SymbolKind::Enum { name: token, .. } => {
+                Some(Type::Enum(name.to_string(), token.span.start))
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolves a type annotation, whose names are either built-in types, or structs and enums in
+    /// scope.
+    fn resolve_type(&self, annotation: &TypeAnnotation) -> Result<Type, Error> {
+        match annotation {
+            TypeAnnotation::Named(token) => Type::from_token(token)
+                .or_else(|why| self.find_nominal(&token.token_kind.to_string()).ok_or(why)),
+            TypeAnnotation::Array { element, .. } => {
+                Ok(Type::Array(Box::new(self.resolve_type(element)?)))
+            }
+        }
+    }
+
+    /// Resolves the types of the fields of a struct, which was declared when its block was hoisted.
+    fn visit_struct(
+        &mut self,
+        name: &Token,
+        fields: &[(Token, TypeAnnotation)],
+    ) -> Result<(), Error> {
+        let structure = name.token_kind.to_string();
+
+        let mut resolved: Vec<(String, Type)> = Vec::with_capacity(fields.len());
+        for (field, annotation) in fields {
+            let field_name = field.token_kind.to_string();
+            if resolved.iter().any(|(existing, _)| *existing == field_name) {
+                self.errors.push(Error::DuplicateField {
+                    structure: structure.clone(),
+                    field: field_name,
+                    line: field.line,
+                    column: field.column,
+                    span: field.span,
+                });
+
+                continue;
+            }
+
+            let r#type = self.resolve_type(annotation);
+            let r#type = self.record(r#type).unwrap_or(Type::Unknown);
+            resolved.push((field_name, r#type));
+        }
+
+        self.current_scope()?.define(
+            &structure,
+            SymbolKind::Struct {
+                name: name.clone(),
+                fields: resolved,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Resolves the types of the values of the variants of an enum, which was declared when its
+    /// block was hoisted.
+    fn visit_enum(
+        &mut self,
+        name: &Token,
+        variants: &[(Token, Vec<TypeAnnotation>)],
+    ) -> Result<(), Error> {
+        let enumeration = name.token_kind.to_string();
+
+        let mut resolved: Vec<(String, Vec<Type>)> = Vec::with_capacity(variants.len());
+        for (variant, payload) in variants {
+            let variant_name = variant.token_kind.to_string();
+            if resolved
+                .iter()
+                .any(|(existing, _)| *existing == variant_name)
+            {
+                self.errors.push(Error::DuplicateVariant {
+                    enumeration: enumeration.clone(),
+                    variant: variant_name,
+                    line: variant.line,
+                    column: variant.column,
+                    span: variant.span,
+                });
+
+                continue;
+            }
+
+            let mut types = Vec::with_capacity(payload.len());
+            for annotation in payload {
+                let r#type = self.resolve_type(annotation);
+                types.push(self.record(r#type).unwrap_or(Type::Unknown));
+            }
+            resolved.push((variant_name, types));
+        }
+
+        self.current_scope()?.define(
+            &enumeration,
+            SymbolKind::Enum {
+                name: name.clone(),
+                variants: resolved,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Finds the types of the values of a variant of an enum in scope.
+    fn find_variant(&self, enumeration: &Token, variant: &Token) -> Result<Vec<Type>, Error> {
+        let name = enumeration.token_kind.to_string();
+        let variants = match self.find_nominal(&name) {
+            Some(Type::Enum(name, id)) => self.find_enum(&name, id),
+            _ => None,
+        };
+        let Some(variants) = variants else {
+            return Err(Error::UnknownType {
+                name,
+                line: enumeration.line,
+                column: enumeration.column,
+                span: enumeration.span,
+            });
+        };
+
+        let variant_name = variant.token_kind.to_string();
+        variants
+            .iter()
+            .find(|(declared, _)| *declared == variant_name)
+            .map(|(_, payload)| payload.clone())
+            .ok_or(Error::UnknownVariant {
+                enumeration: name,
+                variant: variant_name,
+                line: variant.line,
+                column: variant.column,
+                span: variant.span,
+            })
+    }
+
+    /// Visits a variant of an enum, whose values have to match the types it was declared with.
+    fn visit_variant(
+        &mut self,
+        enumeration: &Token,
+        variant: &Token,
+        arguments: &[Expression],
+    ) -> Result<Type, Error> {
+        let mut argument_types = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            argument_types.push(self.infer(argument));
+        }
+
+        let payload = self.find_variant(enumeration, variant)?;
+        if payload.len() != arguments.len() {
+            return Err(Error::PayloadMismatch {
+                variant: format!("{}::{}", enumeration.token_kind, variant.token_kind),
+                expected: payload.len(),
+                found: arguments.len(),
+                line: variant.line,
+                column: variant.column,
+                span: variant.span,
+            });
+        }
+
+        for ((expected, found), argument) in payload.iter().zip(&argument_types).zip(arguments) {
+            let result = expect(expected, found, argument);
+            self.record(result);
+        }
+        self.payload_types.insert(variant.span.start, payload);
+
+        Ok(self
+            .find_nominal(&enumeration.token_kind.to_string())
+            .unwrap_or(Type::Unknown))
+    }
+
+    /// Visits a match expression, whose arms all have to have the same type and which has to
+    /// cover every value it can be given.
+    fn visit_match(
+        &mut self,
+        keyword: &Token,
+        scrutinee: &Expression,
+        arms: &[Arm],
+    ) -> Result<Type, Error> {
+        let scrutinee_type = self.infer(scrutinee);
+
+        // The value gets a slot of its own, where it's kept while it's compared to the patterns.
+        let frame = self.frames.last_mut().ok_or(Error::InvalidScope)?;
+        self.resolutions
+            .insert(keyword.span.start, Resolution::Local(*frame));
+        *frame += 1;
+
+        let mut result: Option<(Type, &Expression)> = None;
+        for arm in arms {
+            self.begin_scope();
+            self.visit_pattern(&arm.pattern, &scrutinee_type);
+            let found = self.infer(&arm.body);
+            self.end_scope();
+
+            match &result {
+                None | Some((Type::Unknown, _)) => result = Some((found, &arm.body)),
+                Some((expected, _)) if expected.accepts(&found) && found.accepts(expected) => {}
+                Some((expected, first)) => {
+                    let (line, column) = locate(&arm.body);
+
+                    self.errors.push(Error::TypeMismatch {
+                        expected: expected.to_string(),
+                        found: found.to_string(),
+                        line,
+                        column,
+                        span: arm.body.span(),
+                        origin: Some(first.span()),
+                    });
+                }
+            }
+        }
+
+        let missing = self.missing_cases(&scrutinee_type, arms);
+        if !missing.is_empty() {
+            self.errors.push(Error::NonExhaustiveMatch {
+                missing,
+                line: keyword.line,
+                column: keyword.column,
+                span: keyword.span,
+            });
+        }
+
+        Ok(result.map_or(Type::Unit, |(r#type, _)| r#type))
+    }
+
+    /// Checks a pattern against the type of the value it's compared to, declaring the names it
+    /// binds in the current scope.
+    fn visit_pattern(&mut self, pattern: &Pattern, scrutinee_type: &Type) {
+        match pattern {
+            Pattern::Wildcard { .. } => {}
+            Pattern::Literal { value, span } => {
+                let literal = Expression::Literal {
+                    value: value.clone(),
+                    span: *span,
+                };
+
+                let result = expect(scrutinee_type, &literal_type(value), &literal);
+                self.record(result);
+            }
+            Pattern::Variant {
+                enumeration,
+                variant,
+                bindings,
+                span,
+            } => {
+                let name = enumeration.token_kind.to_string();
+                if self.find_nominal(&name).as_ref() != Some(scrutinee_type)
+                    && *scrutinee_type != Type::Unknown
+                {
+                    self.errors.push(Error::TypeMismatch {
+                        expected: scrutinee_type.to_string(),
+                        found: name,
+                        line: enumeration.line,
+                        column: enumeration.column,
+                        span: *span,
+                        origin: None,
+                    });
+                }
+
+                let result = self.find_variant(enumeration, variant);
+                let payload = self.record(result);
+                if let Some(payload) = payload
+                    .as_ref()
+                    .filter(|payload| payload.len() != bindings.len())
+                {
+                    self.errors.push(Error::PayloadMismatch {
+                        variant: format!("{}::{}", enumeration.token_kind, variant.token_kind),
+                        expected: payload.len(),
+                        found: bindings.len(),
+                        line: variant.line,
+                        column: variant.column,
+                        span: *span,
+                    });
+                }
+
+                for (index, binding) in bindings.iter().enumerate() {
+                    let r#type = payload
+                        .as_ref()
+                        .and_then(|payload| payload.get(index))
+                        .cloned()
+                        .unwrap_or(Type::Unknown);
+                    let result = self.declare_variable(binding, true, r#type);
+                    self.record(result);
+                }
+            }
+        }
+    }
+
+    /// Finds the cases a match doesn't cover, which is none if it has a wildcard.
+    fn missing_cases(&self, scrutinee_type: &Type, arms: &[Arm]) -> Vec<String> {
+        let patterns: Vec<&Pattern> = arms.iter().map(|arm| &arm.pattern).collect();
+        if patterns
+            .iter()
+            .any(|pattern| matches!(pattern, Pattern::Wildcard { .. }))
+        {
+            return Vec::new();
+        }
+
+        match scrutinee_type {
+            Type::Enum(name, id) => self
+                .find_enum(name, *id)
+                .unwrap_or_default()
+                .iter()
+                .filter(|(variant, _)| {
+                    !patterns.iter().any(|pattern| {
+                        matches!(pattern, Pattern::Variant { variant: covered, .. } if covered.token_kind.to_string() == *variant)
+                    })
+                })
+                .map(|(variant, _)| format!("'{name}::{variant}'"))
+                .collect(),
+            Type::Boolean => [true, false]
+                .into_iter()
+                .filter(|value| {
+                    !patterns.iter().any(|pattern| {
+                        matches!(pattern, Pattern::Literal { value: Literal::Boolean(covered), .. } if covered == value)
+                    })
+                })
+                .map(|value| format!("'{value}'"))
+                .collect(),
+            Type::Unknown => Vec::new(),
+            _ => vec!["'_'".to_string()],
+        }
+    }
+
+    /// Visits a struct literal, which has to give every field of its struct exactly once.
+    fn visit_struct_literal(
+        &mut self,
+        name: &Token,
+        fields: &[(Token, Expression)],
+    ) -> Result<Type, Error> {
+        let mut value_types = Vec::with_capacity(fields.len());
+        for (_, value) in fields {
+            value_types.push(self.infer(value));
+        }
+
+        let structure = name.token_kind.to_string();
+        let nominal = self.find_nominal(&structure);
+        let declared = match &nominal {
+            Some(Type::Struct(_, id)) => self.find_struct(&structure, *id).map(<[_]>::to_vec),
+            _ => None,
+        };
+        let (Some(nominal), Some(declared)) = (nominal, declared) else {
+            return Err(Error::UnknownType {
+                name: structure,
+                line: name.line,
+                column: name.column,
+                span: name.span,
+            });
+        };
+
+        let mut given: Vec<String> = Vec::with_capacity(fields.len());
+        for ((field, value), found) in fields.iter().zip(&value_types) {
+            let field_name = field.token_kind.to_string();
+            let error = if given.contains(&field_name) {
+                Error::DuplicateField {
+                    structure: structure.clone(),
+                    field: field_name,
+                    line: field.line,
+                    column: field.column,
+                    span: field.span,
+                }
+            } else if let Some((_, expected)) = declared
+                .iter()
+                .find(|(declared, _)| *declared == field_name)
+            {
+                self.field_types.insert(field.span.start, expected.clone());
+                given.push(field_name);
+
+                let result = expect(expected, found, value);
+                self.record(result);
+
+                continue;
+            } else {
+                Error::UnknownField {
+                    structure: structure.clone(),
+                    field: field_name,
+                    line: field.line,
+                    column: field.column,
+                    span: field.span,
                 }
+            };
+
+            self.errors.push(error);
+        }
+
+        for (field, _) in &declared {
+            if !given.contains(field) {
+                self.errors.push(Error::MissingField {
+                    structure: structure.clone(),
+                    field: field.clone(),
+                    line: name.line,
+                    column: name.column,
+                    span: name.span,
+                });
+            }
+        }
+
+        Ok(nominal)
+    }
+
+    /// Gets the declared type of a field which is read or assigned.
+    fn visit_field(&mut self, object: &Expression, name: &Token) -> Result<Type, Error> {
+        let (structure, id) = match self.infer(object) {
+            Type::Struct(structure, id) => (structure, id),
+            Type::Unknown => return Ok(Type::Unknown),
+            found => return Err(mismatch("struct", &found, object)),
+        };
+
+        // A struct which is out of scope can't be checked any further.
+        let Some(fields) = self.find_struct(&structure, id) else {
+            return Ok(Type::Unknown);
+        };
+
+        let field = name.token_kind.to_string();
+        let Some((_, r#type)) = fields.iter().find(|(declared, _)| *declared == field) else {
+            return Err(Error::UnknownField {
+                structure,
+                field,
+                line: name.line,
+                column: name.column,
+                span: name.span,
+            });
+        };
+        let r#type = r#type.clone();
+        self.field_types.insert(name.span.start, r#type.clone());
 
-                self.current_scope()?.define(
-                    &name.token_kind.to_string(),
-                    SymbolKind::Variable { is_initialized },
-                );
+        Ok(r#type)
+    }
+
+    /// Declares the structs, enums and functions of a block before any of its statements are
+    /// visited, so that they can be used before the point where they're declared. The types come
+    /// first, since the signatures of the functions may refer to them.
+    fn hoist(&mut self, statements: &[Statement]) {
+        // Every type is declared before any is resolved, so that they can refer to each other
+        // regardless of their order.
+        for statement in statements {
+            let result = match statement {
+                Statement::Struct { name, .. } => self.declare_type(
+                    name,
+                    SymbolKind::Struct {
+                        name: name.clone(),
+                        fields: Vec::new(),
+                    },
+                ),
+                Statement::Enum { name, .. } => self.declare_type(
+                    name,
+                    SymbolKind::Enum {
+                        name: name.clone(),
+                        variants: Vec::new(),
+                    },
+                ),
+                _ => Ok(()),
+            };
+            self.record(result);
+        }
+
+        // A duplicate type was reported when it was declared, and doesn't replace the first one.
+        for statement in statements {
+            let result = match statement {
+                Statement::Struct { name, fields, .. } if self.declares(name) => {
+                    self.visit_struct(name, fields)
+                }
+                Statement::Enum { name, variants, .. } if self.declares(name) => {
+                    self.visit_enum(name, variants)
+                }
+                _ => Ok(()),
+            };
+            self.record(result);
+        }
 
-                Ok(())
+        for statement in statements {
+            if let Statement::Function {
+                name,
+                parameters,
+                return_type,
+                ..
+            } = statement
+            {
+                let result = self.declare_function(name, parameters, return_type.as_ref());
+                self.record(result);
             }
-            Statement::Block { statements } => {
+        }
+    }
+
+    /// Declares a struct or an enum in the current scope, before its fields or variants are
+    /// resolved.
+    fn declare_type(&mut self, name: &Token, symbol: SymbolKind) -> Result<(), Error> {
+        let scope = self.current_scope()?;
+        let key = name.token_kind.to_string();
+        if let Some(existing) = scope.symbol_table.get(&key) {
+            return Err(duplicate_declaration(symbol.kind(), name, existing));
+        }
+        scope.define(&key, symbol);
+
+        Ok(())
+    }
+
+    /// Checks whether a name is declared by the given token in the current scope, rather than by
+    /// an earlier declaration of the same name.
+    fn declares(&self, name: &Token) -> bool {
+        self.scopes
+            .last()
+            .and_then(|scope| scope.symbol_table.get(&name.token_kind.to_string()))
+            .and_then(SymbolKind::name)
+            .is_some_and(|token| token.span == name.span)
+    }
+
+    /// Declares a function with its signature, giving it an id, without visiting its body.
+    fn declare_function(
+        &mut self,
+        name: &Token,
+        parameters: &[(Token, TypeAnnotation)],
+        return_type_annotation: Option<&TypeAnnotation>,
+    ) -> Result<(), Error> {
+        // Unknown types are recorded, and don't stop the function from being declared.
+        let mut parameter_types = Vec::with_capacity(parameters.len());
+        for (_, kind) in parameters {
+            let r#type = self.resolve_type(kind);
+            let r#type = self.record(r#type);
+            parameter_types.push(r#type.unwrap_or(Type::Unknown));
+        }
+        let return_type = return_type_annotation
+            .map_or(Ok(Type::Unit), |annotation| self.resolve_type(annotation));
+        let return_type = self.record(return_type).unwrap_or(Type::Unknown);
+
+        let id = self.functions.len();
+        self.functions.push(0);
+        self.resolutions
+            .insert(name.span.start, Resolution::Function(id));
+
+        // A duplicate still has its body visited, but calls go to the first declaration.
+        let scope = self.current_scope()?;
+        let key = name.token_kind.to_string();
+        if let Some(existing) = scope.symbol_table.get(&key) {
+            return Err(duplicate_declaration("function", name, existing));
+        }
+        scope.define(
+            &key,
+            SymbolKind::Function {
+                name: name.clone(),
+                parameters: parameter_types,
+                return_type,
+                id,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Visits the body of a function, which has already been declared when its block was hoisted.
+    fn visit_function(
+        &mut self,
+        name: &Token,
+        parameters: &[(Token, TypeAnnotation)],
+        return_type_annotation: Option<&TypeAnnotation>,
+        body: &Statement,
+    ) -> Result<(), Error> {
+        let Some(Resolution::Function(id)) = self.resolution(name) else {
+            return Err(Error::InvalidScope);
+        };
+
+        // Any unknown types were recorded when the function was declared.
+        let parameter_types: Vec<Type> = parameters
+            .iter()
+            .map(|(_, kind)| self.resolve_type(kind).unwrap_or(Type::Unknown))
+            .collect();
+        let return_type = return_type_annotation
+            .map_or(Ok(Type::Unit), |annotation| self.resolve_type(annotation))
+            .unwrap_or(Type::Unknown);
+
+        // The function gets its own frame, which starts with the parameters.
+        self.frames.push(0);
+        self.return_types.push((
+            return_type,
+            return_type_annotation.map(TypeAnnotation::span),
+        ));
+        let loops = std::mem::take(&mut self.loops);
+        self.begin_scope();
+
+        // Define the parameters.
+        for ((name, kind), r#type) in parameters.iter().zip(parameter_types) {
+            if matches!(kind, TypeAnnotation::Named(token) if !matches!(token.token_kind, TokenKind::Identifier(_)))
+            {
+                self.errors.push(Error::InvalidParameterKind {
+                    name: name.token_kind.to_string(),
+                    line: name.line,
+                    column: name.column,
+                    span: name.span,
+                });
+            }
+
+            let key = name.token_kind.to_string();
+            let scope = self.current_scope()?;
+            if scope.is_defined(&key) {
+                let previous = scope
+                    .symbol_table
+                    .get(&key)
+                    .and_then(SymbolKind::name)
+                    .map(|token| token.span);
+                self.errors.push(Error::DuplicateParameter {
+                    name: key,
+                    line: name.line,
+                    column: name.column,
+                    span: name.span,
+                    previous,
+                });
+
+                continue;
+            }
+
+            self.declare_parameter(name, r#type)?;
+        }
+
+        // Examine the body, which must return a value on every path unless it returns nothing. A
+        // hoisted function may be called before the globals it reads are declared, so both
+        // backends check those reads at runtime.
+        let result = self.visit_statement(body);
+        self.record(result);
+        if let Some((return_type, _)) = self.return_types.last() {
+            if !matches!(return_type, Type::Unit | Type::Unknown) && !returns(body) {
+                self.errors.push(Error::MissingReturn {
+                    name: name.token_kind.to_string(),
+                    expected: return_type.to_string(),
+                    line: name.line,
+                    column: name.column,
+                    span: name.span,
+                });
+            }
+        }
+
+        self.end_scope();
+        self.loops = loops;
+        self.return_types.pop();
+        self.functions[id] = self.frames.pop().unwrap_or_default();
+
+        Ok(())
+    }
+
+    fn visit_variable(&mut self, name: &Token) -> Result<Type, Error> {
+        // If the variable is not defined, this will return an error.
+        let symbol = self.get_symbol(name)?;
+
+        match symbol {
+            SymbolKind::Variable {
+                is_initialized,
+                is_used,
+                r#type,
+                ..
+            } => {
+                *is_used = true;
+
+                // If the variable is not initialized, this will return an error.
+                if !*is_initialized {
+                    return Err(Error::UninitializedVariable {
+                        name: name.token_kind.to_string(),
+                        line: name.line,
+                        column: name.column,
+                        span: name.span,
+                    });
+                }
+
+                Ok(r#type.clone())
+            }
+            SymbolKind::Function {
+                parameters,
+                return_type,
+                ..
+            } => Ok(Type::Function(
+                parameters.clone(),
+                Box::new(return_type.clone()),
+            )),
+            SymbolKind::Builtin(builtin) => Ok(Type::Builtin(*builtin)),
+            // Structs and enums are only types, so their names can't be used as values.
+            SymbolKind::Struct { .. } | SymbolKind::Enum { .. } => Err(Error::InvalidVariable {
+                name: name.token_kind.to_string(),
+                line: name.line,
+                column: name.column,
+                span: name.span,
+            }),
+        }
+    }
+
+    /// Visits a variable declaration, whose type is the one it's annotated with, or else the type
+    /// of its initializer. A variable without an initializer must have an annotation.
+    fn visit_let(
+        &mut self,
+        name: &Token,
+        annotation: Option<&TypeAnnotation>,
+        initializer: Option<&Expression>,
+    ) -> Result<(), Error> {
+        let declared = match annotation.map(|annotation| self.resolve_type(annotation)) {
+            Some(Ok(declared)) => Some(declared),
+            Some(Err(error)) => {
+                self.errors.push(error);
+
+                Some(Type::Unknown)
+            }
+            None => None,
+        };
+
+        let r#type = match (declared, initializer) {
+            (Some(declared), Some(initializer)) => {
+                let found = self.infer(initializer);
+                let result = expect(&declared, &found, initializer);
+                self.record(result);
+
+                declared
+            }
+            (Some(declared), None) => declared,
+            (None, Some(initializer)) => self.infer(initializer),
+            (None, None) => {
+                self.errors.push(Error::MissingTypeAnnotation {
+                    name: name.token_kind.to_string(),
+                    line: name.line,
+                    column: name.column,
+                    span: name.span,
+                });
+
+                Type::Unknown
+            }
+        };
+
+        if self.warn_shadowing {
+            self.warn_shadowed(name);
+        }
+
+        self.declare_variable(name, initializer.is_some(), r#type)
+    }
+
+    /// Warns if a variable being declared shadows a variable or a parameter of an enclosing
+    /// scope. Declaring a variable again in the same scope isn't shadowing.
+    fn warn_shadowed(&mut self, name: &Token) {
+        let key = name.token_kind.to_string();
+        let Some((current, enclosing)) = self.scopes.split_last() else {
+            return;
+        };
+        if current.is_defined(&key) {
+            return;
+        }
+
+        let Some(SymbolKind::Variable {
+            name: previous,
+            is_parameter,
+            ..
+        }) = enclosing
+            .iter()
+            .rev()
+            .find(|scope| scope.is_defined(&key))
+            .and_then(|scope| scope.symbol_table.get(&key))
+        else {
+            return;
+        };
+
+        let (line, column, span, previous) = (name.line, name.column, name.span, previous.span);
+        self.warnings.push(if *is_parameter {
+            Warning::ShadowedParameter {
+                name: key,
+                line,
+                column,
+                span,
+                previous,
+            }
+        } else {
+            Warning::ShadowedVariable {
+                name: key,
+                line,
+                column,
+                span,
+                previous,
+            }
+        });
+    }
+
+    fn visit_assignment(&mut self, name: &Token, value: &Expression) -> Result<Type, Error> {
+        let value_type = self.infer(value);
+
+        // If the variable is not defined, this will return an error.
+        let symbol = self.get_symbol(name)?;
+
+        let SymbolKind::Variable {
+            is_initialized,
+            r#type,
+            ..
+        } = symbol
+        else {
+            return Err(Error::InvalidAssignment {
+                name: name.token_kind.to_string(),
+                line: name.line,
+                column: name.column,
+                span: name.span,
+            });
+        };
+
+        // The first assignment to a variable declared without a value decides its type.
+        if *r#type == Type::Unknown {
+            *r#type = value_type.clone();
+        }
+        *is_initialized = true;
+
+        let declared = r#type.clone();
+        self.variable_types
+            .insert(name.span.start, declared.clone());
+
+        if !widens(&declared, &value_type, value) {
+            types::expect(&declared, &value_type, name)?;
+        }
+
+        Ok(value_type)
+    }
+
+    /// Gets the type of a variable which is both read and assigned, such as by `+=` or `++`.
+    fn visit_update_target(&mut self, name: &Token) -> Result<Type, Error> {
+        // If the variable is not defined, this will return an error.
+        let symbol = self.get_symbol(name)?;
+
+        let SymbolKind::Variable {
+            is_initialized,
+            r#type,
+            ..
+        } = symbol
+        else {
+            return Err(Error::InvalidAssignment {
+                name: name.token_kind.to_string(),
+                line: name.line,
+                column: name.column,
+                span: name.span,
+            });
+        };
+
+        // The variable is read before it's assigned, so it needs a value already.
+        if !*is_initialized {
+            return Err(Error::UninitializedVariable {
+                name: name.token_kind.to_string(),
+                line: name.line,
+                column: name.column,
+                span: name.span,
+            });
+        }
+
+        Ok(r#type.clone())
+    }
+
+    fn visit_update(&mut self, name: &Token) -> Result<Type, Error> {
+        let r#type = self.visit_update_target(name)?;
+
+        // Only numbers can be incremented or decremented, and their type doesn't change.
+        if !r#type.is_numeric() && r#type != Type::Unknown {
+            return Err(Error::TypeMismatch {
+                expected: "int or float".to_string(),
+                found: r#type.to_string(),
+                line: name.line,
+                column: name.column,
+                span: name.span,
+                origin: None,
+            });
+        }
+
+        Ok(r#type)
+    }
+
+    fn visit_compound_assignment(
+        &mut self,
+        name: &Token,
+        operator: &Token,
+        value_type: &Type,
+    ) -> Result<Type, Error> {
+        let r#type = self.visit_update_target(name)?;
+
+        // The operator is checked like the binary operator it applies, and the result has to fit
+        // back into the variable.
+        let Some(kind) = operator.token_kind.compound_operator() else {
+            return Err(Error::InvalidOperator {
+                operator: operator.token_kind.to_string(),
+                line: operator.line,
+                column: operator.column,
+                span: operator.span,
+            });
+        };
+        let binary = Token::new(kind, operator.line, operator.column, operator.span);
+        let result = types::binary(&binary, &r#type, value_type)?;
+        types::expect(&r#type, &result, operator)?;
+
+        Ok(r#type)
+    }
+
+    fn visit_condition(&mut self, condition: &Expression) {
+        let r#type = self.infer(condition);
+
+        let result = expect(&Type::Boolean, &r#type, condition);
+        self.record(result);
+    }
+
+    /// Visits a range loop, whose variable is an integer unless a bound or the step is a float.
+    fn visit_for_range(
+        &mut self,
+        label: Option<&Token>,
+        variable: &Token,
+        start: &Expression,
+        end: &Expression,
+        step: Option<&Expression>,
+        body: &Statement,
+    ) -> Result<(), Error> {
+        // A loop with a zero step would never get anywhere.
+        if let Some(step) = step.filter(|step| is_zero(step)) {
+            let (line, column) = locate(step);
+            self.errors.push(Error::ZeroStep {
+                line,
+                column,
+                span: step.span(),
+            });
+        }
+
+        let mut r#type = Type::Integer;
+        for bound in [Some(start), Some(end), step].into_iter().flatten() {
+            let bound_type = self.infer(bound);
+            let result = expect_numeric(&bound_type, bound);
+            self.record(result);
+
+            r#type = match (r#type, bound_type) {
+                (Type::Float, _) | (_, Type::Float) => Type::Float,
+                (Type::Integer, Type::Integer) => Type::Integer,
+                _ => Type::Unknown,
+            };
+        }
+
+        self.begin_scope();
+        self.declare_variable(variable, true, r#type)?;
+
+        // The two slots after the variable hold the end and the step while the loop runs.
+        let frame = self.frames.last_mut().ok_or(Error::InvalidScope)?;
+        *frame += 2;
+
+        let result = self.visit_loop_body(label, body);
+        self.end_scope();
+
+        result
+    }
+
+    /// Visits a return statement, whose value must be accepted by the return type of the function
+    /// it's in.
+    fn visit_return(&mut self, keyword: &Token, value: Option<&Expression>) -> Result<(), Error> {
+        let (r#type, span) = value.map_or((Type::Unit, keyword.span), |value| {
+            (self.infer(value), value.span())
+        });
+
+        let Some((expected, origin)) = self.return_types.last() else {
+            return Err(Error::ReturnOutsideFunction {
+                line: keyword.line,
+                column: keyword.column,
+                span: keyword.span,
+            });
+        };
+        let is_widened = value.is_some_and(|value| widens(expected, &r#type, value));
+        if !expected.accepts(&r#type) && !is_widened {
+            return Err(Error::TypeMismatch {
+                expected: expected.to_string(),
+                found: r#type.to_string(),
+                line: keyword.line,
+                column: keyword.column,
+                span,
+                origin: *origin,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Visits the body of a loop, where `break` and `continue` are allowed.
+    fn visit_loop_body(&mut self, label: Option<&Token>, body: &Statement) -> Result<(), Error> {
+        self.loops
+            .push(label.map(|label| label.token_kind.to_string()));
+        let result = self.visit_statement(body);
+        self.loops.pop();
+
+        result
+    }
+
+    /// Checks that a `break` or `continue` is inside a loop, and inside the loop it names if it
+    /// has a label.
+    fn visit_jump(&self, keyword: &Token, label: Option<&Token>) -> Result<(), Error> {
+        if self.loops.is_empty() {
+            let (line, column, span) = (keyword.line, keyword.column, keyword.span);
+
+            return Err(if keyword.token_kind == TokenKind::Break {
+                Error::BreakOutsideLoop { line, column, span }
+            } else {
+                Error::ContinueOutsideLoop { line, column, span }
+            });
+        }
+
+        let Some(label) = label else {
+            return Ok(());
+        };
+        let name = label.token_kind.to_string();
+        if self
+            .loops
+            .iter()
+            .any(|loop_label| loop_label.as_ref() == Some(&name))
+        {
+            return Ok(());
+        }
+
+        Err(Error::UndefinedLabel {
+            label: name,
+            line: label.line,
+            column: label.column,
+            span: label.span,
+        })
+    }
+
+    /// Visits a call, whose callee has to be a function, or a variable holding one. The number of
+    /// arguments is checked against the parameters of a user-defined function or a built-in.
+    fn visit_call(
+        &mut self,
+        call: &Expression,
+        callee: &Expression,
+        arguments: &[Expression],
+    ) -> Result<Type, Error> {
+        let mut argument_types = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            argument_types.push(self.infer(argument));
+        }
+
+        // A function is called by its signature, whether it's named directly or stored in a
+        // variable.
+        let callee_type = self.visit_expression(callee)?;
+        match callee_type {
+            Type::Function(parameters, return_type) => {
+                expect_arity(parameters.len(), arguments.len(), call)?;
+
+                for ((expected, found), argument) in
+                    parameters.iter().zip(&argument_types).zip(arguments)
+                {
+                    let result = expect(expected, found, argument);
+                    self.record(result);
+                }
+
+                Ok(*return_type)
+            }
+            Type::Builtin(builtin) => {
+                expect_arity(builtin.arity(), arguments.len(), call)?;
+
+                for (found, argument) in argument_types.iter().zip(arguments) {
+                    if !builtin.accepts(found) {
+                        self.errors
+                            .push(mismatch(builtin.expected(), found, argument));
+                    }
+                }
+
+                Ok(builtin.return_type())
+            }
+            Type::Unknown => Ok(Type::Unknown),
+            found => {
+                let (line, column) = locate(callee);
+
+                Err(Error::NotCallable {
+                    found: found.to_string(),
+                    line,
+                    column,
+                    span: callee.span(),
+                })
+            }
+        }
+    }
+
+    /// Visits a method call, which calls the function with the name of the method, giving it the
+    /// receiver as its first argument. The first parameter of the function must accept the type
+    /// of the receiver, and the receiver counts towards the number of arguments.
+    fn visit_method_call(
+        &mut self,
+        call: &Expression,
+        receiver: &Expression,
+        name: &Token,
+        arguments: &[Expression],
+    ) -> Result<Type, Error> {
+        let receiver_type = self.infer(receiver);
+        let mut argument_types = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            argument_types.push(self.infer(argument));
+        }
+
+        let unknown_method = || Error::UnknownMethod {
+            receiver: receiver_type.to_string(),
+            method: name.token_kind.to_string(),
+            line: name.line,
+            column: name.column,
+            span: name.span,
+        };
+
+        // A method is called by its signature, whether it's a function or a variable holding one.
+        let method_type = match self.get_symbol(name).map_err(|_| unknown_method())? {
+            SymbolKind::Function {
+                parameters,
+                return_type,
+                ..
+            } => Type::Function(parameters.clone(), Box::new(return_type.clone())),
+            SymbolKind::Builtin(builtin) => Type::Builtin(*builtin),
+            SymbolKind::Variable { r#type, .. } => r#type.clone(),
+            SymbolKind::Struct { .. } | SymbolKind::Enum { .. } => return Err(unknown_method()),
+        };
+
+        match method_type {
+            Type::Function(parameters, return_type) => {
+                let Some((first, rest)) = parameters.split_first() else {
+                    return Err(unknown_method());
+                };
+                if !first.accepts(&receiver_type) {
+                    return Err(unknown_method());
+                }
+
+                expect_arity(rest.len() + 1, arguments.len() + 1, call)?;
+
+                for ((expected, found), argument) in rest.iter().zip(&argument_types).zip(arguments)
+                {
+                    let result = expect(expected, found, argument);
+                    self.record(result);
+                }
+
+                Ok(*return_type)
+            }
+            Type::Builtin(builtin) if builtin.accepts(&receiver_type) => {
+                expect_arity(builtin.arity(), arguments.len() + 1, call)?;
+
+                Ok(builtin.return_type())
+            }
+            Type::Unknown => Ok(Type::Unknown),
+            _ => Err(unknown_method()),
+        }
+    }
+
+    /// Visits an array literal, whose elements all have to have the same type, except that mixing
+    /// integers and floats gives an array of floats.
+    fn visit_array(&mut self, elements: &[Expression]) -> Type {
+        let mut element_type = Type::Unknown;
+        let mut first = None;
+
+        for element in elements {
+            let found = self.infer(element);
+
+            element_type = match (element_type, found) {
+                (Type::Unknown, found) => {
+                    first = Some(element);
+
+                    found
+                }
+                (Type::Integer, Type::Float) | (Type::Float, Type::Integer) => Type::Float,
+                (expected, found) if expected.accepts(&found) && found.accepts(&expected) => {
+                    expected
+                }
+                (expected, found) => {
+                    let (line, column) = locate(element);
+
+                    self.errors.push(Error::HeterogeneousArray {
+                        expected: expected.to_string(),
+                        found: found.to_string(),
+                        line,
+                        column,
+                        span: element.span(),
+                        origin: first.map_or_else(|| element.span(), Expression::span),
+                    });
+
+                    expected
+                }
+            };
+        }
+
+        Type::Array(Box::new(element_type))
+    }
+
+    /// Gets the type of the elements of an array which is indexed, checking the index is an integer.
+    fn visit_index(&mut self, array: &Expression, index: &Expression) -> Result<Type, Error> {
+        let array_type = self.infer(array);
+        let index_type = self.infer(index);
+
+        let result = expect(&Type::Integer, &index_type, index);
+        self.record(result);
+
+        match array_type {
+            Type::Array(element) => Ok(*element),
+            Type::Unknown => Ok(Type::Unknown),
+            found => Err(mismatch("array", &found, array)),
+        }
+    }
+}
+
+impl Visitor for SemanticAnalyzer<'_> {
+    fn visit_statement(&mut self, statement: &Statement) -> Result<(), Error> {
+        match statement {
+            Statement::Expression { expression, .. } => {
+                self.infer(expression);
+            }
+            Statement::Variable {
+                name,
+                type_annotation,
+                initializer,
+                ..
+            } => self.visit_let(name, type_annotation.as_ref(), initializer.as_ref())?,
+            Statement::Block { statements, .. } => {
                 self.begin_scope();
+                self.hoist(statements);
                 for statement in statements {
-                    self.visit_statement(statement)?;
+                    let result = self.visit_statement(statement);
+                    self.record(result);
                 }
                 self.end_scope();
-
-                Ok(())
             }
             Statement::If {
                 condition,
                 then_branch,
                 else_branch,
+                ..
             } => {
-                self.visit_expression(condition)?;
-                self.visit_statement(then_branch)?;
+                self.visit_condition(condition);
+                let result = self.visit_statement(then_branch);
+                self.record(result);
 
                 if let Some(else_branch) = else_branch {
                     self.visit_statement(else_branch)?;
                 }
-
-                Ok(())
             }
-            Statement::While { condition, body } => {
-                self.visit_expression(condition)?;
-                self.visit_statement(body)?;
-
-                Ok(())
+            Statement::While {
+                label,
+                condition,
+                body,
+                ..
+            } => {
+                self.visit_condition(condition);
+                self.visit_loop_body(label.as_ref(), body)?;
             }
             Statement::For {
+                label,
                 initializer,
                 condition,
                 increment,
                 body,
+                ..
             } => {
                 if let Some(initializer) = initializer {
-                    self.visit_statement(initializer)?;
+                    let result = self.visit_statement(initializer);
+                    self.record(result);
                 }
                 if let Some(condition) = condition {
-                    self.visit_expression(condition)?;
+                    self.visit_condition(condition);
                 }
                 if let Some(increment) = increment {
-                    self.visit_expression(increment)?;
+                    self.infer(increment);
                 }
-                self.visit_statement(body)?;
-
-                Ok(())
+                self.visit_loop_body(label.as_ref(), body)?;
+            }
+            Statement::ForRange {
+                label,
+                variable,
+                start,
+                end,
+                step,
+                body,
+                ..
+            } => {
+                self.visit_for_range(label.as_ref(), variable, start, end, step.as_deref(), body)?;
             }
             Statement::Function {
                 name,
                 parameters,
                 return_type,
                 body,
-            } => {
-                self.current_scope()?.define(
-                    &name.token_kind.to_string(),
-                    SymbolKind::Function {
-                        return_type: return_type.as_ref().cloned(),
-                    },
-                );
-
-                self.begin_scope();
-
-                // Define the parameters.
-                for (name, kind) in parameters {
-                    if !matches!(kind.token_kind, TokenKind::Identifier(_)) {
-                        return Err(Error::InvalidParameterKind {
-                            name: name.token_kind.to_string(),
-                            line: name.line,
-                            column: name.column,
-                        });
-                    }
-
-                    self.current_scope()?.define(
-                        &name.token_kind.to_string(),
-                        SymbolKind::Variable { is_initialized: true },
-                    );
-                }
-
-                // Examine the body.
-                self.visit_statement(body)?;
-                
-                self.end_scope();
-
-                Ok(())
+                ..
+            } => self.visit_function(name, parameters, return_type.as_ref(), body)?,
+            // Structs and enums are declared when their block is hoisted.
+            Statement::Struct { .. } | Statement::Enum { .. } => {}
+            Statement::Return { keyword, value, .. } => {
+                self.visit_return(keyword, value.as_ref())?;
             }
-            Statement::Return { value, .. } => {
-                if let Some(value) = value {
-                    self.visit_expression(value)?;
-                }
-
-                Ok(())
+            Statement::Break { keyword, label, .. }
+            | Statement::Continue { keyword, label, .. } => {
+                self.visit_jump(keyword, label.as_ref())?;
             }
-            Statement::Break | Statement::Continue => Ok(()),
         }
+
+        Ok(())
     }
 
-    fn visit_expression(&mut self, expression: &Expression) -> Result<(), Error> {
+    fn visit_expression(&mut self, expression: &Expression) -> Result<Type, Error> {
         match expression {
-            Expression::Literal(_) => {}
-            Expression::Variable { name } => {
-                // If the variable is not defined, this will return an error.
-                let symbol = self.get_symbol(name)?;
-
-                // If the variable is not initialized, this will return an error.
-                if let SymbolKind::Variable { is_initialized } = &symbol {
-                    if !is_initialized {
-                        return Err(Error::UninitializedVariable {
-                            name: name.token_kind.to_string(),
-                            line: name.line,
-                            column: name.column,
-                        });
-                    }
-                }
-            }
-            Expression::Assignment { name, value } => {
-                self.visit_expression(value)?;
-
-                // If the variable is not defined, this will return an error.
-                let symbol = self.get_symbol(name)?;
+            Expression::Literal { value: literal, .. } => Ok(literal_type(literal)),
+            Expression::Variable { name, .. } => self.visit_variable(name),
+            Expression::Assignment { name, value, .. } => self.visit_assignment(name, value),
+            Expression::CompoundAssignment {
+                name,
+                operator,
+                value,
+                ..
+            } => {
+                let value_type = self.infer(value);
 
-                if matches!(symbol, SymbolKind::Function { .. }) {
-                    return Err(Error::InvalidAssignment {
-                        name: name.token_kind.to_string(),
-                        line: name.line,
-                        column: name.column,
-                    });
-                }
+                self.visit_compound_assignment(name, operator, &value_type)
             }
+            Expression::Update { name, .. } => self.visit_update(name),
             Expression::Binary {
                 left,
                 operator,
                 right,
+                ..
             } => {
-                self.visit_expression(left)?;
+                let left = self.infer(left);
+                let right = self.infer(right);
 
-                // Make sure the operator makes sense.
-                match operator.token_kind {
-                    TokenKind::Plus | TokenKind::Minus | TokenKind::Star | TokenKind::Slash => {
-                        self.visit_expression(right)?;
-                    }
-                    TokenKind::Equality
-                    | TokenKind::NotEqual
-                    | TokenKind::LessThan
-                    | TokenKind::LessThanOrEqual
-                    | TokenKind::GreaterThan
-                    | TokenKind::GreaterThanOrEqual => {}
-                    _ => {
-                        return Err(Error::InvalidOperator {
-                            operator: operator.token_kind.to_string(),
-                            line: operator.line,
-                            column: operator.column,
-                        });
-                    }
-                }
+                types::binary(operator, &left, &right)
             }
-            Expression::Unary { operator, right } => {
-                // Make sure the operator makes sense.
-                match operator.token_kind {
-                    TokenKind::Plus | TokenKind::Minus | TokenKind::LogicalNot => {
-                        self.visit_expression(right)?;
-                    }
-                    _ => {
-                        return Err(Error::InvalidOperator {
-                            operator: operator.token_kind.to_string(),
-                            line: operator.line,
-                            column: operator.column,
-                        });
-                    }
-                }
+            Expression::Unary {
+                operator, right, ..
+            } => {
+                let right = self.infer(right);
+
+                types::unary(operator, &right)
             }
-            Expression::Call { callee, arguments } => {
-                self.visit_expression(callee)?;
+            Expression::Call {
+                callee, arguments, ..
+            } => self.visit_call(expression, callee, arguments),
+            Expression::Grouping { expression, .. } => self.visit_expression(expression),
+            Expression::Array { elements, .. } => Ok(self.visit_array(elements)),
+            Expression::Index { array, index, .. } => self.visit_index(array, index),
+            Expression::IndexAssignment {
+                array,
+                index,
+                value,
+                ..
+            } => {
+                let element_type = self.visit_index(array, index)?;
+                let value_type = self.infer(value);
 
-                for argument in arguments {
-                    self.visit_expression(argument)?;
+                // Unlike variables and fields, elements have no declared type which the
+                // interpreter could widen an array literal to, so its type has to match.
+                if !element_type.accepts(&value_type) {
+                    return Err(mismatch(&element_type.to_string(), &value_type, value));
                 }
+
+                Ok(value_type)
             }
-            Expression::Grouping { expression } => {
-                self.visit_expression(expression)?;
+            Expression::Struct { name, fields, .. } => self.visit_struct_literal(name, fields),
+            Expression::Field { object, name, .. } => self.visit_field(object, name),
+            Expression::FieldAssignment {
+                object,
+                name,
+                value,
+                ..
+            } => {
+                let field_type = self.visit_field(object, name)?;
+                let value_type = self.infer(value);
+
+                expect(&field_type, &value_type, value)?;
+
+                Ok(value_type)
             }
+            Expression::Variant {
+                enumeration,
+                variant,
+                arguments,
+                ..
+            } => self.visit_variant(enumeration, variant, arguments),
+            Expression::Match {
+                keyword,
+                scrutinee,
+                arms,
+                ..
+            } => self.visit_match(keyword, scrutinee, arms),
+            Expression::MethodCall {
+                receiver,
+                name,
+                arguments,
+                ..
+            } => self.visit_method_call(expression, receiver, name, arguments),
         }
+    }
+}
 
-        Ok(())
+/// Finds the variables of a scope which are never read, except those starting with an underscore.
+fn unused_variables(scope: &Scope) -> Vec<Warning> {
+    scope
+        .symbol_table
+        .values()
+        .filter_map(|symbol| match symbol {
+            SymbolKind::Variable {
+                name,
+                is_used: false,
+                ..
+            } if !name.token_kind.to_string().starts_with('_') => Some(Warning::UnusedVariable {
+                name: name.token_kind.to_string(),
+                line: name.line,
+                column: name.column,
+                span: name.span,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Gets the type of a literal.
+const fn literal_type(literal: &Literal) -> Type {
+    match literal {
+        Literal::String(_) => Type::String,
+        Literal::Integer(_) => Type::Integer,
+        Literal::Float(_) => Type::Float,
+        Literal::Boolean(_) => Type::Boolean,
+    }
+}
+
+/// Checks that the type of an expression is accepted where another type is expected.
+fn expect(expected: &Type, found: &Type, expression: &Expression) -> Result<(), Error> {
+    if expected.accepts(found) || widens(expected, found, expression) {
+        return Ok(());
+    }
+
+    Err(mismatch(&expected.to_string(), found, expression))
+}
+
+/// Checks whether an array literal of integers can be stored where an array of floats is
+/// expected. Unlike other arrays, a literal isn't shared with anything yet, so its elements can
+/// be widened, including those of the array literals nested in it.
+fn widens(expected: &Type, found: &Type, expression: &Expression) -> bool {
+    match (expected, found, expression) {
+        (Type::Array(expected), Type::Array(found), Expression::Array { elements, .. }) => {
+            expected.accepts(found)
+                || elements
+                    .iter()
+                    .all(|element| widens(expected, found, element))
+        }
+        _ => false,
     }
 }
+
+/// Reports a name declared twice in one scope, pointing at whichever declaration comes later in
+/// the source, since types are hoisted before functions.
+fn duplicate_declaration(kind: &str, name: &Token, existing: &SymbolKind) -> Error {
+    let (kind, later, earlier) = match existing.name() {
+        Some(previous) if previous.span.start > name.span.start => {
+            (existing.kind(), previous, Some(name))
+        }
+        previous => (kind, name, previous),
+    };
+
+    Error::DuplicateDeclaration {
+        kind: kind.to_string(),
+        name: later.token_kind.to_string(),
+        line: later.line,
+        column: later.column,
+        span: later.span,
+        previous: earlier.map(|token| token.span),
+    }
+}
+
+/// Checks that a call passes as many arguments as the function it calls has parameters.
+fn expect_arity(expected: usize, found: usize, call: &Expression) -> Result<(), Error> {
+    if expected == found {
+        return Ok(());
+    }
+
+    let (line, column) = locate(call);
+
+    Err(Error::ArityMismatch {
+        expected,
+        found,
+        line,
+        column,
+        span: call.span(),
+    })
+}
+
+/// Checks whether a statement returns on every path. Other loops may run zero times, so only a
+/// loop without a condition which is never broken out of counts.
+fn returns(statement: &Statement) -> bool {
+    match statement {
+        Statement::Return { .. } => true,
+        Statement::Block { statements, .. } => statements.iter().any(returns),
+        Statement::If {
+            then_branch,
+            else_branch: Some(else_branch),
+            ..
+        } => returns(then_branch) && returns(else_branch),
+        Statement::While {
+            label,
+            condition:
+                Expression::Literal {
+                    value: Literal::Boolean(true),
+                    ..
+                },
+            body,
+            ..
+        }
+        | Statement::For {
+            label,
+            condition: None,
+            body,
+            ..
+        } => !breaks(body, label.as_ref(), true),
+        _ => false,
+    }
+}
+
+/// Checks whether a statement can break out of the loop with the given label, where `innermost`
+/// tells whether that loop is the closest one, which an unlabeled break leaves.
+fn breaks(statement: &Statement, label: Option<&Token>, innermost: bool) -> bool {
+    match statement {
+        Statement::Break { label: None, .. } => innermost,
+        Statement::Break {
+            label: Some(target),
+            ..
+        } => label.is_some_and(|label| label.token_kind == target.token_kind),
+        Statement::Block { statements, .. } => statements
+            .iter()
+            .any(|statement| breaks(statement, label, innermost)),
+        Statement::If {
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            breaks(then_branch, label, innermost)
+                || else_branch
+                    .as_ref()
+                    .is_some_and(|branch| breaks(branch, label, innermost))
+        }
+        Statement::While { body, .. }
+        | Statement::For { body, .. }
+        | Statement::ForRange { body, .. } => breaks(body, label, false),
+        _ => false,
+    }
+}
+
+/// Checks whether an expression is a literal zero, possibly in parentheses.
+fn is_zero(expression: &Expression) -> bool {
+    match expression {
+        Expression::Literal {
+            value: Literal::Integer(0),
+            ..
+        } => true,
+        Expression::Literal {
+            value: Literal::Float(value),
+            ..
+        } => *value == 0.0,
+        Expression::Grouping { expression, .. } => is_zero(expression),
+        _ => false,
+    }
+}
+
+/// Checks that the type of an expression is `int` or `float`.
+fn expect_numeric(found: &Type, expression: &Expression) -> Result<(), Error> {
+    if found.is_numeric() || *found == Type::Unknown {
+        return Ok(());
+    }
+
+    Err(mismatch("int or float", found, expression))
+}
+
+fn mismatch(expected: &str, found: &Type, expression: &Expression) -> Error {
+    let (line, column) = locate(expression);
+
+    Error::TypeMismatch {
+        expected: expected.to_string(),
+        found: found.to_string(),
+        line,
+        column,
+        span: expression.span(),
+        origin: None,
+    }
+}
+
+/// Finds the line and column of the first token of an expression, or line 0, column 0 for literals.
+fn locate(expression: &Expression) -> (usize, usize) {
+    expression
+        .first_token()
+        .map_or((0, 0), |token| (token.line, token.column))
+}
@@ -0,0 +1,252 @@
+use crate::lexer::tokens::TokenKind;
+use crate::parser::Literal;
+use crate::semantics::types::Type;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// A number, as shared by the constant folder and both ways of running a program.
+///
+/// Mixing integers and floats gives a float, just like the type checker infers.
+///
+/// # Variants
+///
+/// * `Integer` - An integer, such as `1`.
+/// * `Float` - A float, such as `2.5`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Number {
+    /// Converts the number to a float.
+    ///
+    /// # Returns
+    ///
+    /// * `f64` - The number as a float, which may lose precision for large integers.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn as_float(self) -> f64 {
+        match self {
+            Self::Integer(value) => value as f64,
+            Self::Float(value) => value,
+        }
+    }
+
+    /// Widens an integer to a float where a float is expected, since the type checker accepts
+    /// integers there.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - The type expected where the number is stored.
+    ///
+    /// # Returns
+    ///
+    /// * `Number` - The number, as a float if one is expected.
+    #[must_use]
+    pub const fn widen(self, expected: &Type) -> Self {
+        match (self, expected) {
+            (Self::Integer(_), Type::Float) => Self::Float(self.as_float()),
+            _ => self,
+        }
+    }
+
+    /// Compares two numbers, widening an integer to a float if the other one is a float.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The number to compare to.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Ordering>` - The ordering, or `None` if a float is `NaN`.
+    #[must_use]
+    pub fn compare(self, other: Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Integer(left), Self::Integer(right)) => Some(left.cmp(&right)),
+            _ => self.as_float().partial_cmp(&other.as_float()),
+        }
+    }
+
+    /// Negates the number.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Number, ArithmeticError>` - The negated number, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * If the number is `i64::MIN`, which has no positive counterpart.
+    pub fn negate(self) -> Result<Self, ArithmeticError> {
+        match self {
+            Self::Integer(value) => value
+                .checked_neg()
+                .map(Self::Integer)
+                .ok_or(ArithmeticError::Overflow),
+            Self::Float(value) => Ok(Self::Float(-value)),
+        }
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            // Whole floats keep a fractional part, so they don't read as integers.
+            Self::Float(value) if value.is_finite() && value.fract() == 0.0 => {
+                write!(f, "{value:.1}")
+            }
+            Self::Float(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<Number> for Literal {
+    fn from(number: Number) -> Self {
+        match number {
+            Number::Integer(value) => Self::Integer(value),
+            Number::Float(value) => Self::Float(value),
+        }
+    }
+}
+
+/// Why an arithmetic operation failed.
+///
+/// # Variants
+///
+/// * `DivisionByZero` - A number was divided by zero.
+/// * `Overflow` - The result of an integer operation doesn't fit in 64 bits.
+/// * `NegativeExponent` - An integer was raised to a negative power.
+/// * `InvalidShift` - An integer was shifted by a negative amount, or by 64 bits or more.
+/// * `ExpectedIntegers` - A bitwise operator was applied to a float.
+/// * `UnsupportedOperator` - The operator isn't an arithmetic one.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticError {
+    #[error("Division by zero.")]
+    DivisionByZero,
+    #[error("The result doesn't fit in a 64-bit integer.")]
+    Overflow,
+    #[error("Integers can't be raised to a negative power.")]
+    NegativeExponent,
+    #[error("Integers can only be shifted by 0 to 63 bits.")]
+    InvalidShift,
+    #[error("Expected integers.")]
+    ExpectedIntegers,
+    #[error("Unsupported operator.")]
+    UnsupportedOperator,
+}
+
+/// Applies an arithmetic or bitwise operator to two numbers.
+///
+/// # Arguments
+///
+/// * `kind` - The operator, such as `+` or `<<`.
+/// * `left` - The left operand.
+/// * `right` - The right operand.
+///
+/// # Returns
+///
+/// * `Result<Number, ArithmeticError>` - The result, or an error.
+///
+/// # Errors
+///
+/// * If a number is divided by zero.
+/// * If the result of an integer operation overflows.
+/// * If a bitwise operator is applied to a float.
+/// * If the operator isn't an arithmetic one.
+pub fn arithmetic(
+    kind: &TokenKind,
+    left: Number,
+    right: Number,
+) -> Result<Number, ArithmeticError> {
+    match (left, right) {
+        (Number::Integer(left), Number::Integer(right)) => {
+            integer(kind, left, right).map(Number::Integer)
+        }
+        _ => float(kind, left.as_float(), right.as_float()).map(Number::Float),
+    }
+}
+
+fn integer(kind: &TokenKind, left: i64, right: i64) -> Result<i64, ArithmeticError> {
+    let result = match kind {
+        TokenKind::Plus => left.checked_add(right),
+        TokenKind::Minus => left.checked_sub(right),
+        TokenKind::Star => left.checked_mul(right),
+        TokenKind::Slash | TokenKind::Percent if right == 0 => {
+            return Err(ArithmeticError::DivisionByZero)
+        }
+        TokenKind::Slash => left.checked_div(right),
+        TokenKind::Percent => left.checked_rem(right),
+        TokenKind::Power if right < 0 => return Err(ArithmeticError::NegativeExponent),
+        TokenKind::Power => u32::try_from(right)
+            .ok()
+            .and_then(|exponent| left.checked_pow(exponent)),
+        TokenKind::BitwiseAnd => Some(left & right),
+        TokenKind::BitwiseOr => Some(left | right),
+        TokenKind::BitwiseXor => Some(left ^ right),
+        TokenKind::BitwiseLeftShift | TokenKind::BitwiseRightShift => {
+            let amount = u32::try_from(right).map_err(|_| ArithmeticError::InvalidShift)?;
+            let shifted = if *kind == TokenKind::BitwiseLeftShift {
+                left.checked_shl(amount)
+            } else {
+                left.checked_shr(amount)
+            };
+
+            return shifted.ok_or(ArithmeticError::InvalidShift);
+        }
+        _ => return Err(ArithmeticError::UnsupportedOperator),
+    };
+
+    result.ok_or(ArithmeticError::Overflow)
+}
+
+fn float(kind: &TokenKind, left: f64, right: f64) -> Result<f64, ArithmeticError> {
+    Ok(match kind {
+        TokenKind::Plus => left + right,
+        TokenKind::Minus => left - right,
+        TokenKind::Star => left * right,
+        TokenKind::Slash | TokenKind::Percent if right == 0.0 => {
+            return Err(ArithmeticError::DivisionByZero)
+        }
+        TokenKind::Slash => left / right,
+        TokenKind::Percent => left % right,
+        TokenKind::Power => left.powf(right),
+        TokenKind::BitwiseAnd
+        | TokenKind::BitwiseOr
+        | TokenKind::BitwiseXor
+        | TokenKind::BitwiseLeftShift
+        | TokenKind::BitwiseRightShift => return Err(ArithmeticError::ExpectedIntegers),
+        _ => return Err(ArithmeticError::UnsupportedOperator),
+    })
+}
+
+/// Applies a comparison operator to two numbers.
+///
+/// # Arguments
+///
+/// * `kind` - The operator, such as `<` or `==`.
+/// * `left` - The left operand.
+/// * `right` - The right operand.
+///
+/// # Returns
+///
+/// * `Option<bool>` - The result, or `None` if the operator isn't a comparison.
+#[must_use]
+pub fn compare(kind: &TokenKind, left: Number, right: Number) -> Option<bool> {
+    let ordering = left.compare(right);
+
+    Some(match kind {
+        TokenKind::Equality => ordering == Some(Ordering::Equal),
+        TokenKind::NotEqual => ordering != Some(Ordering::Equal),
+        TokenKind::LessThan => ordering == Some(Ordering::Less),
+        TokenKind::LessThanOrEqual => {
+            matches!(ordering, Some(Ordering::Less | Ordering::Equal))
+        }
+        TokenKind::GreaterThan => ordering == Some(Ordering::Greater),
+        TokenKind::GreaterThanOrEqual => {
+            matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+        }
+        _ => return None,
+    })
+}
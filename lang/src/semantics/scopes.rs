@@ -1,3 +1,4 @@
+use crate::semantics::builtins::Builtin;
 use crate::semantics::symbols::SymbolKind;
 use std::collections::HashMap;
 
@@ -6,12 +7,46 @@ use std::collections::HashMap;
 /// # Fields
 ///
 /// * `symbol_table` - A symbol table which maps a symbol to its kind.
+/// * `frame` - The depth of the function the scope belongs to, where `0` is the top-level code.
 #[derive(Debug, Default)]
 pub struct Scope {
     pub symbol_table: HashMap<String, SymbolKind>,
+    pub frame: usize,
 }
 
 impl Scope {
+    /// Creates a new, empty scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The depth of the function the scope belongs to.
+    ///
+    /// # Returns
+    ///
+    /// * `Scope` - The new scope.
+    #[must_use]
+    pub fn new(frame: usize) -> Self {
+        Self {
+            symbol_table: HashMap::new(),
+            frame,
+        }
+    }
+
+    /// Creates the outermost scope, which contains the built-in functions.
+    ///
+    /// # Returns
+    ///
+    /// * `Scope` - The prelude scope.
+    #[must_use]
+    pub fn prelude() -> Self {
+        let mut scope = Self::default();
+        for builtin in Builtin::ALL {
+            scope.define(builtin.name(), SymbolKind::Builtin(*builtin));
+        }
+
+        scope
+    }
+
     /// Adds a symbol to the symbol table.
     ///
     /// # Arguments
@@ -1,17 +1,108 @@
 use crate::lexer::tokens::Token;
+use crate::semantics::builtins::Builtin;
+use crate::semantics::types::Type;
 
 /// A symbol kind which defines the type of a symbol.
 ///
 /// # Variants
 ///
-/// * `Variable` - A variable.
-/// * `Function` - A function.
+/// * `Variable` - A variable, along with the token which declared it, whether it's ever read and
+///   whether it's a parameter of a function.
+/// * `Function` - A function, along with the token which declared it, the types of its
+///   parameters in order and its return type.
+/// * `Builtin` - A built-in function.
+/// * `Struct` - A struct, along with the token which declared it and its fields in the order
+///   they're declared.
+/// * `Enum` - An enum, along with the token which declared it and its variants in the order
+///   they're declared, each with the types of its values.
 #[derive(Debug)]
 pub enum SymbolKind {
     Variable {
+        name: Token,
         is_initialized: bool,
+        is_used: bool,
+        is_parameter: bool,
+        location: Location,
+        r#type: Type,
     },
     Function {
-        return_type: Option<Token>,
+        name: Token,
+        parameters: Vec<Type>,
+        return_type: Type,
+        id: usize,
     },
+    Builtin(Builtin),
+    Struct {
+        name: Token,
+        fields: Vec<(String, Type)>,
+    },
+    Enum {
+        name: Token,
+        variants: Vec<(String, Vec<Type>)>,
+    },
+}
+
+impl SymbolKind {
+    /// Gets the token which declared the symbol.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<&Token>` - The token, or `None` for a built-in, which isn't declared in the code.
+    #[must_use]
+    pub const fn name(&self) -> Option<&Token> {
+        match self {
+            Self::Variable { name, .. }
+            | Self::Function { name, .. }
+            | Self::Struct { name, .. }
+            | Self::Enum { name, .. } => Some(name),
+            Self::Builtin(_) => None,
+        }
+    }
+
+    /// Describes what the symbol declares, for error messages.
+    ///
+    /// # Returns
+    ///
+    /// * `&'static str` - The description, such as `function`.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Variable { .. } => "variable",
+            Self::Function { .. } | Self::Builtin(_) => "function",
+            Self::Struct { .. } => "struct",
+            Self::Enum { .. } => "enum",
+        }
+    }
+}
+
+/// Where a variable is stored at runtime.
+///
+/// # Variants
+///
+/// * `Global` - A global variable, by its slot among the globals.
+/// * `Local` - A local variable, by its slot in the frame of the function declaring it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Global(usize),
+    Local(usize),
+}
+
+/// What a name refers to at the place it's used.
+///
+/// # Variants
+///
+/// * `Global` - A global variable, by its slot.
+/// * `Local` - A local variable of the current function, by its slot.
+/// * `Function` - A user-defined function, by its id.
+/// * `Builtin` - A built-in function.
+/// * `Struct` - A struct, which is only a type and never a value.
+/// * `Enum` - An enum, which is only a type, whose variants are the values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Global(usize),
+    Local(usize),
+    Function(usize),
+    Builtin(Builtin),
+    Struct,
+    Enum,
 }
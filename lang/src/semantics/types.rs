@@ -0,0 +1,342 @@
+use crate::lexer::tokens::{Token, TokenKind};
+use crate::parser::TypeAnnotation;
+use crate::semantics::builtins::Builtin;
+use crate::semantics::errors::Error;
+use std::fmt::{Display, Formatter};
+
+/// The static type of a value.
+///
+/// # Variants
+///
+/// * `String` - A string, written `str`.
+/// * `Integer` - An integer, written `int`.
+/// * `Float` - A float, written `float`.
+/// * `Boolean` - A boolean, written `bool`.
+/// * `Unit` - The type of functions which don't return anything, written `void`.
+/// * `Function` - A function, by the types of its parameters and of its value, written like
+///   `fn(int, str) -> bool`.
+/// * `Builtin` - A built-in function, which has no single signature since it accepts values of
+///   several types.
+/// * `Array` - An array of elements of one type, written `[T]`.
+/// * `Struct` - A struct, by its name and where its declaration starts, which tells apart structs
+///   of the same name declared in different blocks.
+/// * `Enum` - An enum, by its name and where its declaration starts.
+/// * `Unknown` - A type which hasn't been inferred yet, such as that of an uninitialized variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    String,
+    Integer,
+    Float,
+    Boolean,
+    Unit,
+    Function(Vec<Self>, Box<Self>),
+    Builtin(Builtin),
+    Array(Box<Self>),
+    Struct(String, usize),
+    Enum(String, usize),
+    Unknown,
+}
+
+impl Type {
+    /// Resolves a type from the token naming it.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token naming the type, such as `int`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Type, Error>` - The type, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * If the token doesn't name a type.
+    pub fn from_token(token: &Token) -> Result<Self, Error> {
+        let TokenKind::Identifier(name) = &token.token_kind else {
+            return Err(Error::UnknownType {
+                name: token.token_kind.to_string(),
+                line: token.line,
+                column: token.column,
+                span: token.span,
+            });
+        };
+
+        match name.as_str() {
+            "str" => Ok(Self::String),
+            "int" => Ok(Self::Integer),
+            "float" => Ok(Self::Float),
+            "bool" => Ok(Self::Boolean),
+            "void" => Ok(Self::Unit),
+            _ => Err(Error::UnknownType {
+                name: name.clone(),
+                line: token.line,
+                column: token.column,
+                span: token.span,
+            }),
+        }
+    }
+
+    /// Resolves a type from how it's written in the source.
+    ///
+    /// # Arguments
+    ///
+    /// * `annotation` - The type as written, such as `[int]`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Type, Error>` - The type, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * If a name in the annotation doesn't name a type.
+    pub fn from_annotation(annotation: &TypeAnnotation) -> Result<Self, Error> {
+        match annotation {
+            TypeAnnotation::Named(token) => Self::from_token(token),
+            TypeAnnotation::Array { element, .. } => {
+                Ok(Self::Array(Box::new(Self::from_annotation(element)?)))
+            }
+        }
+    }
+
+    /// Checks whether the type is `int` or `float`.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the type is numeric.
+    #[must_use]
+    pub const fn is_numeric(&self) -> bool {
+        matches!(self, Self::Integer | Self::Float)
+    }
+
+    /// Checks whether integers are widened to floats where the type is expected, which is the
+    /// case for floats and for arrays of them.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether integers are widened.
+    #[must_use]
+    pub fn widens_integers(&self) -> bool {
+        match self {
+            Self::Float => true,
+            Self::Array(element) => element.widens_integers(),
+            _ => false,
+        }
+    }
+
+    /// Checks whether a value of another type can be stored where this type is expected.
+    ///
+    /// Integers are widened to floats, and unknown types are accepted everywhere. The elements of
+    /// arrays aren't widened, since arrays are shared rather than copied, so their types have to
+    /// match, and so do the signatures of functions. Array literals are the exception, which the
+    /// analyzer checks on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The type of the value.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the value is accepted.
+    #[must_use]
+    pub fn accepts(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Array(expected), Self::Array(found)) => expected.matches(found),
+            (Self::Function(expected, expected_return), Self::Function(found, found_return)) => {
+                expected.len() == found.len()
+                    && expected
+                        .iter()
+                        .zip(found)
+                        .all(|(expected, found)| expected.matches(found))
+                    && expected_return.matches(found_return)
+            }
+            _ => {
+                self == other
+                    || matches!((self, other), (Self::Float, Self::Integer))
+                    || matches!(self, Self::Unknown)
+                    || matches!(other, Self::Unknown)
+            }
+        }
+    }
+
+    /// Checks whether values of two types can be stored where either is expected.
+    fn matches(&self, other: &Self) -> bool {
+        self.accepts(other) && other.accepts(self)
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::String => write!(f, "str"),
+            Self::Integer => write!(f, "int"),
+            Self::Float => write!(f, "float"),
+            Self::Boolean => write!(f, "bool"),
+            Self::Unit => write!(f, "void"),
+            Self::Function(parameters, return_type) => {
+                write!(f, "fn(")?;
+                for (index, parameter) in parameters.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{parameter}")?;
+                }
+                write!(f, ") -> {return_type}")
+            }
+            Self::Builtin(builtin) => write!(f, "builtin {}", builtin.name()),
+            Self::Array(element) => write!(f, "[{element}]"),
+            Self::Struct(name, _) | Self::Enum(name, _) => write!(f, "{name}"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Infers the type of a unary expression.
+///
+/// # Arguments
+///
+/// * `operator` - The operator.
+/// * `right` - The type of the operand.
+///
+/// # Returns
+///
+/// * `Result<Type, Error>` - The type of the expression, or an error.
+///
+/// # Errors
+///
+/// * If the operator doesn't exist.
+/// * If the operand has the wrong type.
+pub fn unary(operator: &Token, right: &Type) -> Result<Type, Error> {
+    match operator.token_kind {
+        TokenKind::Minus if right.is_numeric() || *right == Type::Unknown => Ok(right.clone()),
+        TokenKind::Minus => Err(mismatch("int or float", right, operator)),
+        TokenKind::LogicalNot => expect(&Type::Boolean, right, operator).map(|()| Type::Boolean),
+        _ => Err(Error::InvalidOperator {
+            operator: operator.token_kind.to_string(),
+            line: operator.line,
+            column: operator.column,
+            span: operator.span,
+        }),
+    }
+}
+
+/// Infers the type of a binary expression.
+///
+/// # Arguments
+///
+/// * `operator` - The operator.
+/// * `left` - The type of the left operand.
+/// * `right` - The type of the right operand.
+///
+/// # Returns
+///
+/// * `Result<Type, Error>` - The type of the expression, or an error.
+///
+/// # Errors
+///
+/// * If the operator doesn't exist.
+/// * If the operands have the wrong types.
+pub fn binary(operator: &Token, left: &Type, right: &Type) -> Result<Type, Error> {
+    match operator.token_kind {
+        TokenKind::Plus if *left == Type::String || *right == Type::String => {
+            expect(&Type::String, left, operator)?;
+            expect(&Type::String, right, operator)?;
+
+            Ok(Type::String)
+        }
+        TokenKind::Plus
+        | TokenKind::Minus
+        | TokenKind::Star
+        | TokenKind::Slash
+        | TokenKind::Percent
+        | TokenKind::Power => arithmetic(operator, left, right),
+        TokenKind::LessThan
+        | TokenKind::LessThanOrEqual
+        | TokenKind::GreaterThan
+        | TokenKind::GreaterThanOrEqual => {
+            // Strings are compared lexicographically.
+            if *left == Type::String {
+                expect(&Type::String, right, operator)?;
+            } else {
+                arithmetic(operator, left, right)?;
+            }
+
+            Ok(Type::Boolean)
+        }
+        TokenKind::Equality | TokenKind::NotEqual => {
+            let comparable = left.accepts(right) || (left.is_numeric() && right.is_numeric());
+            if !comparable {
+                return Err(mismatch(&left.to_string(), right, operator));
+            }
+
+            Ok(Type::Boolean)
+        }
+        TokenKind::BitwiseAnd
+        | TokenKind::BitwiseOr
+        | TokenKind::BitwiseXor
+        | TokenKind::BitwiseLeftShift
+        | TokenKind::BitwiseRightShift => {
+            // Bitwise operators only make sense on integers, so floats aren't truncated implicitly.
+            expect(&Type::Integer, left, operator)?;
+            expect(&Type::Integer, right, operator)?;
+
+            Ok(Type::Integer)
+        }
+        TokenKind::LogicalAnd | TokenKind::LogicalOr => {
+            expect(&Type::Boolean, left, operator)?;
+            expect(&Type::Boolean, right, operator)?;
+
+            Ok(Type::Boolean)
+        }
+        _ => Err(Error::InvalidOperator {
+            operator: operator.token_kind.to_string(),
+            line: operator.line,
+            column: operator.column,
+            span: operator.span,
+        }),
+    }
+}
+
+/// Infers the type of an arithmetic expression, where mixing integers and floats gives a float.
+fn arithmetic(operator: &Token, left: &Type, right: &Type) -> Result<Type, Error> {
+    for operand in [left, right] {
+        if !operand.is_numeric() && *operand != Type::Unknown {
+            return Err(mismatch("int or float", operand, operator));
+        }
+    }
+
+    Ok(match (left, right) {
+        (Type::Integer, Type::Integer) => Type::Integer,
+        (Type::Unknown, _) | (_, Type::Unknown) => Type::Unknown,
+        _ => Type::Float,
+    })
+}
+
+/// Checks that a type is accepted where another one is expected.
+///
+/// # Arguments
+///
+/// * `expected` - The expected type.
+/// * `found` - The type found.
+/// * `location` - The token to report an error at.
+///
+/// # Errors
+///
+/// * If the type found isn't accepted.
+pub fn expect(expected: &Type, found: &Type, location: &Token) -> Result<(), Error> {
+    if expected.accepts(found) {
+        Ok(())
+    } else {
+        Err(mismatch(&expected.to_string(), found, location))
+    }
+}
+
+fn mismatch(expected: &str, found: &Type, location: &Token) -> Error {
+    Error::TypeMismatch {
+        expected: expected.to_string(),
+        found: found.to_string(),
+        line: location.line,
+        column: location.column,
+        span: location.span,
+        origin: None,
+    }
+}
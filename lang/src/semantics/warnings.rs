@@ -0,0 +1,89 @@
+use crate::diagnostics::{Diagnostic, Label};
+use crate::lexer::span::Span;
+use thiserror::Error;
+
+/// A semantic warning, for code which is valid but probably doesn't do what was intended.
+///
+/// # Variants
+///
+/// * `UnusedVariable` - A variable is declared but never read.
+/// * `ShadowedVariable` - A variable hides a variable of an enclosing scope.
+/// * `ShadowedParameter` - A variable hides a parameter of the function it's in.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Warning {
+    #[error("The variable '{name}' at line {line} and column {column} is never used!")]
+    UnusedVariable {
+        name: String,
+        line: usize,
+        column: usize,
+        span: Span,
+    },
+    #[error("The variable '{name}' at line {line} and column {column} shadows a variable!")]
+    ShadowedVariable {
+        name: String,
+        line: usize,
+        column: usize,
+        span: Span,
+        previous: Span,
+    },
+    #[error("The variable '{name}' at line {line} and column {column} shadows a parameter!")]
+    ShadowedParameter {
+        name: String,
+        line: usize,
+        column: usize,
+        span: Span,
+        previous: Span,
+    },
+}
+
+impl Warning {
+    /// Gets where in the source code the warning is.
+    ///
+    /// # Returns
+    ///
+    /// * `Span` - The span of the code the warning is about.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::UnusedVariable { span, .. }
+            | Self::ShadowedVariable { span, .. }
+            | Self::ShadowedParameter { span, .. } => *span,
+        }
+    }
+}
+
+impl From<&Warning> for Diagnostic {
+    fn from(warning: &Warning) -> Self {
+        match warning {
+            Warning::UnusedVariable { name, span, .. } => {
+                Self::warning("W0201", format!("The variable '{name}' is never used."))
+                    .with_label(Label::primary(*span, "declared here"))
+                    .with_help(format!(
+                        "if this is intentional, prefix it with an underscore: '_{name}'"
+                    ))
+            }
+            Warning::ShadowedVariable {
+                name,
+                span,
+                previous,
+                ..
+            } => Self::warning(
+                "W0202",
+                format!("The variable '{name}' shadows a variable of an enclosing scope."),
+            )
+            .with_label(Label::primary(*span, "shadows the outer variable"))
+            .with_label(Label::secondary(*previous, "outer variable declared here")),
+            Warning::ShadowedParameter {
+                name,
+                span,
+                previous,
+                ..
+            } => Self::warning(
+                "W0203",
+                format!("The variable '{name}' shadows a parameter."),
+            )
+            .with_label(Label::primary(*span, "shadows the parameter"))
+            .with_label(Label::secondary(*previous, "parameter declared here")),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use super::compile;
+use lang::bytecode::chunk::Instruction;
+use lang::bytecode::values::Value;
+use lang::semantics::numbers::Number;
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_constant_folding() {
+    let input = r#"
+        let x = (1 + 2) * 3;
+        let y = "a" + "b";
+        let z = 1 << 4 | 3 ** 2 - 8;
+    "#;
+
+    let program = compile(input).unwrap();
+
+    assert_eq!(
+        program.script.chunk.code,
+        [
+            Instruction::Constant(0),
+            Instruction::SetGlobal(0),
+            Instruction::Pop,
+            Instruction::Constant(1),
+            Instruction::SetGlobal(1),
+            Instruction::Pop,
+            Instruction::Constant(2),
+            Instruction::SetGlobal(2),
+            Instruction::Pop,
+            Instruction::Constant(3),
+            Instruction::Return,
+        ]
+    );
+    assert_eq!(
+        program.script.chunk.constants,
+        [
+            Value::Number(Number::Integer(9)),
+            Value::String("ab".into()),
+            Value::Number(Number::Integer(17)),
+            Value::Unit
+        ]
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_local_slots() {
+    let input = r"
+        fn add(a: int, b: int) -> int {
+            let sum = a + b;
+
+            return sum;
+        }
+    ";
+
+    let program = compile(input).unwrap();
+    let add = &program.functions[0];
+
+    assert_eq!(add.name, "add");
+    assert_eq!(add.arity, 2);
+    assert_eq!(add.locals, 3);
+    assert_eq!(
+        add.chunk.code[..6],
+        [
+            Instruction::GetLocal(0),
+            Instruction::GetLocal(1),
+            Instruction::Add,
+            Instruction::SetLocal(2),
+            Instruction::Pop,
+            Instruction::GetLocal(2),
+        ]
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_while_jumps() {
+    let input = r"
+        let i = 0;
+        while (i < 3) {
+            i = i + 1;
+        }
+    ";
+
+    let program = compile(input).unwrap();
+
+    assert_eq!(
+        program.script.chunk.code[3..],
+        [
+            Instruction::GetGlobal(0),
+            Instruction::Constant(1),
+            Instruction::Less,
+            Instruction::JumpIfFalse(14),
+            Instruction::Pop,
+            Instruction::GetGlobal(0),
+            Instruction::Constant(2),
+            Instruction::Add,
+            Instruction::SetGlobal(0),
+            Instruction::Pop,
+            Instruction::Jump(3),
+            Instruction::Pop,
+            Instruction::Constant(3),
+            Instruction::Return,
+        ]
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_disassembly() {
+    let input = r#"
+        print("Hi");
+    "#;
+
+    let program = compile(input).unwrap();
+    let listing = program.to_string();
+
+    assert!(listing.contains("== <script> (arity: 0, locals: 0) =="));
+    assert!(listing.contains("CONSTANT        0 (<builtin print>)"));
+    assert!(listing.contains("CONSTANT        1 (\"Hi\")"));
+    assert!(listing.contains("CALL            1"));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_match_fallthrough() {
+    let input = r"
+        enum Shape { Circle(float), Empty }
+
+        let shape = Shape::Circle(1.5);
+        print(match shape {
+            Shape::Circle(radius) => radius,
+            Shape::Empty => 0.0,
+        });
+    ";
+
+    let program = compile(input).unwrap();
+    let listing = program.to_string();
+
+    // Variants are compared by their enum too, and a value no arm matches fails.
+    assert!(listing.contains("(\"Shape::Circle\")"));
+    assert!(listing.contains("(\"Shape::Empty\")"));
+    assert!(listing.contains("NO_MATCH"));
+}
@@ -0,0 +1,37 @@
+mod compiler;
+mod vm;
+
+use lang::bytecode::chunk::Program;
+use lang::bytecode::errors::Error;
+use lang::bytecode::vm::VirtualMachine;
+use lang::bytecode::Compiler;
+use lang::lexer::Lexer;
+use lang::parser::Parser;
+use lang::semantics::SemanticAnalyzer;
+
+/// Compiles a program into bytecode.
+#[allow(clippy::unwrap_used)]
+fn compile(input: &str) -> Result<Program, Error> {
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (ast, errors) = parser.parse();
+    assert_eq!(errors, []);
+
+    let mut semantics = SemanticAnalyzer::new(&ast);
+    semantics.analyze().unwrap();
+
+    Compiler::new(&ast, &semantics).compile()
+}
+
+/// Compiles and runs a program, returning everything it printed.
+#[allow(clippy::unwrap_used)]
+fn run(input: &str) -> Result<String, Error> {
+    let program = compile(input)?;
+
+    let mut output = Vec::new();
+    VirtualMachine::new(&program, &mut output).run()?;
+
+    Ok(String::from_utf8(output).unwrap())
+}
@@ -0,0 +1,567 @@
+use super::run;
+use lang::bytecode::errors::Error;
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_hello_world() {
+    let input = r#"
+        fn greet(name: str) -> str {
+            return "Hello, " + name + "!";
+        }
+
+        let name = "World";
+        print(greet(name));
+    "#;
+
+    assert_eq!(run(input).unwrap(), "Hello, World!\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_recursion() {
+    let input = r"
+        fn fib(n: int) -> int {
+            if (n < 2) {
+                return n;
+            }
+
+            return fib(n - 1) + fib(n - 2);
+        }
+
+        print(fib(15));
+    ";
+
+    assert_eq!(run(input).unwrap(), "610\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_loops() {
+    let input = r"
+        let sum = 0;
+        for (let i = 0; i < 10; i = i + 1) {
+            if (i == 3) {
+                continue;
+            }
+            if (i == 6) {
+                break;
+            }
+
+            sum = sum + i;
+        }
+
+        let j = 0;
+        while (j < 100) {
+            j = j + 7;
+        }
+
+        print(sum);
+        print(j);
+    ";
+
+    assert_eq!(run(input).unwrap(), "12\n105\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_for_range() {
+    let input = r"
+        fn sum(n: int) -> int {
+            let total = 0;
+            for i in n to 1 step -1 {
+                if (i == 2) {
+                    continue;
+                }
+
+                total += i;
+            }
+
+            return total;
+        }
+
+        for i in 0 to 1 step 0.5 {
+            print(i);
+        }
+
+        // A step which is only zero at runtime ends the loop at once.
+        let zero = 0;
+        for i in 0 to 10 step zero {
+            print(i);
+        }
+
+        for i in 1 to 100 {
+            if (i > 3) {
+                break;
+            }
+
+            print(i);
+        }
+
+        print(sum(4));
+
+        // A step past the largest integer finishes the loop instead of overflowing.
+        for i in 9223372036854775806 to 9223372036854775807 {
+            print(i);
+        }
+        for i in -9223372036854775806 to -9223372036854775807 step -3 {
+            print(i);
+        }
+    ";
+
+    assert_eq!(run(input).unwrap(), "0.0\n0.5\n1.0\n1\n2\n3\n8\n9223372036854775806\n9223372036854775807\n-9223372036854775806\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_arrays() {
+    let input = r"
+        fn reverse(values: [int]) -> [int] {
+            let last = len(values) - 1;
+            for i in 0 to last / 2 {
+                let swapped = values[i];
+                values[i] = values[last - i];
+                values[last - i] = swapped;
+            }
+
+            return values;
+        }
+
+        let values = [1, 2, 3, 4, 5];
+        reverse(values);
+        print(values);
+
+        let averages = [0.5, 1];
+        averages[0] = 2;
+        print(averages[0] / 4);
+
+        fn half(values: [float]) -> float {
+            return values[0] / 2;
+        }
+
+        let weights: [float] = [1, 2];
+        weights = [3];
+        let table: [[float]] = [[1], [2, 3]];
+        print(half(weights) + half([1]));
+        print(table);
+    ";
+
+    assert_eq!(
+        run(input).unwrap(),
+        "[5, 4, 3, 2, 1]\n0.5\n2.0\n[[1.0], [2.0, 3.0]]\n"
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_structs() {
+    let input = r"
+        struct Point { x: float, y: float }
+        struct Line { start: Point, end: Point }
+
+        fn length(line: Line) -> float {
+            return line.end.x - line.start.x;
+        }
+
+        let line = Line { start: Point { x: 1, y: 0 }, end: Point { x: 2, y: 0 } };
+        line.end.x = 4;
+        print(length(line) / 2);
+        print(line.start);
+    ";
+
+    assert_eq!(run(input).unwrap(), "1.5\nPoint { x: 1.0, y: 0.0 }\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_enums() {
+    let input = r#"
+        enum Shape { Circle(float), Rect(float, float), Empty }
+
+        fn area(shape: Shape) -> float {
+            return match shape {
+                Shape::Circle(r) => 3 * r * r,
+                Shape::Rect(w, h) => w * h,
+                Shape::Empty => 0.0,
+            };
+        }
+
+        let shapes = [Shape::Circle(1), Shape::Rect(2, 3.5), Shape::Empty];
+        for i in 0 to len(shapes) - 1 {
+            print(area(shapes[i]));
+        }
+        print(shapes[1]);
+        match true {
+            true => print("yes"),
+            false => print("no"),
+        }
+    "#;
+
+    assert_eq!(
+        run(input).unwrap(),
+        "3.0\n7.0\n0.0\nShape::Rect(2.0, 3.5)\nyes\n"
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_method_calls() {
+    let input = r"
+        struct Point { x: float, y: float }
+
+        fn scale(p: Point, by: float) -> Point {
+            return Point { x: p.x * by, y: p.y * by };
+        }
+
+        fn sum(p: Point) -> float {
+            return p.x + p.y;
+        }
+
+        let p = Point { x: 1, y: 2 };
+        print(p.scale(3).scale(0.5).sum());
+    ";
+
+    assert_eq!(run(input).unwrap(), "4.5\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_typed_declarations() {
+    let input = r"
+        fn halves(n: int) -> float {
+            let total: float = n;
+            return total / 2;
+        }
+
+        let half: float = 1;
+        print(half / 2);
+        print(halves(3));
+    ";
+
+    assert_eq!(run(input).unwrap(), "0.5\n1.5\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_typed_declaration_without_value() {
+    let input = r"
+        let f: float;
+        f = 3;
+        print(f / 2);
+
+        fn halve(n: int) -> float {
+            let half: float;
+            half = n;
+            return half / 2;
+        }
+        print(halve(5));
+    ";
+
+    assert_eq!(run(input).unwrap(), "1.5\n2.5\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_labeled_loops() {
+    let input = r"
+        fn find(target: int) -> int {
+            let found = -1;
+            'rows: for i in 0 to 9 {
+                'columns: for j in 0 to 9 {
+                    if (j > i) {
+                        continue 'rows;
+                    }
+                    if (i * j == target) {
+                        found = i * 10 + j;
+                        break 'rows;
+                    }
+                }
+            }
+
+            return found;
+        }
+
+        print(find(12));
+        print(find(11));
+    ";
+
+    assert_eq!(run(input).unwrap(), "43\n-1\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_forward_references() {
+    let input = r"
+        fn collatz(n: int) -> int {
+            if (n == 1) {
+                return 0;
+            }
+
+            return 1 + step(n);
+
+            fn step(n: int) -> int {
+                if (n % 2 == 0) {
+                    return collatz(n / 2);
+                }
+
+                return collatz(3 * n + 1);
+            }
+        }
+
+        print(collatz(start()));
+
+        fn start() -> int {
+            return 6;
+        }
+    ";
+
+    assert_eq!(run(input).unwrap(), "8\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_compound_assignment() {
+    let input = r#"
+        fn sum(n: int) -> int {
+            let total = 0;
+            let i = 1;
+            while (i <= n) {
+                total += i;
+                i += 1;
+            }
+
+            return total;
+        }
+
+        let greeting = "Hello";
+        greeting += ", World!";
+        print(greeting);
+        print(sum(100));
+    "#;
+
+    assert_eq!(run(input).unwrap(), "Hello, World!\n5050\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_increment_and_decrement() {
+    let input = r"
+        fn count(n: int) -> int {
+            let total = 0;
+            for (let i = 0; i < n; i++) {
+                total += i;
+            }
+
+            return total;
+        }
+
+        let i = 5;
+        print(i++);
+        print(--i + i--);
+        print(i);
+        print(count(10));
+    ";
+
+    assert_eq!(run(input).unwrap(), "5\n10\n4\n45\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_bitwise_operators() {
+    let input = r"
+        fn popcount(n: int) -> int {
+            let count = 0;
+            while (n != 0) {
+                count += n & 1;
+                n >>= 1;
+            }
+
+            return count;
+        }
+
+        let mask = 0;
+        for (let bit = 0; bit < 8; bit += 2) {
+            mask |= 1 << bit;
+        }
+
+        print(mask);
+        print(mask ^ 255);
+        print(popcount(mask));
+    ";
+
+    assert_eq!(run(input).unwrap(), "85\n170\n4\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_power() {
+    let input = r"
+        fn square(x: float) -> float {
+            return x ** 2;
+        }
+
+        print(-2 ** 2 == -4);
+        print(square(1.5));
+        print(2 ** 3 ** 2);
+    ";
+
+    assert_eq!(run(input).unwrap(), "true\n2.25\n512\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_integers_and_floats() {
+    let input = r"
+        fn average(a: float, b: float) -> float {
+            return (a + b) / 2;
+        }
+
+        let total = 0.5;
+        total = 3;
+        print(total / 2);
+        print(average(1, 2));
+        print(7 / 2);
+        print(9007199254740993);
+
+        let zero = 0.0;
+        let negative = -0.0;
+        print(negative);
+    ";
+
+    assert_eq!(run(input).unwrap(), "1.5\n1.5\n3\n9007199254740993\n-0.0\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_logical_operators() {
+    let input = r#"
+        fn loud() -> bool {
+            print("evaluated");
+
+            return true;
+        }
+
+        print(false && loud());
+        print(true || loud());
+        print(!false && loud());
+    "#;
+
+    assert_eq!(run(input).unwrap(), "false\ntrue\nevaluated\ntrue\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_block_locals() {
+    let input = r"
+        let x = 1;
+        {
+            let x = 2;
+            {
+                let y = x + 1;
+                print(y);
+            }
+            print(x);
+        }
+        print(x);
+    ";
+
+    assert_eq!(run(input).unwrap(), "3\n2\n1\n");
+}
+
+#[test]
+fn test_runtime_errors() {
+    let division = r"
+        let zero = 0;
+        print(1 / zero);
+    ";
+    let operands = r"
+        let min = -9223372036854775807 - 1;
+        print(-min);
+    ";
+    let integer_overflow = r"
+        let big = 9223372036854775807;
+        print(big + 1);
+    ";
+    let bounds = r"
+        let values = [1, 2, 3];
+        values[3] = 4;
+    ";
+    let overflow = r"
+        fn forever(n: int) -> int {
+            return forever(n + 1);
+        }
+
+        forever(0);
+    ";
+    let uninitialized = r"
+        let x: int;
+        while (false) {
+            x = 1;
+        }
+        print(x);
+    ";
+    let hoisted = r"
+        print(f());
+        let x = [5];
+
+        fn f() -> int {
+            return x[0];
+        }
+    ";
+    let uninitialized_local = r"
+        fn f() {
+            let x: int;
+            while (false) {
+                x = 1;
+            }
+            print(x);
+        }
+
+        f();
+    ";
+
+    assert!(matches!(run(division), Err(Error::DivisionByZero { .. })));
+    assert!(matches!(run(operands), Err(Error::InvalidOperands { .. })));
+    assert!(matches!(
+        run(integer_overflow),
+        Err(Error::InvalidOperands { .. })
+    ));
+    assert!(matches!(
+        run(bounds),
+        Err(Error::IndexOutOfBounds {
+            index: 3,
+            length: 3,
+            ..
+        })
+    ));
+    assert!(matches!(run(overflow), Err(Error::StackOverflow { .. })));
+    assert!(matches!(
+        run(uninitialized),
+        Err(Error::UninitializedVariable { line: 6, .. })
+    ));
+    assert!(matches!(
+        run(hoisted),
+        Err(Error::UninitializedVariable { line: 6, .. })
+    ));
+    assert!(matches!(
+        run(uninitialized_local),
+        Err(Error::UninitializedVariable { line: 7, .. })
+    ));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_deep_recursion() {
+    let input = r"
+        fn down(n: int) -> int {
+            if (n == 0) {
+                return 0;
+            }
+
+            return 1 + down(n - 1);
+        }
+
+        print(down(250));
+    ";
+
+    assert_eq!(run(input).unwrap(), "250\n");
+}
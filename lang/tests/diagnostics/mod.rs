@@ -0,0 +1,135 @@
+use lang::diagnostics::{Diagnostic, Label, Severity};
+use lang::lexer::span::{SourceMap, Span};
+use lang::lexer::Lexer;
+use lang::parser::Parser;
+use lang::semantics::SemanticAnalyzer;
+
+/// Renders the diagnostic for the first error found in a program.
+#[allow(clippy::unwrap_used)]
+fn render(input: &str) -> String {
+    let source = SourceMap::new(input);
+
+    let tokens = match Lexer::new(input).tokenize() {
+        Ok(tokens) => tokens,
+        Err(why) => return Diagnostic::from(&why).render(&source, "main.lt"),
+    };
+
+    let (ast, errors) = Parser::new(&tokens).parse();
+    if let Some(why) = errors.first() {
+        return Diagnostic::from(why).render(&source, "main.lt");
+    }
+
+    let errors = SemanticAnalyzer::new(&ast).analyze().unwrap_err();
+
+    Diagnostic::from(&errors[0]).render(&source, "main.lt")
+}
+
+#[test]
+fn test_lexer_error() {
+    let input = "let greeting = \"Hello;\n";
+
+    let expected = r#"error[E0003]: Unterminated string.
+ --> main.lt:1:16
+  |
+1 | let greeting = "Hello;
+  |                ^^^^^^^ the string starts here
+  |
+  = help: add a '"' at the end of the string
+"#;
+
+    assert_eq!(render(input), expected);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_file_errors() {
+    let missing = lang::lexer::read_file("missing.lt").unwrap_err();
+    let extension = lang::lexer::read_file("Cargo.toml").unwrap_err();
+
+    let missing = Diagnostic::from(&missing);
+    let extension = Diagnostic::from(&extension);
+
+    assert_eq!(missing.code, "E0009");
+    assert_eq!(missing.message, "File 'missing.lt' doesn't exist.");
+    assert_eq!(extension.code, "E0008");
+    assert_eq!(extension.message, "Invalid file extension.");
+}
+
+#[test]
+fn test_parser_error() {
+    let input = "let x = 1;\nx = (1 + 2;\n";
+
+    let expected = r"error[E0101]: Expected ')' after expression.
+ --> main.lt:2:11
+  |
+2 | x = (1 + 2;
+  |           ^ unexpected token
+";
+
+    assert_eq!(render(input), expected);
+}
+
+#[test]
+fn test_invalid_assignment_target() {
+    let input = "let xs = [1];\nxs[0] += 1;\n";
+
+    let expected = r"error[E0103]: Invalid assignment target.
+ --> main.lt:2:7
+  |
+2 | xs[0] += 1;
+  |       ^^ cannot assign to the left-hand side
+  |
+  = note: compound assignments, '++' and '--' only apply to variables
+  = help: only variables, array elements and fields can be assigned to
+";
+
+    assert_eq!(render(input), expected);
+}
+
+#[test]
+fn test_semantic_error() {
+    let input = r#"fn greet(name: str) -> str {
+    let greeting = "Hello, " + name;
+
+    return 5;
+}
+"#;
+
+    let expected = r"error[E0207]: Mismatched types.
+ --> main.lt:4:12
+  |
+1 | fn greet(name: str) -> str {
+  |                        --- expected because of this
+...
+4 |     return 5;
+  |            ^ expected 'str', found 'int'
+";
+
+    assert_eq!(render(input), expected);
+}
+
+#[test]
+fn test_notes_and_labels_on_one_line() {
+    let input = "let a = b + c;";
+    let source = SourceMap::new(input);
+
+    let diagnostic = Diagnostic::warning("W0001", "Suspicious addition.")
+        .with_label(Label::primary(Span::new(8, 9), "this"))
+        .with_label(Label::secondary(Span::new(12, 13), "and this"))
+        .with_note("addition is commutative")
+        .with_help("swap the operands");
+
+    let expected = r"warning[W0001]: Suspicious addition.
+ --> main.lt:1:9
+  |
+1 | let a = b + c;
+  |         ^ this
+  |             - and this
+  |
+  = note: addition is commutative
+  = help: swap the operands
+";
+
+    assert_eq!(diagnostic.severity, Severity::Warning);
+    assert_eq!(diagnostic.render(&source, "main.lt"), expected);
+}
@@ -0,0 +1,153 @@
+use super::run;
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_if_else() {
+    let input = r#"
+        let x = 3;
+        if (x > 2) {
+            print("big");
+        } else {
+            print("small");
+        }
+
+        if (x > 5) print("huge"); else print("not huge");
+    "#;
+
+    assert_eq!(run(input).unwrap(), "big\nnot huge\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_while_break_continue() {
+    let input = r"
+        let i = 0;
+        while (true) {
+            i = i + 1;
+            if (i % 2 == 0) {
+                continue;
+            }
+            if (i > 5) {
+                break;
+            }
+
+            print(i);
+        }
+    ";
+
+    assert_eq!(run(input).unwrap(), "1\n3\n5\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_for() {
+    let input = r"
+        let sum = 0;
+        for (let i = 0; i < 5; i = i + 1) {
+            if (i == 2) {
+                continue;
+            }
+
+            sum = sum + i;
+        }
+
+        print(sum);
+    ";
+
+    assert_eq!(run(input).unwrap(), "8\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_block_scope() {
+    let input = r#"
+        let x = "outer";
+        {
+            let x = "inner";
+            print(x);
+        }
+
+        print(x);
+    "#;
+
+    assert_eq!(run(input).unwrap(), "inner\nouter\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_for_range() {
+    let input = r"
+        for i in 1 to 3 {
+            print(i);
+        }
+
+        for i in 3 to 1 step -1 {
+            print(i);
+        }
+
+        for i in 0 to 1 step 0.5 {
+            print(i);
+        }
+
+        // A step which is only zero at runtime ends the loop at once.
+        let zero = 0;
+        for i in 0 to 10 step zero {
+            print(i);
+        }
+
+        let sum = 0;
+        for i in 0 to 10 {
+            if (i % 2 == 0) {
+                continue;
+            }
+            if (i > 7) {
+                break;
+            }
+
+            sum += i;
+        }
+
+        print(sum);
+
+        // A step past the largest integer finishes the loop instead of overflowing.
+        for i in 9223372036854775806 to 9223372036854775807 {
+            print(i);
+        }
+        for i in -9223372036854775806 to -9223372036854775807 step -3 {
+            print(i);
+        }
+    ";
+
+    assert_eq!(run(input).unwrap(), "1\n2\n3\n3\n2\n1\n0.0\n0.5\n1.0\n16\n9223372036854775806\n9223372036854775807\n-9223372036854775806\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_labeled_loops() {
+    let input = r"
+        'rows: for i in 1 to 3 {
+            for (let j = 1; j <= 3; j++) {
+                if (j == 2) {
+                    continue 'rows;
+                }
+                if (i == 3) {
+                    break 'rows;
+                }
+                print(i * 10 + j);
+            }
+        }
+
+        let n = 0;
+        'search: while (true) {
+            while (true) {
+                n += 1;
+                if (n == 5) {
+                    break 'search;
+                }
+            }
+        }
+        print(n);
+    ";
+
+    assert_eq!(run(input).unwrap(), "11\n21\n5\n");
+}
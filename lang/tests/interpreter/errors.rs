@@ -0,0 +1,99 @@
+use super::run;
+use lang::interpreter::errors::Error;
+use lang::interpreter::Interpreter;
+use lang::lexer::Lexer;
+use lang::parser::Parser;
+
+#[test]
+fn test_division_by_zero() {
+    let input = r"
+        print(1 / 0);
+    ";
+
+    assert!(matches!(run(input), Err(Error::DivisionByZero { .. })));
+}
+
+#[test]
+fn test_invalid_operands() {
+    let input = r"
+        let big = 9223372036854775807;
+        print(big + 1);
+    ";
+
+    assert!(matches!(run(input), Err(Error::InvalidOperands { .. })));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_arity_mismatch() {
+    // The analyzer rejects this, so it's run without being checked.
+    let input = r"
+        fn add(a: int, b: int) -> int {
+            return a + b;
+        }
+
+        let f = add;
+        f(1);
+    ";
+    let tokens = Lexer::new(input).tokenize().unwrap();
+    let (ast, errors) = Parser::new(&tokens).parse();
+    assert_eq!(errors, []);
+
+    assert!(matches!(
+        Interpreter::new(&ast, Vec::new()).interpret(),
+        Err(Error::ArityMismatch {
+            expected: 2,
+            found: 1,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_global_read_before_declaration() {
+    // The function is hoisted, so it can be called before the global it reads is declared.
+    let input = r"
+        print(f());
+        let x = 5;
+
+        fn f() -> int {
+            return x;
+        }
+    ";
+
+    assert!(matches!(
+        run(input),
+        Err(Error::UndefinedVariable { line: 6, .. })
+    ));
+}
+
+#[test]
+fn test_stack_overflow() {
+    let input = r"
+        fn forever(n: int) -> int {
+            return forever(n + 1);
+        }
+
+        forever(0);
+    ";
+
+    assert!(matches!(run(input), Err(Error::StackOverflow { .. })));
+}
+
+#[test]
+fn test_index_out_of_bounds() {
+    let input = r"
+        let values = [1, 2, 3];
+        print(values[-1]);
+    ";
+
+    assert!(matches!(
+        run(input),
+        Err(Error::IndexOutOfBounds {
+            index: -1,
+            length: 3,
+            line: 3,
+            column: 21,
+        })
+    ));
+}
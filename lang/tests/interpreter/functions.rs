@@ -0,0 +1,143 @@
+use super::run;
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_hello_world() {
+    let input = r#"
+        fn greet(name: str) -> str {
+            return "Hello, " + name + "!";
+        }
+
+        print(greet("World"));
+    "#;
+
+    assert_eq!(run(input).unwrap(), "Hello, World!\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_recursion() {
+    let input = r"
+        fn factorial(n: int) -> int {
+            if (n == 0) {
+                return 1;
+            }
+
+            return n * factorial(n - 1);
+        }
+
+        print(factorial(10));
+    ";
+
+    assert_eq!(run(input).unwrap(), "3628800\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_return_from_loop() {
+    let input = r"
+        fn first_above(limit: int) -> int {
+            let i = 0;
+            while (true) {
+                if (i * i > limit) {
+                    return i;
+                }
+
+                i = i + 1;
+            }
+        }
+
+        print(first_above(50));
+    ";
+
+    assert_eq!(run(input).unwrap(), "8\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_call_frames() {
+    let input = r"
+        let x = 1;
+
+        fn shadow(x: int) {
+            x = x + 10;
+            print(x);
+        }
+
+        shadow(5);
+        print(x);
+    ";
+
+    assert_eq!(run(input).unwrap(), "15\n1\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_method_calls() {
+    let input = r#"
+        fn shout(s: str) -> str {
+            return s + "!";
+        }
+
+        fn repeat(s: str, separator: str) -> str {
+            return s + separator + s;
+        }
+
+        let name = "hey";
+        print(name.shout().repeat(" "));
+        print(name.len());
+    "#;
+
+    assert_eq!(run(input).unwrap(), "hey! hey!\n3\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_forward_references() {
+    let input = r"
+        print(is_even(7));
+        print(describe(Shape::Square(2)));
+
+        fn is_even(n: int) -> bool {
+            if (n == 0) {
+                return true;
+            }
+
+            return is_odd(n - 1);
+        }
+
+        fn is_odd(n: int) -> bool {
+            if (n == 0) {
+                return false;
+            }
+
+            return is_even(n - 1);
+        }
+
+        fn describe(shape: Shape) -> int {
+            return match shape { Shape::Square(side) => side * side };
+        }
+
+        enum Shape { Square(int) }
+    ";
+
+    assert_eq!(run(input).unwrap(), "false\n4\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_deep_recursion() {
+    let input = r"
+        fn down(n: int) -> int {
+            if (n == 0) {
+                return 0;
+            }
+
+            return 1 + down(n - 1);
+        }
+
+        print(down(250));
+    ";
+
+    assert_eq!(run(input).unwrap(), "250\n");
+}
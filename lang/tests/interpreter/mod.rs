@@ -0,0 +1,28 @@
+mod control_flow;
+mod errors;
+mod functions;
+mod operations;
+
+use lang::interpreter::{errors::Error, Interpreter};
+use lang::lexer::Lexer;
+use lang::parser::Parser;
+use lang::semantics::SemanticAnalyzer;
+
+/// Runs a program and returns everything it printed.
+#[allow(clippy::unwrap_used)]
+fn run(input: &str) -> Result<String, Error> {
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (ast, errors) = parser.parse();
+    assert_eq!(errors, []);
+
+    let mut semantics = SemanticAnalyzer::new(&ast);
+    semantics.analyze().unwrap();
+
+    let mut output = Vec::new();
+    Interpreter::new(&ast, &mut output).interpret()?;
+
+    Ok(String::from_utf8(output).unwrap())
+}
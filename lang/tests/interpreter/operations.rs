@@ -0,0 +1,280 @@
+use super::run;
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_arithmetic() {
+    let input = r"
+        print(1 + 2 * 3 - 4.0 / 8);
+        print(7 % 4);
+        print(-(2 + 3));
+        print(7 / 2);
+    ";
+
+    assert_eq!(run(input).unwrap(), "6.5\n3\n-5\n3\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_string_concatenation() {
+    let input = r#"
+        let name = "World";
+        print("Hello, " + name + "!");
+    "#;
+
+    assert_eq!(run(input).unwrap(), "Hello, World!\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_comparisons() {
+    let input = r#"
+        print(1 < 2);
+        print(2 <= 1);
+        print("a" == "a");
+        print(1 != 1);
+        print(!true);
+    "#;
+
+    assert_eq!(run(input).unwrap(), "true\nfalse\ntrue\nfalse\nfalse\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_short_circuit() {
+    let input = r#"
+        fn loud() -> bool {
+            print("evaluated");
+
+            return true;
+        }
+
+        print(false && loud());
+        print(true || loud());
+        print(true && loud());
+    "#;
+
+    assert_eq!(run(input).unwrap(), "false\ntrue\nevaluated\ntrue\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_assignment() {
+    let input = r"
+        let x = 1;
+        let y = x = 2;
+        print(x + y);
+    ";
+
+    assert_eq!(run(input).unwrap(), "4\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_compound_assignment() {
+    let input = r#"
+        let x = 10;
+        x += 5;
+        x -= 3;
+        x *= 2;
+        x /= 4;
+        print(x %= 4);
+
+        let greeting = "Hello";
+        greeting += ", World!";
+        print(greeting);
+    "#;
+
+    assert_eq!(run(input).unwrap(), "2\nHello, World!\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_increment_and_decrement() {
+    let input = r"
+        let i = 5;
+        print(i++);
+        print(i);
+        print(--i);
+        print(i--);
+        print(++i);
+    ";
+
+    assert_eq!(run(input).unwrap(), "5\n6\n5\n5\n5\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_bitwise_operators() {
+    let input = r"
+        print(6 & 3);
+        print(6 | 3);
+        print(6 ^ 3);
+        print(1 << 4);
+        print(-16 >> 2);
+        print((1 | 2 << 3) == 17);
+        let flags = 12;
+        flags &= 4;
+        print(flags);
+    ";
+
+    assert_eq!(run(input).unwrap(), "2\n7\n5\n16\n-4\ntrue\n4\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_power() {
+    let input = r"
+        print(2 ** 10);
+        print(-2 ** 2);
+        print((-2) ** 2);
+        print(2 ** 3 ** 2);
+        print(2.0 ** -1);
+        let x = 3;
+        print(-x ** 2 * 2);
+    ";
+
+    assert_eq!(run(input).unwrap(), "1024\n-4\n4\n512\n0.5\n-18\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_integers_and_floats() {
+    let input = r"
+        fn half(x: float) -> float {
+            return x / 2;
+        }
+
+        fn whole() -> float {
+            return 3;
+        }
+
+        print(9007199254740993);
+        print(7 / 2);
+        print(7 / 2.0);
+        print(half(3));
+        print(whole() / 2);
+        print(1 == 1.0);
+    ";
+
+    assert_eq!(
+        run(input).unwrap(),
+        "9007199254740993\n3\n3.5\n1.5\n1.5\ntrue\n"
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_arrays() {
+    let input = r#"
+        fn fill(values: [float], value: int) {
+            for i in 0 to len(values) - 1 {
+                values[i] = value;
+            }
+        }
+
+        let values = [1, 2.5];
+        fill(values, 3);
+        print(values[0] / 2);
+
+        let grid = [[1, 2], [3]];
+        grid[1][0] = grid[0][1] * 10;
+        print(grid);
+        print(len(grid) + len("abc"));
+        print([1, 2] == [1, 2]);
+
+        fn half(values: [float]) -> float {
+            return values[0] / 2;
+        }
+
+        let weights: [float] = [1, 2];
+        weights = [3];
+        let table: [[float]] = [[1], [2, 3]];
+        print(half(weights) + half([1]));
+        print(table);
+    "#;
+
+    assert_eq!(
+        run(input).unwrap(),
+        "1.5\n[[1, 2], [20]]\n5\ntrue\n2.0\n[[1.0], [2.0, 3.0]]\n"
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_structs() {
+    let input = r"
+        struct Point { x: float, y: float }
+
+        fn shift(point: Point, by: int) {
+            point.x = point.x + by;
+        }
+
+        let p = Point { y: 2, x: 1 };
+        shift(p, 3);
+        print(p);
+        print(p.x / 8);
+        print(p == Point { x: 4.0, y: 2.0 });
+    ";
+
+    assert_eq!(run(input).unwrap(), "Point { y: 2.0, x: 4.0 }\n0.5\ntrue\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_enums() {
+    let input = r#"
+        enum Shape { Circle(float), Rect(float, float), Empty }
+
+        fn describe(shape: Shape) -> str {
+            return match shape {
+                Shape::Circle(_) => "circle",
+                Shape::Rect(w, h) => "rect",
+                _ => "nothing",
+            };
+        }
+
+        let rect = Shape::Rect(2, 3.5);
+        print(rect);
+        print(describe(rect) + " " + describe(Shape::Empty));
+        print(Shape::Circle(1) == Shape::Circle(1.0));
+        print(match 2 { 1 => "one", 2 => "two", _ => "many" });
+    "#;
+
+    assert_eq!(
+        run(input).unwrap(),
+        "Shape::Rect(2.0, 3.5)\nrect nothing\ntrue\ntwo\n"
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_typed_declarations() {
+    let input = r"
+        let half: float = 1;
+        half = half / 2;
+        let count: int = 3;
+        print(half);
+        print(count / 2);
+    ";
+
+    assert_eq!(run(input).unwrap(), "0.5\n1\n");
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_typed_declaration_without_value() {
+    let input = r"
+        let f: float;
+        f = 3;
+        print(f / 2);
+
+        fn halve(n: int) -> float {
+            let half: float;
+            half = n;
+            return half / 2;
+        }
+        print(halve(5));
+    ";
+
+    assert_eq!(run(input).unwrap(), "1.5\n2.5\n");
+}
@@ -1,3 +1,4 @@
+use lang::lexer::errors::Error;
 use lang::lexer::{tokens::TokenKind, Lexer};
 
 #[test]
@@ -30,3 +31,42 @@ fn test_multi_line() {
 
     assert_eq!(actual_token_kinds, expected_token_kinds);
 }
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_adjacent_tokens() {
+    let input = "/**/a/* * / */=/*x*/1;";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let actual_token_kinds: Vec<TokenKind> = tokens
+        .iter()
+        .map(|token| token.token_kind.clone())
+        .collect();
+    let expected_token_kinds = [
+        TokenKind::Identifier("a".into()),
+        TokenKind::Assign,
+        TokenKind::Integer(1),
+        TokenKind::Semicolon,
+        TokenKind::EndOfFile,
+    ];
+
+    assert_eq!(actual_token_kinds, expected_token_kinds);
+}
+
+#[test]
+fn test_unterminated() {
+    let input = "let a = 10; /* This comment never ends.";
+
+    let mut lexer = Lexer::new(input);
+
+    assert!(matches!(
+        lexer.tokenize(),
+        Err(Error::UnterminatedComment {
+            line: 1,
+            column: 13,
+            ..
+        })
+    ));
+}
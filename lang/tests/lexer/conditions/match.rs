@@ -0,0 +1,43 @@
+use lang::lexer::tokens::TokenKind;
+use lang::lexer::Lexer;
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_match() {
+    let input = r"
+        match shape {
+            Shape::Circle(r) => r,
+            _ => -1,
+        }
+        ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let actual_token_kinds: Vec<TokenKind> = tokens
+        .iter()
+        .map(|token| token.token_kind.clone())
+        .collect();
+    let expected_token_kinds = [
+        TokenKind::Match,
+        TokenKind::Identifier("shape".into()),
+        TokenKind::LeftCurlyBrace,
+        TokenKind::Identifier("Shape".into()),
+        TokenKind::DoubleColon,
+        TokenKind::Identifier("Circle".into()),
+        TokenKind::LeftParenthesis,
+        TokenKind::Identifier("r".into()),
+        TokenKind::RightParenthesis,
+        TokenKind::FatArrow,
+        TokenKind::Identifier("r".into()),
+        TokenKind::Comma,
+        TokenKind::Identifier("_".into()),
+        TokenKind::FatArrow,
+        TokenKind::Integer(-1),
+        TokenKind::Comma,
+        TokenKind::RightCurlyBrace,
+        TokenKind::EndOfFile,
+    ];
+
+    assert_eq!(actual_token_kinds, expected_token_kinds);
+}
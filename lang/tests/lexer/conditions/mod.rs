@@ -1,3 +1,4 @@
 mod r#else;
 mod r#if;
+mod r#match;
 mod multiple;
@@ -0,0 +1,65 @@
+use lang::lexer::errors::Error;
+use lang::lexer::span::Span;
+use lang::lexer::{tokens::TokenKind, Lexer};
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_lazy_tokens() {
+    // Only the tokens which are asked for are lexed, so the error is never reached.
+    let input = "let a = 1; /* comment */ let b = \"never ends";
+
+    let token_kinds = Lexer::new(input)
+        .take(6)
+        .map(|token| token.unwrap().token_kind)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        token_kinds,
+        [
+            TokenKind::Variable,
+            TokenKind::Identifier("a".into()),
+            TokenKind::Assign,
+            TokenKind::Integer(1),
+            TokenKind::Semicolon,
+            TokenKind::Variable,
+        ]
+    );
+}
+
+#[test]
+fn test_ends_after_error() {
+    let input = "a @ b";
+
+    let mut lexer = Lexer::new(input);
+
+    assert!(matches!(
+        lexer.next(),
+        Some(Ok(token)) if token.token_kind == TokenKind::Identifier("a".into())
+    ));
+    assert_eq!(
+        lexer.next(),
+        Some(Err(Error::UnexpectedCharacter {
+            char: Some('@'),
+            line: 1,
+            column: 3,
+            span: Span::new(2, 3),
+        }))
+    );
+    assert!(lexer.next().is_none());
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_ends_after_end_of_file() {
+    let mut lexer = Lexer::new("a");
+
+    assert_eq!(
+        lexer.next().unwrap().unwrap().token_kind,
+        TokenKind::Identifier("a".into())
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().token_kind,
+        TokenKind::EndOfFile
+    );
+    assert!(lexer.next().is_none());
+}
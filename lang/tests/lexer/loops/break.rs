@@ -1,3 +1,4 @@
+use lang::lexer::errors::Error;
 use lang::lexer::tokens::TokenKind;
 use lang::lexer::Lexer;
 
@@ -19,3 +20,54 @@ fn test_break() {
 
     assert_eq!(actual_token_kinds, expected_token_kinds);
 }
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_break_label() {
+    let input = r"
+        'outer: while (true) { break 'outer; }
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let actual_token_kinds: Vec<TokenKind> = tokens
+        .iter()
+        .map(|token| token.token_kind.clone())
+        .collect();
+    let expected_token_kinds = [
+        TokenKind::Label("outer".into()),
+        TokenKind::Colon,
+        TokenKind::While,
+        TokenKind::LeftParenthesis,
+        TokenKind::True,
+        TokenKind::RightParenthesis,
+        TokenKind::LeftCurlyBrace,
+        TokenKind::Break,
+        TokenKind::Label("outer".into()),
+        TokenKind::Semicolon,
+        TokenKind::RightCurlyBrace,
+        TokenKind::EndOfFile,
+    ];
+
+    assert_eq!(actual_token_kinds, expected_token_kinds);
+}
+
+#[test]
+fn test_apostrophe_without_label() {
+    let input = r"
+        break ';
+    ";
+
+    let mut lexer = Lexer::new(input);
+
+    assert!(matches!(
+        lexer.tokenize(),
+        Err(Error::UnexpectedCharacter {
+            char: Some('\''),
+            line: 2,
+            column: 15,
+            ..
+        })
+    ));
+}
@@ -1,5 +1,7 @@
 mod comments;
 mod conditions;
 mod functions;
+mod iterator;
 mod loops;
+mod spans;
 mod variables;
@@ -0,0 +1,64 @@
+use lang::lexer::span::{SourceMap, Span};
+use lang::lexer::{tokens::Token, tokens::TokenKind, Lexer};
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_token_positions() {
+    let input = "let name = \"π\";\n  name >>= 2;";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let expected_tokens = [
+        Token::new(TokenKind::Variable, 1, 1, Span::new(0, 3)),
+        Token::new(TokenKind::Identifier("name".into()), 1, 5, Span::new(4, 8)),
+        Token::new(TokenKind::Assign, 1, 10, Span::new(9, 10)),
+        Token::new(TokenKind::String("π".into()), 1, 12, Span::new(11, 15)),
+        Token::new(TokenKind::Semicolon, 1, 15, Span::new(15, 16)),
+        Token::new(
+            TokenKind::Identifier("name".into()),
+            2,
+            3,
+            Span::new(19, 23),
+        ),
+        Token::new(TokenKind::BitwiseRightShiftAssign, 2, 8, Span::new(24, 27)),
+        Token::new(TokenKind::Integer(2), 2, 12, Span::new(28, 29)),
+        Token::new(TokenKind::Semicolon, 2, 13, Span::new(29, 30)),
+        Token::new(TokenKind::EndOfFile, 2, 14, Span::new(30, 30)),
+    ];
+
+    assert_eq!(tokens, expected_tokens);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_source_map() {
+    let input = "let a = \"π\";\r\nprint(a);\n";
+
+    let tokens = Lexer::new(input).tokenize().unwrap();
+    let source_map = SourceMap::new(input);
+
+    for token in &tokens {
+        assert_eq!(
+            source_map.location(token.span.start),
+            (token.line, token.column)
+        );
+    }
+
+    assert_eq!(source_map.line(1), Some("let a = \"π\";"));
+    assert_eq!(source_map.line(2), Some("print(a);"));
+    assert_eq!(source_map.line(3), Some(""));
+    assert_eq!(source_map.line(4), None);
+    assert_eq!(source_map.text(tokens[3].span), Some("\"π\""));
+}
+
+#[test]
+fn test_span_to() {
+    let left = Span::new(4, 8);
+    let right = Span::new(12, 20);
+
+    assert_eq!(left.to(right), Span::new(4, 20));
+    assert_eq!(right.to(left), Span::new(4, 20));
+    assert_eq!(left.len(), 4);
+    assert!(Span::new(3, 3).is_empty());
+}
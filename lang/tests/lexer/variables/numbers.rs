@@ -1,3 +1,5 @@
+use lang::lexer::errors::Error;
+use lang::lexer::span::Span;
 use lang::lexer::tokens::TokenKind;
 use lang::lexer::Lexer;
 
@@ -71,3 +73,147 @@ fn test_ints() {
 
     assert_eq!(actual_token_kinds, expected_token_kinds);
 }
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_floats() {
+    let input = "1.5 2e3 2.5E-3 1e+2 1.max 3e";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let actual_token_kinds: Vec<TokenKind> = tokens
+        .iter()
+        .map(|token| token.token_kind.clone())
+        .collect();
+    let expected_token_kinds = [
+        TokenKind::Float(1.5),
+        TokenKind::Float(2e3),
+        TokenKind::Float(2.5e-3),
+        TokenKind::Float(1e2),
+        TokenKind::Integer(1),
+        TokenKind::Dot,
+        TokenKind::Identifier("max".into()),
+        TokenKind::Integer(3),
+        TokenKind::Identifier("e".into()),
+        TokenKind::EndOfFile,
+    ];
+
+    assert_eq!(actual_token_kinds, expected_token_kinds);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_signs() {
+    let input = "a-1 + (2)+3 - -4 * +5";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let actual_token_kinds: Vec<TokenKind> = tokens
+        .iter()
+        .map(|token| token.token_kind.clone())
+        .collect();
+    let expected_token_kinds = [
+        TokenKind::Identifier("a".into()),
+        TokenKind::Minus,
+        TokenKind::Integer(1),
+        TokenKind::Plus,
+        TokenKind::LeftParenthesis,
+        TokenKind::Integer(2),
+        TokenKind::RightParenthesis,
+        TokenKind::Plus,
+        TokenKind::Integer(3),
+        TokenKind::Minus,
+        TokenKind::Integer(-4),
+        TokenKind::Star,
+        TokenKind::Integer(5),
+        TokenKind::EndOfFile,
+    ];
+
+    assert_eq!(actual_token_kinds, expected_token_kinds);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_signs_after_update() {
+    let input = "i++-1; i---1";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let actual_token_kinds: Vec<TokenKind> = tokens
+        .iter()
+        .map(|token| token.token_kind.clone())
+        .collect();
+    let expected_token_kinds = [
+        TokenKind::Identifier("i".into()),
+        TokenKind::Increment,
+        TokenKind::Minus,
+        TokenKind::Integer(1),
+        TokenKind::Semicolon,
+        TokenKind::Identifier("i".into()),
+        TokenKind::Decrement,
+        TokenKind::Minus,
+        TokenKind::Integer(1),
+        TokenKind::EndOfFile,
+    ];
+
+    assert_eq!(actual_token_kinds, expected_token_kinds);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_integer_limits() {
+    let input = "9223372036854775807, -9223372036854775808, 0x7FFFFFFFFFFFFFFF";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let actual_token_kinds: Vec<TokenKind> = tokens
+        .iter()
+        .map(|token| token.token_kind.clone())
+        .collect();
+    let expected_token_kinds = [
+        TokenKind::Integer(i64::MAX),
+        TokenKind::Comma,
+        TokenKind::Integer(i64::MIN),
+        TokenKind::Comma,
+        TokenKind::Integer(i64::MAX),
+        TokenKind::EndOfFile,
+    ];
+
+    assert_eq!(actual_token_kinds, expected_token_kinds);
+}
+
+#[test]
+fn test_integer_overflow() {
+    let decimal = "let x = 9223372036854775808;";
+    let hexadecimal = "let x = -0x8000000000000001;";
+    let invalid_digits = "let x = 0b102;";
+
+    assert_eq!(
+        Lexer::new(decimal).tokenize(),
+        Err(Error::IntegerOverflow {
+            line: 1,
+            column: 9,
+            span: Span::new(8, 27),
+        })
+    );
+    assert_eq!(
+        Lexer::new(hexadecimal).tokenize(),
+        Err(Error::IntegerOverflow {
+            line: 1,
+            column: 9,
+            span: Span::new(8, 27),
+        })
+    );
+    assert_eq!(
+        Lexer::new(invalid_digits).tokenize(),
+        Err(Error::InvalidToken {
+            line: 1,
+            column: 9,
+            span: Span::new(8, 13),
+        })
+    );
+}
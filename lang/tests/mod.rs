@@ -3,3 +3,15 @@ mod lexer;
 
 #[cfg(feature = "parser")]
 mod parser;
+
+#[cfg(feature = "semantics")]
+mod semantics;
+
+#[cfg(feature = "semantics")]
+mod diagnostics;
+
+#[cfg(feature = "interpreter")]
+mod interpreter;
+
+#[cfg(feature = "bytecode")]
+mod bytecode;
@@ -1,4 +1,4 @@
-use lang::lexer::{tokens::Token, tokens::TokenKind, Lexer};
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
 use lang::parser::ast::AST;
 use lang::parser::errors::Error;
 use lang::parser::{Expression, Literal, Parser, Statement};
@@ -18,34 +18,58 @@ fn test_if() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("x".into()), 1, 5),
-            initializer: Some(Expression::Literal(Literal::Number(1.0))),
+            name: Token::new(TokenKind::Identifier("x".into()), 2, 13, Span::new(13, 14)),
+            type_annotation: None,
+            initializer: Some(Expression::Literal {
+                value: Literal::Integer(1),
+                span: Span::new(17, 18),
+            }),
+            span: Span::new(9, 19),
         },
         Statement::If {
             condition: Expression::Binary {
                 left: Box::from(Expression::Variable {
-                    name: Token::new(TokenKind::Identifier("x".into()), 3, 14),
+                    name: Token::new(TokenKind::Identifier("x".into()), 4, 13, Span::new(33, 34)),
+                    span: Span::new(33, 34),
+                }),
+                operator: Token::new(TokenKind::Equality, 4, 15, Span::new(35, 37)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(38, 39),
                 }),
-                operator: Token::new(TokenKind::Equality, 3, 16),
-                right: Box::from(Expression::Literal(Literal::Number(1.0))),
+                span: Span::new(33, 39),
             },
             then_branch: Box::from(Statement::Block {
                 statements: vec![Statement::Expression {
                     expression: Expression::Call {
                         callee: Box::from(Expression::Variable {
-                            name: Token::new(TokenKind::Identifier("print".into()), 4, 18),
+                            name: Token::new(
+                                TokenKind::Identifier("print".into()),
+                                5,
+                                13,
+                                Span::new(55, 60),
+                            ),
+                            span: Span::new(55, 60),
                         }),
-                        arguments: vec![Expression::Literal(Literal::String("x is 1.".into()))],
+                        arguments: vec![Expression::Literal {
+                            value: Literal::String("x is 1.".into()),
+                            span: Span::new(61, 70),
+                        }],
+                        span: Span::new(55, 71),
                     },
+                    span: Span::new(55, 72),
                 }],
+                span: Span::new(41, 82),
             }),
             else_branch: None,
+            span: Span::new(29, 82),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -66,48 +90,84 @@ fn test_if_else() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("x".into()), 1, 5),
-            initializer: Some(Expression::Literal(Literal::Number(1.0))),
+            name: Token::new(TokenKind::Identifier("x".into()), 2, 13, Span::new(13, 14)),
+            type_annotation: None,
+            initializer: Some(Expression::Literal {
+                value: Literal::Integer(1),
+                span: Span::new(17, 18),
+            }),
+            span: Span::new(9, 19),
         },
         Statement::If {
             condition: Expression::Binary {
                 left: Box::from(Expression::Variable {
-                    name: Token::new(TokenKind::Identifier("x".into()), 3, 14),
+                    name: Token::new(TokenKind::Identifier("x".into()), 4, 13, Span::new(33, 34)),
+                    span: Span::new(33, 34),
+                }),
+                operator: Token::new(TokenKind::Equality, 4, 15, Span::new(35, 37)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(38, 39),
                 }),
-                operator: Token::new(TokenKind::Equality, 3, 16),
-                right: Box::from(Expression::Literal(Literal::Number(1.0))),
+                span: Span::new(33, 39),
             },
             then_branch: Box::from(Statement::Block {
                 statements: vec![Statement::Expression {
                     expression: Expression::Call {
                         callee: Box::from(Expression::Variable {
-                            name: Token::new(TokenKind::Identifier("print".into()), 4, 18),
+                            name: Token::new(
+                                TokenKind::Identifier("print".into()),
+                                5,
+                                13,
+                                Span::new(55, 60),
+                            ),
+                            span: Span::new(55, 60),
                         }),
-                        arguments: vec![Expression::Literal(Literal::String("x is 1.".into()))],
+                        arguments: vec![Expression::Literal {
+                            value: Literal::String("x is 1.".into()),
+                            span: Span::new(61, 70),
+                        }],
+                        span: Span::new(55, 71),
                     },
+                    span: Span::new(55, 72),
                 }],
+                span: Span::new(41, 82),
             }),
             else_branch: Some(Box::from(Statement::Block {
                 statements: vec![Statement::Expression {
                     expression: Expression::Call {
                         callee: Box::from(Expression::Variable {
-                            name: Token::new(TokenKind::Identifier("print".into()), 6, 18),
+                            name: Token::new(
+                                TokenKind::Identifier("print".into()),
+                                7,
+                                13,
+                                Span::new(102, 107),
+                            ),
+                            span: Span::new(102, 107),
                         }),
-                        arguments: vec![Expression::Literal(Literal::String("x is not 1.".into()))],
+                        arguments: vec![Expression::Literal {
+                            value: Literal::String("x is not 1.".into()),
+                            span: Span::new(108, 121),
+                        }],
+                        span: Span::new(102, 122),
                     },
+                    span: Span::new(102, 123),
                 }],
+                span: Span::new(88, 133),
             })),
+            span: Span::new(29, 133),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
 #[test]
-#[allow(clippy::unwrap_used)]
+#[allow(clippy::unwrap_used, clippy::too_many_lines)]
 fn test_else_if() {
     let input = r#"
         let x = 1;
@@ -125,64 +185,121 @@ fn test_else_if() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("x".into()), 1, 5),
-            initializer: Some(Expression::Literal(Literal::Number(1.0))),
+            name: Token::new(TokenKind::Identifier("x".into()), 2, 13, Span::new(13, 14)),
+            type_annotation: None,
+            initializer: Some(Expression::Literal {
+                value: Literal::Integer(1),
+                span: Span::new(17, 18),
+            }),
+            span: Span::new(9, 19),
         },
         Statement::If {
             condition: Expression::Binary {
                 left: Box::from(Expression::Variable {
-                    name: Token::new(TokenKind::Identifier("x".into()), 3, 14),
+                    name: Token::new(TokenKind::Identifier("x".into()), 4, 13, Span::new(33, 34)),
+                    span: Span::new(33, 34),
                 }),
-                operator: Token::new(TokenKind::Equality, 3, 16),
-                right: Box::from(Expression::Literal(Literal::Number(1.0))),
+                operator: Token::new(TokenKind::Equality, 4, 15, Span::new(35, 37)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(38, 39),
+                }),
+                span: Span::new(33, 39),
             },
             then_branch: Box::from(Statement::Block {
                 statements: vec![Statement::Expression {
                     expression: Expression::Call {
                         callee: Box::from(Expression::Variable {
-                            name: Token::new(TokenKind::Identifier("print".into()), 4, 18),
+                            name: Token::new(
+                                TokenKind::Identifier("print".into()),
+                                5,
+                                13,
+                                Span::new(55, 60),
+                            ),
+                            span: Span::new(55, 60),
                         }),
-                        arguments: vec![Expression::Literal(Literal::String("x is 1.".into()))],
+                        arguments: vec![Expression::Literal {
+                            value: Literal::String("x is 1.".into()),
+                            span: Span::new(61, 70),
+                        }],
+                        span: Span::new(55, 71),
                     },
+                    span: Span::new(55, 72),
                 }],
+                span: Span::new(41, 82),
             }),
             else_branch: Some(Box::from(Statement::If {
                 condition: Expression::Binary {
                     left: Box::from(Expression::Variable {
-                        name: Token::new(TokenKind::Identifier("x".into()), 5, 21),
+                        name: Token::new(
+                            TokenKind::Identifier("x".into()),
+                            6,
+                            20,
+                            Span::new(92, 93),
+                        ),
+                        span: Span::new(92, 93),
+                    }),
+                    operator: Token::new(TokenKind::Equality, 6, 22, Span::new(94, 96)),
+                    right: Box::from(Expression::Literal {
+                        value: Literal::Integer(2),
+                        span: Span::new(97, 98),
                     }),
-                    operator: Token::new(TokenKind::Equality, 5, 23),
-                    right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                    span: Span::new(92, 98),
                 },
                 then_branch: Box::from(Statement::Block {
                     statements: vec![Statement::Expression {
                         expression: Expression::Call {
                             callee: Box::from(Expression::Variable {
-                                name: Token::new(TokenKind::Identifier("print".into()), 6, 18),
+                                name: Token::new(
+                                    TokenKind::Identifier("print".into()),
+                                    7,
+                                    13,
+                                    Span::new(114, 119),
+                                ),
+                                span: Span::new(114, 119),
                             }),
-                            arguments: vec![Expression::Literal(Literal::String("x is 2.".into()))],
+                            arguments: vec![Expression::Literal {
+                                value: Literal::String("x is 2.".into()),
+                                span: Span::new(120, 129),
+                            }],
+                            span: Span::new(114, 130),
                         },
+                        span: Span::new(114, 131),
                     }],
+                    span: Span::new(100, 141),
                 }),
                 else_branch: Some(Box::from(Statement::Block {
                     statements: vec![Statement::Expression {
                         expression: Expression::Call {
                             callee: Box::from(Expression::Variable {
-                                name: Token::new(TokenKind::Identifier("print".into()), 8, 18),
+                                name: Token::new(
+                                    TokenKind::Identifier("print".into()),
+                                    9,
+                                    13,
+                                    Span::new(161, 166),
+                                ),
+                                span: Span::new(161, 166),
                             }),
-                            arguments: vec![Expression::Literal(Literal::String(
-                                "x is not 1 or 2.".into(),
-                            ))],
+                            arguments: vec![Expression::Literal {
+                                value: Literal::String("x is not 1 or 2.".into()),
+                                span: Span::new(167, 185),
+                            }],
+                            span: Span::new(161, 186),
                         },
+                        span: Span::new(161, 187),
                     }],
+                    span: Span::new(147, 197),
                 })),
+                span: Span::new(88, 197),
             })),
+            span: Span::new(29, 197),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -199,14 +316,15 @@ fn test_else_only() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let ast = parser.parse();
+    let (_, errors) = parser.parse();
 
     assert_eq!(
-        ast,
-        Err(Error::UnexpectedToken {
-            line: 1,
-            column: 4,
+        errors,
+        [Error::UnexpectedToken {
+            line: 2,
+            column: 9,
             message: "Expected expression.".into(),
-        })
+            span: Span::new(9, 13),
+        }]
     );
 }
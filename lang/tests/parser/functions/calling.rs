@@ -1,6 +1,6 @@
-use lang::lexer::{tokens::Token, tokens::TokenKind, Lexer};
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
 use lang::parser::ast::AST;
-use lang::parser::{Expression, Literal, Parser, Statement};
+use lang::parser::{Expression, Literal, Parser, Statement, TypeAnnotation};
 
 #[test]
 #[allow(clippy::unwrap_used)]
@@ -17,47 +17,142 @@ fn test_greet() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Function {
-            name: Token::new(TokenKind::Identifier("greet".into()), 1, 8),
+            name: Token::new(
+                TokenKind::Identifier("greet".into()),
+                2,
+                12,
+                Span::new(12, 17),
+            ),
             parameters: vec![(
-                Token::new(TokenKind::Identifier("name".into()), 1, 13),
-                Token::new(TokenKind::Identifier("str".into()), 1, 18),
+                Token::new(
+                    TokenKind::Identifier("name".into()),
+                    2,
+                    18,
+                    Span::new(18, 22),
+                ),
+                TypeAnnotation::Named(Token::new(
+                    TokenKind::Identifier("str".into()),
+                    2,
+                    24,
+                    Span::new(24, 27),
+                )),
             )],
             return_type: None,
             body: Box::from(Statement::Block {
                 statements: vec![Statement::Expression {
                     expression: Expression::Call {
                         callee: Box::from(Expression::Variable {
-                            name: Token::new(TokenKind::Identifier("print".into()), 2, 18),
+                            name: Token::new(
+                                TokenKind::Identifier("print".into()),
+                                3,
+                                13,
+                                Span::new(43, 48),
+                            ),
+                            span: Span::new(43, 48),
                         }),
                         arguments: vec![Expression::Binary {
                             left: Box::from(Expression::Binary {
-                                left: Box::from(Expression::Literal(Literal::String(
-                                    "Hello, ".into(),
-                                ))),
-                                operator: Token::new(TokenKind::Plus, 2, 29),
+                                left: Box::from(Expression::Literal {
+                                    value: Literal::String("Hello, ".into()),
+                                    span: Span::new(49, 58),
+                                }),
+                                operator: Token::new(TokenKind::Plus, 3, 29, Span::new(59, 60)),
                                 right: Box::from(Expression::Variable {
-                                    name: Token::new(TokenKind::Identifier("name".into()), 2, 35),
+                                    name: Token::new(
+                                        TokenKind::Identifier("name".into()),
+                                        3,
+                                        31,
+                                        Span::new(61, 65),
+                                    ),
+                                    span: Span::new(61, 65),
                                 }),
+                                span: Span::new(49, 65),
+                            }),
+                            operator: Token::new(TokenKind::Plus, 3, 36, Span::new(66, 67)),
+                            right: Box::from(Expression::Literal {
+                                value: Literal::String("!".into()),
+                                span: Span::new(68, 71),
                             }),
-                            operator: Token::new(TokenKind::Plus, 2, 36),
-                            right: Box::from(Expression::Literal(Literal::String("!".into()))),
+                            span: Span::new(49, 71),
                         }],
+                        span: Span::new(43, 72),
                     },
+                    span: Span::new(43, 73),
                 }],
+                span: Span::new(29, 83),
             }),
+            span: Span::new(9, 83),
         },
         Statement::Expression {
             expression: Expression::Call {
                 callee: Box::from(Expression::Variable {
-                    name: Token::new(TokenKind::Identifier("greet".into()), 5, 14),
+                    name: Token::new(
+                        TokenKind::Identifier("greet".into()),
+                        6,
+                        9,
+                        Span::new(93, 98),
+                    ),
+                    span: Span::new(93, 98),
                 }),
-                arguments: vec![Expression::Literal(Literal::String("World".into()))],
+                arguments: vec![Expression::Literal {
+                    value: Literal::String("World".into()),
+                    span: Span::new(99, 106),
+                }],
+                span: Span::new(93, 107),
             },
+            span: Span::new(93, 108),
         },
     ]);
 
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_method_call() {
+    let input = r#"
+        "a".twice(1).len;
+    "#;
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Expression {
+        expression: Expression::Field {
+            object: Box::from(Expression::MethodCall {
+                receiver: Box::from(Expression::Literal {
+                    value: Literal::String("a".into()),
+                    span: Span::new(9, 12),
+                }),
+                name: Token::new(
+                    TokenKind::Identifier("twice".into()),
+                    2,
+                    13,
+                    Span::new(13, 18),
+                ),
+                arguments: vec![Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(19, 20),
+                }],
+                span: Span::new(9, 21),
+            }),
+            name: Token::new(
+                TokenKind::Identifier("len".into()),
+                2,
+                22,
+                Span::new(22, 25),
+            ),
+            span: Span::new(9, 25),
+        },
+        span: Span::new(9, 26),
+    }]);
+
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
@@ -1,4 +1,4 @@
-use lang::lexer::{tokens::Token, tokens::TokenKind, Lexer};
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
 use lang::parser::ast::AST;
 use lang::parser::errors::Error;
 use lang::parser::{Parser, Statement};
@@ -14,15 +14,16 @@ fn test_no_name() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let ast = parser.parse();
+    let (_, errors) = parser.parse();
 
     assert_eq!(
-        ast,
-        Err(Error::UnexpectedToken {
-            line: 1,
-            column: 3,
+        errors,
+        [Error::UnexpectedToken {
+            line: 2,
+            column: 12,
             message: "Expected function name.".into(),
-        })
+            span: Span::new(12, 13),
+        }]
     );
 }
 
@@ -37,15 +38,16 @@ fn test_no_parenthesis() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let ast = parser.parse();
+    let (_, errors) = parser.parse();
 
     assert_eq!(
-        ast,
-        Err(Error::UnexpectedToken {
-            line: 1,
-            column: 8,
+        errors,
+        [Error::UnexpectedToken {
+            line: 2,
+            column: 17,
             message: "Expected '(' after function name.".into(),
-        })
+            span: Span::new(17, 18),
+        }]
     );
 }
 
@@ -60,9 +62,9 @@ fn test_no_braces() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let ast = parser.parse();
+    let (_, errors) = parser.parse();
 
-    assert!(ast.is_err());
+    assert!(!errors.is_empty());
 }
 
 #[test]
@@ -76,13 +78,23 @@ fn test_pure_main() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![Statement::Function {
-        name: Token::new(TokenKind::Identifier("main".into()), 1, 7),
+        name: Token::new(
+            TokenKind::Identifier("main".into()),
+            2,
+            12,
+            Span::new(12, 16),
+        ),
         parameters: vec![],
         return_type: None,
-        body: Box::from(Statement::Block { statements: vec![] }),
+        body: Box::from(Statement::Block {
+            statements: vec![],
+            span: Span::new(19, 21),
+        }),
+        span: Span::new(9, 21),
     }]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
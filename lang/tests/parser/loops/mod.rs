@@ -0,0 +1,158 @@
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
+use lang::parser::ast::AST;
+use lang::parser::errors::Error;
+use lang::parser::{Expression, Literal, Parser, Statement};
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_for_range() {
+    let input = r"
+        for i in 0 to 10 step 2 {}
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::ForRange {
+        label: None,
+        variable: Token::new(TokenKind::Identifier("i".into()), 2, 13, Span::new(13, 14)),
+        start: Expression::Literal {
+            value: Literal::Integer(0),
+            span: Span::new(18, 19),
+        },
+        end: Expression::Literal {
+            value: Literal::Integer(10),
+            span: Span::new(23, 25),
+        },
+        step: Some(Box::from(Expression::Literal {
+            value: Literal::Integer(2),
+            span: Span::new(31, 32),
+        })),
+        body: Box::from(Statement::Block {
+            statements: vec![],
+            span: Span::new(33, 35),
+        }),
+        span: Span::new(9, 35),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_for_range_without_step() {
+    let input = r"
+        for i in 1 to n print(i);
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+
+    assert_eq!(errors, []);
+    assert!(matches!(
+        actual_ast.statements.as_slice(),
+        [Statement::ForRange {
+            end: Expression::Variable { .. },
+            step: None,
+            body,
+            ..
+        }] if matches!(**body, Statement::Expression { .. })
+    ));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_for_range_missing_to() {
+    let input = r"
+        for i in 0 10 {}
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert_eq!(
+        errors,
+        [Error::UnexpectedToken {
+            line: 2,
+            column: 20,
+            message: "Expected 'to' after range start.".into(),
+            span: Span::new(20, 22),
+        }]
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_labeled_loop() {
+    let input = r"
+        'outer: while (true) { break 'outer; }
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::While {
+        label: Some(Token::new(
+            TokenKind::Label("outer".into()),
+            2,
+            9,
+            Span::new(9, 15),
+        )),
+        condition: Expression::Literal {
+            value: Literal::Boolean(true),
+            span: Span::new(24, 28),
+        },
+        body: Box::from(Statement::Block {
+            statements: vec![Statement::Break {
+                keyword: Token::new(TokenKind::Break, 2, 32, Span::new(32, 37)),
+                label: Some(Token::new(
+                    TokenKind::Label("outer".into()),
+                    2,
+                    38,
+                    Span::new(38, 44),
+                )),
+                span: Span::new(32, 45),
+            }],
+            span: Span::new(30, 47),
+        }),
+        span: Span::new(9, 47),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_label_without_loop() {
+    let input = r"
+        'outer: print(1);
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert_eq!(
+        errors,
+        [Error::UnexpectedToken {
+            line: 2,
+            column: 17,
+            message: "Expected loop after label.".into(),
+            span: Span::new(17, 22),
+        }]
+    );
+}
@@ -1,3 +1,6 @@
 mod conditions;
 mod functions;
+mod loops;
+mod recovery;
+mod streaming;
 mod variables;
@@ -0,0 +1,94 @@
+use lang::lexer::Lexer;
+use lang::parser::errors::Error;
+use lang::parser::{Parser, Statement};
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_multiple_errors() {
+    let input = r"
+        let a = ;
+        let b = 2;
+
+        fn broken(x: int {
+            print(x);
+        }
+
+        fn fine() {
+            let c = 3
+            print(c);
+        }
+
+        print(b);
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (ast, errors) = parser.parse();
+
+    let messages = errors
+        .iter()
+        .map(|error| match error {
+            Error::UnexpectedToken { line, message, .. } => (*line, message.as_str()),
+            _ => (0, ""),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            (2, "Expected expression."),
+            (5, "Expected ')' after parameters."),
+            (11, "Expected ';' after variable declaration."),
+        ]
+    );
+
+    // Everything but the broken statements is still parsed.
+    assert_eq!(ast.statements.len(), 3);
+    assert!(matches!(ast.statements[0], Statement::Variable { .. }));
+    assert!(matches!(
+        &ast.statements[1],
+        Statement::Function { body, .. }
+            if matches!(&**body, Statement::Block { statements, .. } if statements.is_empty())
+    ));
+    assert!(matches!(ast.statements[2], Statement::Expression { .. }));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_stray_closing_brace() {
+    let input = r"
+        }
+        let a = 1;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (ast, errors) = parser.parse();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(ast.statements.len(), 1);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_invalid_assignment_target() {
+    let input = r"
+        1 = 2;
+        let a = 1;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (ast, errors) = parser.parse();
+
+    assert!(matches!(
+        errors.as_slice(),
+        [Error::InvalidAssignmentTarget { line: 2, .. }]
+    ));
+    assert_eq!(ast.statements.len(), 2);
+}
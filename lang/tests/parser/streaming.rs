@@ -0,0 +1,57 @@
+use lang::lexer::errors::Error as LexerError;
+use lang::lexer::span::Span;
+use lang::lexer::Lexer;
+use lang::parser::errors::Error;
+use lang::parser::Parser;
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_same_as_buffered() {
+    let input = r"
+        fn fib(n: int) -> int {
+            if (n < 2) {
+                return n;
+            }
+
+            return fib(n - 1) + fib(n - 2);
+        }
+
+        print(fib(10));
+    ";
+
+    let tokens = Lexer::new(input).tokenize().unwrap();
+    let (buffered, buffered_errors) = Parser::new(&tokens).parse();
+    let (streamed, streamed_errors) = Parser::streaming(Lexer::new(input)).parse();
+
+    assert_eq!(buffered_errors, []);
+    assert_eq!(streamed_errors, []);
+    assert_eq!(buffered, streamed);
+}
+
+#[test]
+fn test_lexer_error() {
+    let input = r#"
+        let a = 1 +;
+        let b = "never ends
+    "#;
+
+    let (ast, errors) = Parser::streaming(Lexer::new(input)).parse();
+
+    assert!(ast.statements.is_empty());
+    assert_eq!(
+        errors,
+        [
+            Error::UnexpectedToken {
+                line: 2,
+                column: 20,
+                message: "Expected expression.".to_string(),
+                span: Span::new(20, 21),
+            },
+            Error::Lexer(LexerError::UnterminatedString {
+                line: 3,
+                column: 17,
+                span: Span::new(38, 54),
+            }),
+        ]
+    );
+}
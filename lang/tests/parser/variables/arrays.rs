@@ -0,0 +1,140 @@
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
+use lang::parser::ast::AST;
+use lang::parser::errors::Error;
+use lang::parser::{Expression, Literal, Parser, Statement, TypeAnnotation};
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_index_assignment() {
+    let input = r"
+        xs[i] = [1, 2];
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Expression {
+        expression: Expression::IndexAssignment {
+            array: Box::from(Expression::Variable {
+                name: Token::new(TokenKind::Identifier("xs".into()), 2, 9, Span::new(9, 11)),
+                span: Span::new(9, 11),
+            }),
+            bracket: Token::new(TokenKind::LeftBracket, 2, 11, Span::new(11, 12)),
+            index: Box::from(Expression::Variable {
+                name: Token::new(TokenKind::Identifier("i".into()), 2, 12, Span::new(12, 13)),
+                span: Span::new(12, 13),
+            }),
+            value: Box::from(Expression::Array {
+                elements: vec![
+                    Expression::Literal {
+                        value: Literal::Integer(1),
+                        span: Span::new(18, 19),
+                    },
+                    Expression::Literal {
+                        value: Literal::Integer(2),
+                        span: Span::new(21, 22),
+                    },
+                ],
+                span: Span::new(17, 23),
+            }),
+            span: Span::new(9, 23),
+        },
+        span: Span::new(9, 24),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_nested_index() {
+    let input = r"
+        let x = grid[0][1];
+        let empty = [];
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+
+    assert_eq!(errors, []);
+    assert!(matches!(
+        actual_ast.statements.as_slice(),
+        [
+            Statement::Variable {
+                initializer: Some(Expression::Index { array, .. }),
+                ..
+            },
+            Statement::Variable {
+                initializer: Some(Expression::Array { elements, .. }),
+                ..
+            },
+        ] if matches!(**array, Expression::Index { .. }) && elements.is_empty()
+    ));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_array_type() {
+    let input = r"
+        fn first(rows: [[int]]) -> [int] {
+            return rows[0];
+        }
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+
+    assert_eq!(errors, []);
+    assert!(matches!(
+        actual_ast.statements.as_slice(),
+        [Statement::Function {
+            parameters,
+            return_type: Some(TypeAnnotation::Array { span, .. }),
+            ..
+        }] if *span == Span::new(36, 41) && matches!(
+            parameters.as_slice(),
+            [(_, TypeAnnotation::Array { element, .. })]
+                if matches!(**element, TypeAnnotation::Array { .. })
+        )
+    ));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_compound_index_assignment() {
+    let input = r"
+        xs[0] += 1;
+        xs[0]++;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert_eq!(
+        errors,
+        [
+            Error::InvalidAssignmentTarget {
+                line: 2,
+                column: 15,
+                span: Span::new(15, 17),
+            },
+            Error::InvalidAssignmentTarget {
+                line: 3,
+                column: 14,
+                span: Span::new(34, 36),
+            }
+        ]
+    );
+}
@@ -1,4 +1,4 @@
-use lang::lexer::{tokens::Token, tokens::TokenKind, Lexer};
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
 use lang::parser::ast::AST;
 use lang::parser::{Expression, Literal, Parser, Statement};
 
@@ -13,12 +13,23 @@ fn test_true() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![Statement::Variable {
-        name: Token::new(TokenKind::Identifier("yes".into()), 1, 7),
-        initializer: Some(Expression::Literal(Literal::Boolean(true))),
+        name: Token::new(
+            TokenKind::Identifier("yes".into()),
+            2,
+            13,
+            Span::new(13, 16),
+        ),
+        type_annotation: None,
+        initializer: Some(Expression::Literal {
+            value: Literal::Boolean(true),
+            span: Span::new(19, 23),
+        }),
+        span: Span::new(9, 24),
     }]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -33,12 +44,18 @@ fn test_false() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![Statement::Variable {
-        name: Token::new(TokenKind::Identifier("no".into()), 1, 6),
-        initializer: Some(Expression::Literal(Literal::Boolean(false))),
+        name: Token::new(TokenKind::Identifier("no".into()), 2, 13, Span::new(13, 15)),
+        type_annotation: None,
+        initializer: Some(Expression::Literal {
+            value: Literal::Boolean(false),
+            span: Span::new(18, 23),
+        }),
+        span: Span::new(9, 24),
     }]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -54,24 +71,42 @@ fn test_not() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 1, 7),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                2,
+                13,
+                Span::new(13, 16),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Unary {
-                operator: Token::new(TokenKind::LogicalNot, 1, 10),
-                right: Box::from(Expression::Literal(Literal::Boolean(false))),
+                operator: Token::new(TokenKind::LogicalNot, 2, 19, Span::new(19, 20)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(20, 25),
+                }),
+                span: Span::new(19, 25),
             }),
+            span: Span::new(9, 26),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 2, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 3, 13, Span::new(39, 41)),
+            type_annotation: None,
             initializer: Some(Expression::Unary {
-                operator: Token::new(TokenKind::LogicalNot, 2, 18),
-                right: Box::from(Expression::Literal(Literal::Boolean(true))),
+                operator: Token::new(TokenKind::LogicalNot, 3, 18, Span::new(44, 45)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(45, 49),
+                }),
+                span: Span::new(44, 49),
             }),
+            span: Span::new(35, 50),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -89,47 +124,94 @@ fn test_and() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 1, 7),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                2,
+                13,
+                Span::new(13, 16),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Boolean(true))),
-                operator: Token::new(TokenKind::LogicalAnd, 1, 16),
-                right: Box::from(Expression::Literal(Literal::Boolean(true))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(19, 23),
+                }),
+                operator: Token::new(TokenKind::LogicalAnd, 2, 24, Span::new(24, 26)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(27, 31),
+                }),
+                span: Span::new(19, 31),
             }),
+            span: Span::new(9, 32),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 2, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 3, 13, Span::new(45, 47)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Boolean(true))),
-                operator: Token::new(TokenKind::LogicalAnd, 2, 24),
-                right: Box::from(Expression::Literal(Literal::Boolean(false))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(50, 54),
+                }),
+                operator: Token::new(TokenKind::LogicalAnd, 3, 23, Span::new(55, 57)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(58, 63),
+                }),
+                span: Span::new(50, 63),
             }),
+            span: Span::new(41, 64),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 3, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 4, 13, Span::new(77, 79)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Boolean(false))),
-                operator: Token::new(TokenKind::LogicalAnd, 3, 25),
-                right: Box::from(Expression::Literal(Literal::Boolean(true))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(82, 87),
+                }),
+                operator: Token::new(TokenKind::LogicalAnd, 4, 24, Span::new(88, 90)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(91, 95),
+                }),
+                span: Span::new(82, 95),
             }),
+            span: Span::new(73, 96),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 4, 15),
+            name: Token::new(
+                TokenKind::Identifier("no".into()),
+                5,
+                13,
+                Span::new(109, 111),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Boolean(false))),
-                operator: Token::new(TokenKind::LogicalAnd, 4, 25),
-                right: Box::from(Expression::Literal(Literal::Boolean(false))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(114, 119),
+                }),
+                operator: Token::new(TokenKind::LogicalAnd, 5, 24, Span::new(120, 122)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(123, 128),
+                }),
+                span: Span::new(114, 128),
             }),
+            span: Span::new(105, 129),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
 #[test]
-#[allow(clippy::unwrap_used)]
+#[allow(clippy::unwrap_used, clippy::too_many_lines)]
 fn test_or() {
     let input = r"
         let yes = true || true;
@@ -142,42 +224,99 @@ fn test_or() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 1, 7),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                2,
+                13,
+                Span::new(13, 16),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Boolean(true))),
-                operator: Token::new(TokenKind::LogicalOr, 1, 16),
-                right: Box::from(Expression::Literal(Literal::Boolean(true))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(19, 23),
+                }),
+                operator: Token::new(TokenKind::LogicalOr, 2, 24, Span::new(24, 26)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(27, 31),
+                }),
+                span: Span::new(19, 31),
             }),
+            span: Span::new(9, 32),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 2, 16),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                3,
+                13,
+                Span::new(45, 48),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Boolean(true))),
-                operator: Token::new(TokenKind::LogicalOr, 2, 25),
-                right: Box::from(Expression::Literal(Literal::Boolean(false))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(51, 55),
+                }),
+                operator: Token::new(TokenKind::LogicalOr, 3, 24, Span::new(56, 58)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(59, 64),
+                }),
+                span: Span::new(51, 64),
             }),
+            span: Span::new(41, 65),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 3, 16),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                4,
+                13,
+                Span::new(78, 81),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Boolean(false))),
-                operator: Token::new(TokenKind::LogicalOr, 3, 26),
-                right: Box::from(Expression::Literal(Literal::Boolean(true))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(84, 89),
+                }),
+                operator: Token::new(TokenKind::LogicalOr, 4, 25, Span::new(90, 92)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(true),
+                    span: Span::new(93, 97),
+                }),
+                span: Span::new(84, 97),
             }),
+            span: Span::new(74, 98),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 4, 15),
+            name: Token::new(
+                TokenKind::Identifier("no".into()),
+                5,
+                13,
+                Span::new(111, 113),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Boolean(false))),
-                operator: Token::new(TokenKind::LogicalOr, 4, 25),
-                right: Box::from(Expression::Literal(Literal::Boolean(false))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(116, 121),
+                }),
+                operator: Token::new(TokenKind::LogicalOr, 5, 24, Span::new(122, 124)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Boolean(false),
+                    span: Span::new(125, 130),
+                }),
+                span: Span::new(116, 130),
             }),
+            span: Span::new(107, 131),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -194,34 +333,67 @@ fn test_greater_than() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 1, 7),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                2,
+                13,
+                Span::new(13, 16),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(1.0))),
-                operator: Token::new(TokenKind::LessThan, 1, 12),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(19, 20),
+                }),
+                operator: Token::new(TokenKind::LessThan, 2, 21, Span::new(21, 22)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(23, 24),
+                }),
+                span: Span::new(19, 24),
             }),
+            span: Span::new(9, 25),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 2, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 3, 13, Span::new(38, 40)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(2.0))),
-                operator: Token::new(TokenKind::LessThan, 2, 20),
-                right: Box::from(Expression::Literal(Literal::Number(1.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(43, 44),
+                }),
+                operator: Token::new(TokenKind::LessThan, 3, 20, Span::new(45, 46)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(47, 48),
+                }),
+                span: Span::new(43, 48),
             }),
+            span: Span::new(34, 49),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 3, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 4, 13, Span::new(62, 64)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(2.0))),
-                operator: Token::new(TokenKind::LessThan, 3, 20),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(67, 68),
+                }),
+                operator: Token::new(TokenKind::LessThan, 4, 20, Span::new(69, 70)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(71, 72),
+                }),
+                span: Span::new(67, 72),
             }),
+            span: Span::new(58, 73),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -238,34 +410,72 @@ fn test_greater_than_or_equal() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 1, 7),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                2,
+                13,
+                Span::new(13, 16),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(1.0))),
-                operator: Token::new(TokenKind::LessThanOrEqual, 1, 13),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(19, 20),
+                }),
+                operator: Token::new(TokenKind::LessThanOrEqual, 2, 21, Span::new(21, 23)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(24, 25),
+                }),
+                span: Span::new(19, 25),
             }),
+            span: Span::new(9, 26),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 2, 16),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                3,
+                13,
+                Span::new(39, 42),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(2.0))),
-                operator: Token::new(TokenKind::LessThanOrEqual, 2, 22),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(45, 46),
+                }),
+                operator: Token::new(TokenKind::LessThanOrEqual, 3, 21, Span::new(47, 49)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(50, 51),
+                }),
+                span: Span::new(45, 51),
             }),
+            span: Span::new(35, 52),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 3, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 4, 13, Span::new(65, 67)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(3.0))),
-                operator: Token::new(TokenKind::LessThanOrEqual, 3, 21),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(3),
+                    span: Span::new(70, 71),
+                }),
+                operator: Token::new(TokenKind::LessThanOrEqual, 4, 20, Span::new(72, 74)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(75, 76),
+                }),
+                span: Span::new(70, 76),
             }),
+            span: Span::new(61, 77),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -282,34 +492,67 @@ fn test_less_than() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 1, 7),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                2,
+                13,
+                Span::new(13, 16),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(1.0))),
-                operator: Token::new(TokenKind::GreaterThan, 1, 12),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(19, 20),
+                }),
+                operator: Token::new(TokenKind::GreaterThan, 2, 21, Span::new(21, 22)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(23, 24),
+                }),
+                span: Span::new(19, 24),
             }),
+            span: Span::new(9, 25),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 2, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 3, 13, Span::new(38, 40)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(2.0))),
-                operator: Token::new(TokenKind::GreaterThan, 2, 20),
-                right: Box::from(Expression::Literal(Literal::Number(1.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(43, 44),
+                }),
+                operator: Token::new(TokenKind::GreaterThan, 3, 20, Span::new(45, 46)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(47, 48),
+                }),
+                span: Span::new(43, 48),
             }),
+            span: Span::new(34, 49),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 3, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 4, 13, Span::new(62, 64)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(2.0))),
-                operator: Token::new(TokenKind::GreaterThan, 3, 20),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(67, 68),
+                }),
+                operator: Token::new(TokenKind::GreaterThan, 4, 20, Span::new(69, 70)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(71, 72),
+                }),
+                span: Span::new(67, 72),
             }),
+            span: Span::new(58, 73),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -326,34 +569,72 @@ fn test_less_than_or_equal() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 1, 7),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                2,
+                13,
+                Span::new(13, 16),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(1.0))),
-                operator: Token::new(TokenKind::GreaterThanOrEqual, 1, 13),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(19, 20),
+                }),
+                operator: Token::new(TokenKind::GreaterThanOrEqual, 2, 21, Span::new(21, 23)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(24, 25),
+                }),
+                span: Span::new(19, 25),
             }),
+            span: Span::new(9, 26),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 2, 16),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                3,
+                13,
+                Span::new(39, 42),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(2.0))),
-                operator: Token::new(TokenKind::GreaterThanOrEqual, 2, 22),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(45, 46),
+                }),
+                operator: Token::new(TokenKind::GreaterThanOrEqual, 3, 21, Span::new(47, 49)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(50, 51),
+                }),
+                span: Span::new(45, 51),
             }),
+            span: Span::new(35, 52),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 3, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 4, 13, Span::new(65, 67)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(3.0))),
-                operator: Token::new(TokenKind::GreaterThanOrEqual, 3, 21),
-                right: Box::from(Expression::Literal(Literal::Number(2.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(3),
+                    span: Span::new(70, 71),
+                }),
+                operator: Token::new(TokenKind::GreaterThanOrEqual, 4, 20, Span::new(72, 74)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(75, 76),
+                }),
+                span: Span::new(70, 76),
             }),
+            span: Span::new(61, 77),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -372,41 +653,67 @@ fn test_comparison() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("a".into()), 1, 5),
-            initializer: Some(Expression::Literal(Literal::Number(1.0))),
+            name: Token::new(TokenKind::Identifier("a".into()), 2, 13, Span::new(13, 14)),
+            type_annotation: None,
+            initializer: Some(Expression::Literal {
+                value: Literal::Integer(1),
+                span: Span::new(17, 18),
+            }),
+            span: Span::new(9, 19),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("b".into()), 2, 14),
-            initializer: Some(Expression::Literal(Literal::Number(2.0))),
+            name: Token::new(TokenKind::Identifier("b".into()), 3, 13, Span::new(32, 33)),
+            type_annotation: None,
+            initializer: Some(Expression::Literal {
+                value: Literal::Integer(2),
+                span: Span::new(36, 37),
+            }),
+            span: Span::new(28, 38),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("yes".into()), 4, 16),
+            name: Token::new(
+                TokenKind::Identifier("yes".into()),
+                5,
+                13,
+                Span::new(52, 55),
+            ),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
                 left: Box::from(Expression::Variable {
-                    name: Token::new(TokenKind::Identifier("a".into()), 4, 20),
+                    name: Token::new(TokenKind::Identifier("a".into()), 5, 19, Span::new(58, 59)),
+                    span: Span::new(58, 59),
                 }),
-                operator: Token::new(TokenKind::NotEqual, 4, 22),
+                operator: Token::new(TokenKind::NotEqual, 5, 21, Span::new(60, 62)),
                 right: Box::from(Expression::Variable {
-                    name: Token::new(TokenKind::Identifier("b".into()), 4, 25),
+                    name: Token::new(TokenKind::Identifier("b".into()), 5, 24, Span::new(63, 64)),
+                    span: Span::new(63, 64),
                 }),
+                span: Span::new(58, 64),
             }),
+            span: Span::new(48, 65),
         },
         Statement::Variable {
-            name: Token::new(TokenKind::Identifier("no".into()), 5, 15),
+            name: Token::new(TokenKind::Identifier("no".into()), 6, 13, Span::new(78, 80)),
+            type_annotation: None,
             initializer: Some(Expression::Binary {
                 left: Box::from(Expression::Variable {
-                    name: Token::new(TokenKind::Identifier("a".into()), 5, 19),
+                    name: Token::new(TokenKind::Identifier("a".into()), 6, 18, Span::new(83, 84)),
+                    span: Span::new(83, 84),
                 }),
-                operator: Token::new(TokenKind::Equality, 5, 21),
+                operator: Token::new(TokenKind::Equality, 6, 20, Span::new(85, 87)),
                 right: Box::from(Expression::Variable {
-                    name: Token::new(TokenKind::Identifier("b".into()), 5, 24),
+                    name: Token::new(TokenKind::Identifier("b".into()), 6, 23, Span::new(88, 89)),
+                    span: Span::new(88, 89),
                 }),
+                span: Span::new(83, 89),
             }),
+            span: Span::new(74, 90),
         },
     ]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
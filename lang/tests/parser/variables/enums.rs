@@ -0,0 +1,151 @@
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
+use lang::parser::ast::AST;
+use lang::parser::errors::Error;
+use lang::parser::{Expression, Literal, Parser, Pattern, Statement, TypeAnnotation};
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_enum_declaration() {
+    let input = r"
+        enum Shape { Circle(float), Empty }
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Enum {
+        name: Token::new(
+            TokenKind::Identifier("Shape".into()),
+            2,
+            14,
+            Span::new(14, 19),
+        ),
+        variants: vec![
+            (
+                Token::new(
+                    TokenKind::Identifier("Circle".into()),
+                    2,
+                    22,
+                    Span::new(22, 28),
+                ),
+                vec![TypeAnnotation::Named(Token::new(
+                    TokenKind::Identifier("float".into()),
+                    2,
+                    29,
+                    Span::new(29, 34),
+                ))],
+            ),
+            (
+                Token::new(
+                    TokenKind::Identifier("Empty".into()),
+                    2,
+                    37,
+                    Span::new(37, 42),
+                ),
+                vec![],
+            ),
+        ],
+        span: Span::new(9, 44),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_variant() {
+    let input = r"
+        Shape::Circle(1);
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Expression {
+        expression: Expression::Variant {
+            enumeration: Token::new(
+                TokenKind::Identifier("Shape".into()),
+                2,
+                9,
+                Span::new(9, 14),
+            ),
+            variant: Token::new(
+                TokenKind::Identifier("Circle".into()),
+                2,
+                16,
+                Span::new(16, 22),
+            ),
+            arguments: vec![Expression::Literal {
+                value: Literal::Integer(1),
+                span: Span::new(23, 24),
+            }],
+            span: Span::new(9, 25),
+        },
+        span: Span::new(9, 26),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_match() {
+    let input = r"
+        match shape {
+            Shape::Rect(w, h) => w * h,
+            0 => 1,
+            _ => 0,
+        }
+        print(1);
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+
+    // A match used as a statement doesn't need a semicolon.
+    assert_eq!(errors, []);
+    assert!(matches!(
+        actual_ast.statements.as_slice(),
+        [
+            Statement::Expression {
+                expression: Expression::Match { arms, .. },
+                ..
+            },
+            Statement::Expression { .. },
+        ] if matches!(
+            arms.as_slice(),
+            [first, second, third]
+                if matches!(&first.pattern, Pattern::Variant { bindings, .. } if bindings.len() == 2)
+                    && matches!(second.pattern, Pattern::Literal { value: Literal::Integer(0), .. })
+                    && matches!(third.pattern, Pattern::Wildcard { .. })
+        )
+    ));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_missing_fat_arrow() {
+    let input = r"
+        match n { 0 -> 1 }
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert!(matches!(
+        errors.as_slice(),
+        [Error::UnexpectedToken { message, .. }, ..] if message == "Expected '=>' after pattern."
+    ));
+}
@@ -1,3 +1,4 @@
+use lang::lexer::span::Span;
 use lang::lexer::Lexer;
 use lang::parser::errors::Error;
 use lang::parser::Parser;
@@ -13,15 +14,16 @@ fn test_no_semicolon() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let ast = parser.parse();
+    let (_, errors) = parser.parse();
 
     assert_eq!(
-        ast,
-        Err(Error::UnexpectedToken {
-            line: 1,
-            column: 9,
+        errors,
+        [Error::UnexpectedToken {
+            line: 3,
+            column: 5,
             message: "Expected ';' after variable declaration.".into(),
-        })
+            span: Span::new(23, 23),
+        }]
     );
 }
 
@@ -36,15 +38,16 @@ fn test_no_expression() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let ast = parser.parse();
+    let (_, errors) = parser.parse();
 
     assert_eq!(
-        ast,
-        Err(Error::UnexpectedToken {
-            line: 1,
-            column: 8,
+        errors,
+        [Error::UnexpectedToken {
+            line: 2,
+            column: 17,
             message: "Expected expression.".into(),
-        })
+            span: Span::new(17, 18),
+        }]
     );
 }
 
@@ -59,14 +62,39 @@ fn test_no_name() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let ast = parser.parse();
+    let (_, errors) = parser.parse();
 
     assert_eq!(
-        ast,
-        Err(Error::UnexpectedToken {
-            line: 1,
-            column: 4,
+        errors,
+        [Error::UnexpectedToken {
+            line: 2,
+            column: 13,
             message: "Expected variable name.".into(),
-        })
+            span: Span::new(13, 14),
+        }]
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_no_type() {
+    let input = r"
+        let x: = 1;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert_eq!(
+        errors,
+        [Error::UnexpectedToken {
+            line: 2,
+            column: 16,
+            message: "Expected variable type.".into(),
+            span: Span::new(16, 17),
+        }]
     );
 }
@@ -1,5 +1,8 @@
+mod arrays;
 mod booleans;
+mod enums;
 mod invalid;
 mod numbers;
 mod operations;
 mod strings;
+mod structs;
@@ -1,6 +1,6 @@
-use lang::lexer::{tokens::Token, tokens::TokenKind, Lexer};
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
 use lang::parser::ast::AST;
-use lang::parser::{Expression, Literal, Parser, Statement};
+use lang::parser::{Expression, Literal, Parser, Statement, TypeAnnotation};
 
 #[test]
 #[allow(clippy::unwrap_used)]
@@ -13,12 +13,18 @@ fn test_integer() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![Statement::Variable {
-        name: Token::new(TokenKind::Identifier("x".into()), 1, 5),
-        initializer: Some(Expression::Literal(Literal::Number(1.0))),
+        name: Token::new(TokenKind::Identifier("x".into()), 2, 13, Span::new(13, 14)),
+        type_annotation: None,
+        initializer: Some(Expression::Literal {
+            value: Literal::Integer(1),
+            span: Span::new(17, 18),
+        }),
+        span: Span::new(9, 19),
     }]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
 
@@ -33,11 +39,89 @@ fn test_float() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![Statement::Variable {
-        name: Token::new(TokenKind::Identifier("x".into()), 1, 5),
-        initializer: Some(Expression::Literal(Literal::Number(1.0))),
+        name: Token::new(TokenKind::Identifier("x".into()), 2, 13, Span::new(13, 14)),
+        type_annotation: None,
+        initializer: Some(Expression::Literal {
+            value: Literal::Float(1.0),
+            span: Span::new(17, 20),
+        }),
+        span: Span::new(9, 21),
     }]);
 
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_large_integer() {
+    // 2^53 + 1 can't be represented exactly by a float.
+    let input = r"
+        let x = 9007199254740993;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Variable {
+        name: Token::new(TokenKind::Identifier("x".into()), 2, 13, Span::new(13, 14)),
+        type_annotation: None,
+        initializer: Some(Expression::Literal {
+            value: Literal::Integer(9_007_199_254_740_993),
+            span: Span::new(17, 33),
+        }),
+        span: Span::new(9, 34),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_typed_declaration() {
+    let input = r"
+        let x: float = 1;
+        let y: int;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![
+        Statement::Variable {
+            name: Token::new(TokenKind::Identifier("x".into()), 2, 13, Span::new(13, 14)),
+            type_annotation: Some(TypeAnnotation::Named(Token::new(
+                TokenKind::Identifier("float".into()),
+                2,
+                16,
+                Span::new(16, 21),
+            ))),
+            initializer: Some(Expression::Literal {
+                value: Literal::Integer(1),
+                span: Span::new(24, 25),
+            }),
+            span: Span::new(9, 26),
+        },
+        Statement::Variable {
+            name: Token::new(TokenKind::Identifier("y".into()), 3, 13, Span::new(39, 40)),
+            type_annotation: Some(TypeAnnotation::Named(Token::new(
+                TokenKind::Identifier("int".into()),
+                3,
+                16,
+                Span::new(42, 45),
+            ))),
+            initializer: None,
+            span: Span::new(35, 46),
+        },
+    ]);
+
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
@@ -1,5 +1,6 @@
-use lang::lexer::{tokens::Token, tokens::TokenKind, Lexer};
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
 use lang::parser::ast::AST;
+use lang::parser::errors::Error;
 use lang::parser::{Expression, Literal, Parser, Statement};
 
 #[test]
@@ -13,27 +14,260 @@ fn test_order_of_operations() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![Statement::Variable {
-        name: Token::new(TokenKind::Identifier("x".into()), 1, 5),
+        name: Token::new(TokenKind::Identifier("x".into()), 2, 13, Span::new(13, 14)),
+        type_annotation: None,
         initializer: Some(Expression::Binary {
             left: Box::from(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(1.0))),
-                operator: Token::new(TokenKind::Plus, 1, 10),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(17, 18),
+                }),
+                operator: Token::new(TokenKind::Plus, 2, 19, Span::new(19, 20)),
                 right: Box::from(Expression::Binary {
-                    left: Box::from(Expression::Literal(Literal::Number(2.0))),
-                    operator: Token::new(TokenKind::Star, 1, 14),
-                    right: Box::from(Expression::Literal(Literal::Number(3.0))),
+                    left: Box::from(Expression::Literal {
+                        value: Literal::Integer(2),
+                        span: Span::new(21, 22),
+                    }),
+                    operator: Token::new(TokenKind::Star, 2, 23, Span::new(23, 24)),
+                    right: Box::from(Expression::Literal {
+                        value: Literal::Integer(3),
+                        span: Span::new(25, 26),
+                    }),
+                    span: Span::new(21, 26),
                 }),
+                span: Span::new(17, 26),
             }),
-            operator: Token::new(TokenKind::Minus, 1, 18),
+            operator: Token::new(TokenKind::Minus, 2, 27, Span::new(27, 28)),
             right: Box::from(Expression::Binary {
-                left: Box::from(Expression::Literal(Literal::Number(4.0))),
-                operator: Token::new(TokenKind::Slash, 1, 22),
-                right: Box::from(Expression::Literal(Literal::Number(5.0))),
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(4),
+                    span: Span::new(29, 30),
+                }),
+                operator: Token::new(TokenKind::Slash, 2, 31, Span::new(31, 32)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(5),
+                    span: Span::new(33, 34),
+                }),
+                span: Span::new(29, 34),
             }),
+            span: Span::new(17, 34),
         }),
+        span: Span::new(9, 35),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_compound_assignment() {
+    let input = r"
+        x <<= 1 + 2;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Expression {
+        expression: Expression::CompoundAssignment {
+            name: Token::new(TokenKind::Identifier("x".into()), 2, 9, Span::new(9, 10)),
+            operator: Token::new(TokenKind::BitwiseLeftShiftAssign, 2, 11, Span::new(11, 14)),
+            value: Box::from(Expression::Binary {
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(1),
+                    span: Span::new(15, 16),
+                }),
+                operator: Token::new(TokenKind::Plus, 2, 17, Span::new(17, 18)),
+                right: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(19, 20),
+                }),
+                span: Span::new(15, 20),
+            }),
+            span: Span::new(9, 20),
+        },
+        span: Span::new(9, 21),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_invalid_compound_assignment_target() {
+    let input = r"
+        1 += 2;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert_eq!(
+        errors,
+        [Error::InvalidAssignmentTarget {
+            line: 2,
+            column: 11,
+            span: Span::new(11, 13),
+        }]
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_increment_and_decrement() {
+    let input = r"
+        ++i;
+        j--;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![
+        Statement::Expression {
+            expression: Expression::Update {
+                name: Token::new(TokenKind::Identifier("i".into()), 2, 11, Span::new(11, 12)),
+                operator: Token::new(TokenKind::Increment, 2, 9, Span::new(9, 11)),
+                is_prefix: true,
+                span: Span::new(9, 12),
+            },
+            span: Span::new(9, 13),
+        },
+        Statement::Expression {
+            expression: Expression::Update {
+                name: Token::new(TokenKind::Identifier("j".into()), 3, 9, Span::new(22, 23)),
+                operator: Token::new(TokenKind::Decrement, 3, 10, Span::new(23, 25)),
+                is_prefix: false,
+                span: Span::new(22, 25),
+            },
+            span: Span::new(22, 26),
+        },
+    ]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_invalid_update_target() {
+    let input = r"
+        (i)++;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert_eq!(
+        errors,
+        [Error::InvalidAssignmentTarget {
+            line: 2,
+            column: 12,
+            span: Span::new(12, 14),
+        }]
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_bitwise_precedence() {
+    let input = r"
+        a | b & c << d;
+    ";
+
+    let variable = |name: &str, start: usize| Expression::Variable {
+        name: Token::new(
+            TokenKind::Identifier(name.into()),
+            2,
+            start,
+            Span::new(start, start + 1),
+        ),
+        span: Span::new(start, start + 1),
+    };
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Expression {
+        expression: Expression::Binary {
+            left: Box::from(variable("a", 9)),
+            operator: Token::new(TokenKind::BitwiseOr, 2, 11, Span::new(11, 12)),
+            right: Box::from(Expression::Binary {
+                left: Box::from(variable("b", 13)),
+                operator: Token::new(TokenKind::BitwiseAnd, 2, 15, Span::new(15, 16)),
+                right: Box::from(Expression::Binary {
+                    left: Box::from(variable("c", 17)),
+                    operator: Token::new(TokenKind::BitwiseLeftShift, 2, 19, Span::new(19, 21)),
+                    right: Box::from(variable("d", 22)),
+                    span: Span::new(17, 23),
+                }),
+                span: Span::new(13, 23),
+            }),
+            span: Span::new(9, 23),
+        },
+        span: Span::new(9, 24),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_power() {
+    let input = r"
+        -2 ** 3 ** 2;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Expression {
+        expression: Expression::Unary {
+            operator: Token::new(TokenKind::Minus, 2, 9, Span::new(9, 10)),
+            right: Box::from(Expression::Binary {
+                left: Box::from(Expression::Literal {
+                    value: Literal::Integer(2),
+                    span: Span::new(10, 11),
+                }),
+                operator: Token::new(TokenKind::Power, 2, 12, Span::new(12, 14)),
+                right: Box::from(Expression::Binary {
+                    left: Box::from(Expression::Literal {
+                        value: Literal::Integer(3),
+                        span: Span::new(15, 16),
+                    }),
+                    operator: Token::new(TokenKind::Power, 2, 17, Span::new(17, 19)),
+                    right: Box::from(Expression::Literal {
+                        value: Literal::Integer(2),
+                        span: Span::new(20, 21),
+                    }),
+                    span: Span::new(15, 21),
+                }),
+                span: Span::new(10, 21),
+            }),
+            span: Span::new(9, 21),
+        },
+        span: Span::new(9, 22),
     }]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
@@ -1,4 +1,4 @@
-use lang::lexer::{tokens::Token, tokens::TokenKind, Lexer};
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
 use lang::parser::ast::AST;
 use lang::parser::{Expression, Literal, Parser, Statement};
 
@@ -13,11 +13,22 @@ fn test_string() {
     let tokens = lexer.tokenize().unwrap();
 
     let mut parser = Parser::new(&tokens);
-    let actual_ast = parser.parse().unwrap();
+    let (actual_ast, errors) = parser.parse();
     let expected_ast = AST::new(vec![Statement::Variable {
-        name: Token::new(TokenKind::Identifier("greeting".into()), 1, 12),
-        initializer: Some(Expression::Literal(Literal::String("Hello, World!".into()))),
+        name: Token::new(
+            TokenKind::Identifier("greeting".into()),
+            2,
+            13,
+            Span::new(13, 21),
+        ),
+        type_annotation: None,
+        initializer: Some(Expression::Literal {
+            value: Literal::String("Hello, World!".into()),
+            span: Span::new(24, 39),
+        }),
+        span: Span::new(9, 40),
     }]);
 
+    assert_eq!(errors, []);
     assert_eq!(actual_ast, expected_ast);
 }
@@ -0,0 +1,184 @@
+use lang::lexer::{span::Span, tokens::Token, tokens::TokenKind, Lexer};
+use lang::parser::ast::AST;
+use lang::parser::errors::Error;
+use lang::parser::{Expression, Literal, Parser, Statement, TypeAnnotation};
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_struct_declaration() {
+    let input = r"
+        struct Point { x: float, y: [int], }
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Struct {
+        name: Token::new(
+            TokenKind::Identifier("Point".into()),
+            2,
+            16,
+            Span::new(16, 21),
+        ),
+        fields: vec![
+            (
+                Token::new(TokenKind::Identifier("x".into()), 2, 24, Span::new(24, 25)),
+                TypeAnnotation::Named(Token::new(
+                    TokenKind::Identifier("float".into()),
+                    2,
+                    27,
+                    Span::new(27, 32),
+                )),
+            ),
+            (
+                Token::new(TokenKind::Identifier("y".into()), 2, 34, Span::new(34, 35)),
+                TypeAnnotation::Array {
+                    element: Box::new(TypeAnnotation::Named(Token::new(
+                        TokenKind::Identifier("int".into()),
+                        2,
+                        38,
+                        Span::new(38, 41),
+                    ))),
+                    span: Span::new(37, 42),
+                },
+            ),
+        ],
+        span: Span::new(9, 45),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_field_assignment() {
+    let input = r"
+        p.x = Point { x: 1 }.x;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+    let expected_ast = AST::new(vec![Statement::Expression {
+        expression: Expression::FieldAssignment {
+            object: Box::from(Expression::Variable {
+                name: Token::new(TokenKind::Identifier("p".into()), 2, 9, Span::new(9, 10)),
+                span: Span::new(9, 10),
+            }),
+            name: Token::new(TokenKind::Identifier("x".into()), 2, 11, Span::new(11, 12)),
+            value: Box::from(Expression::Field {
+                object: Box::from(Expression::Struct {
+                    name: Token::new(
+                        TokenKind::Identifier("Point".into()),
+                        2,
+                        15,
+                        Span::new(15, 20),
+                    ),
+                    fields: vec![(
+                        Token::new(TokenKind::Identifier("x".into()), 2, 23, Span::new(23, 24)),
+                        Expression::Literal {
+                            value: Literal::Integer(1),
+                            span: Span::new(26, 27),
+                        },
+                    )],
+                    span: Span::new(15, 29),
+                }),
+                name: Token::new(TokenKind::Identifier("x".into()), 2, 30, Span::new(30, 31)),
+                span: Span::new(15, 31),
+            }),
+            span: Span::new(9, 31),
+        },
+        span: Span::new(9, 32),
+    }]);
+
+    assert_eq!(errors, []);
+    assert_eq!(actual_ast, expected_ast);
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_compound_field_assignment() {
+    let input = r"
+        p.x += 2;
+        p.x++;
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert_eq!(
+        errors,
+        [
+            Error::InvalidAssignmentTarget {
+                line: 2,
+                column: 13,
+                span: Span::new(13, 15),
+            },
+            Error::InvalidAssignmentTarget {
+                line: 3,
+                column: 12,
+                span: Span::new(30, 32),
+            }
+        ]
+    );
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_no_struct_literal_in_range() {
+    let input = r"
+        for i in 0 to p.n { print(i); }
+        struct Empty {}
+        let e = Empty {};
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (actual_ast, errors) = parser.parse();
+
+    // The brace after the end of the range starts the body, not a struct literal.
+    assert_eq!(errors, []);
+    assert!(matches!(
+        actual_ast.statements.as_slice(),
+        [
+            Statement::ForRange { end, body, .. },
+            Statement::Struct { fields, .. },
+            Statement::Variable {
+                initializer: Some(Expression::Struct { fields: given, .. }),
+                ..
+            },
+        ] if matches!(end, Expression::Field { .. })
+            && matches!(**body, Statement::Block { .. })
+            && fields.is_empty()
+            && given.is_empty()
+    ));
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_missing_field_type() {
+    let input = r"
+        struct Point { x }
+    ";
+
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (_, errors) = parser.parse();
+
+    assert!(matches!(
+        errors.as_slice(),
+        [Error::UnexpectedToken { message, .. }] if message == "Expected ':' after field name."
+    ));
+}
@@ -0,0 +1,176 @@
+use crate::semantics::analyze;
+use lang::semantics::errors::Error;
+
+#[test]
+fn test_loop_control() {
+    let input = r"
+        fn first(n: int) -> int {
+            for i in 0 to n {
+                if (i == 3) {
+                    return i;
+                }
+                while (true) {
+                    break;
+                }
+                continue;
+            }
+
+            return n;
+        }
+
+        for (let i = 0; i < 3; i++) {
+            print(first(i));
+            break;
+        }
+    ";
+
+    assert!(analyze(input).is_empty());
+}
+
+#[test]
+fn test_break_outside_loop() {
+    let input = r"
+        break;
+
+        while (true) {
+            fn inner() {
+                continue;
+            }
+        }
+
+        fn outer() {
+            if (true) {
+                break;
+            }
+        }
+    ";
+
+    // A function inside a loop doesn't inherit the loop.
+    assert!(matches!(
+        analyze(input).as_slice(),
+        [
+            Error::BreakOutsideLoop { line: 2, .. },
+            Error::ContinueOutsideLoop { line: 6, .. },
+            Error::BreakOutsideLoop { line: 12, .. },
+        ]
+    ));
+}
+
+#[test]
+fn test_return_outside_function() {
+    let input = r"
+        let x = 1;
+        return x;
+    ";
+
+    assert!(matches!(
+        analyze(input).as_slice(),
+        [Error::ReturnOutsideFunction {
+            line: 3,
+            column: 9,
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn test_missing_return() {
+    let input = r"
+        fn sign(n: int) -> int {
+            if (n < 0) {
+                return -1;
+            } else if (n == 0) {
+                return 0;
+            } else {
+                return 1;
+            }
+        }
+
+        fn search(n: int) -> int {
+            'outer: while (true) {
+                for (;;) {
+                    if (n > 0) {
+                        break;
+                    }
+                    return n;
+                }
+            }
+        }
+
+        fn log(n: int) {
+            print(n);
+        }
+    ";
+    let missing = r"
+        fn empty() -> int {
+        }
+
+        fn partial() -> int {
+            if (true) {
+                return 1;
+            }
+        }
+
+        fn looping(n: int) -> int {
+            while (n > 0) {
+                return n;
+            }
+        }
+
+        fn broken() -> int {
+            'outer: while (true) {
+                while (true) {
+                    break 'outer;
+                }
+            }
+        }
+    ";
+
+    assert!(analyze(input).is_empty());
+    assert!(matches!(
+        analyze(missing).as_slice(),
+        [
+            Error::MissingReturn { name: empty, line: 2, .. },
+            Error::MissingReturn { line: 5, .. },
+            Error::MissingReturn { line: 11, .. },
+            Error::MissingReturn { expected, line: 17, .. },
+        ] if empty == "empty" && expected == "int"
+    ));
+}
+
+#[test]
+fn test_labels() {
+    let input = r"
+        'outer: for i in 0 to 3 {
+            'inner: while (true) {
+                if (i == 1) {
+                    continue 'outer;
+                }
+                break 'inner;
+            }
+        }
+    ";
+    let undefined = r"
+        'outer: while (true) {
+            break 'inner;
+        }
+
+        'outer: while (true) {
+            fn f() {
+                while (true) {
+                    continue 'outer;
+                }
+            }
+        }
+    ";
+
+    assert!(analyze(input).is_empty());
+    // A function doesn't see the labels of the loops around it.
+    assert!(matches!(
+        analyze(undefined).as_slice(),
+        [
+            Error::UndefinedLabel { label: inner, line: 3, .. },
+            Error::UndefinedLabel { label: outer, line: 9, .. },
+        ] if inner == "'inner" && outer == "'outer"
+    ));
+}
@@ -0,0 +1,36 @@
+mod control_flow;
+mod recovery;
+mod types;
+
+use lang::lexer::Lexer;
+use lang::parser::Parser;
+use lang::semantics::errors::Error;
+use lang::semantics::warnings::Warning;
+use lang::semantics::SemanticAnalyzer;
+
+/// Analyzes a program, returning every error found.
+fn analyze(input: &str) -> Vec<Error> {
+    check(input).0
+}
+
+/// Analyzes a program, returning every error and warning found.
+fn check(input: &str) -> (Vec<Error>, Vec<Warning>) {
+    check_shadowing(input, true)
+}
+
+/// Analyzes a program, returning every error and warning found, with or without warnings about
+/// shadowing.
+#[allow(clippy::unwrap_used)]
+fn check_shadowing(input: &str, warn_shadowing: bool) -> (Vec<Error>, Vec<Warning>) {
+    let mut lexer = Lexer::new(input);
+    let tokens = lexer.tokenize().unwrap();
+
+    let mut parser = Parser::new(&tokens);
+    let (ast, errors) = parser.parse();
+    assert_eq!(errors, []);
+
+    let mut semantics = SemanticAnalyzer::new(&ast).with_shadowing_warnings(warn_shadowing);
+    let errors = semantics.analyze().err().unwrap_or_default();
+
+    (errors, semantics.warnings().to_vec())
+}
@@ -0,0 +1,169 @@
+use crate::semantics::{analyze, check, check_shadowing};
+use lang::lexer::span::Span;
+use lang::semantics::errors::Error;
+use lang::semantics::warnings::Warning;
+
+#[test]
+fn test_multiple_errors() {
+    let input = r#"
+        let x: int;
+        print(x);
+        print(y + z);
+        print("a" - 1);
+
+        fn f() {
+            print(undefined);
+        }
+    "#;
+
+    let errors = analyze(input);
+
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            Error::UninitializedVariable { name: x, .. },
+            Error::UndefinedSymbol { name: y, .. },
+            Error::UndefinedSymbol { name: z, .. },
+            Error::TypeMismatch { .. },
+            Error::UndefinedSymbol { name: undefined, .. },
+        ] if x == "x" && y == "y" && z == "z" && undefined == "undefined"
+    ));
+}
+
+#[test]
+fn test_no_cascading_errors() {
+    let input = r"
+        let x = missing + 1;
+        let y = x * 2;
+        print(y);
+    ";
+
+    let errors = analyze(input);
+
+    assert!(matches!(
+        errors.as_slice(),
+        [Error::UndefinedSymbol { name, .. }] if name == "missing"
+    ));
+}
+
+#[test]
+fn test_unused_variables() {
+    let input = r"
+        let unused = 1;
+        let _ignored = 2;
+        let used = 3;
+
+        fn f(parameter: int) {
+            let local = used;
+        }
+    ";
+
+    let (errors, warnings) = check(input);
+
+    assert!(errors.is_empty());
+    assert_eq!(
+        warnings,
+        [
+            Warning::UnusedVariable {
+                name: "unused".to_string(),
+                line: 2,
+                column: 13,
+                span: Span::new(13, 19),
+            },
+            Warning::UnusedVariable {
+                name: "local".to_string(),
+                line: 7,
+                column: 17,
+                span: Span::new(121, 126),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_duplicate_declarations() {
+    let input = r#"
+        fn greet(name: str) -> str {
+            return "Hello, " + name;
+        }
+
+        fn greet(name: str, name: str) -> str {
+            return name;
+        }
+
+        {
+            fn greet() {}
+        }
+
+        print(greet("Lithium"));
+    "#;
+
+    let types = r"
+        struct P { x: int }
+        struct P { y: str }
+        enum E { A }
+        enum E { B }
+
+        fn f() {}
+        struct f { x: int }
+
+        let p = P { x: 1 };
+        let e = E::A;
+    ";
+
+    let errors = analyze(input);
+
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            Error::DuplicateDeclaration { kind, name: function, line: 6, previous: Some(_), .. },
+            Error::DuplicateParameter { name: parameter, column: 29, previous: Some(_), .. },
+        ] if kind == "function" && function == "greet" && parameter == "name"
+    ));
+    // The first declaration of a type is kept, and the later one is always the one reported.
+    assert!(matches!(
+        analyze(types).as_slice(),
+        [
+            Error::DuplicateDeclaration { kind: structure, line: 3, previous: Some(_), .. },
+            Error::DuplicateDeclaration { kind: enumeration, line: 5, previous: Some(_), .. },
+            Error::DuplicateDeclaration { kind: clash, name, line: 8, span, previous: Some(previous), .. },
+        ] if structure == "struct"
+            && enumeration == "enum"
+            && clash == "struct"
+            && name == "f"
+            && previous.start < span.start
+    ));
+}
+
+#[test]
+fn test_shadowing() {
+    let input = r"
+        let count = 1;
+
+        fn f(count: int) -> int {
+            let count = count + 1;
+
+            return count;
+        }
+
+        {
+            let count = 2;
+            print(count);
+        }
+
+        let count = f(count);
+        print(count);
+    ";
+
+    let (errors, warnings) = check(input);
+
+    assert!(errors.is_empty());
+    assert!(matches!(
+        warnings.as_slice(),
+        [
+            Warning::ShadowedParameter { line: 5, previous: parameter, .. },
+            Warning::ShadowedVariable { line: 11, previous: variable, .. },
+        ] if *parameter == Span::new(38, 43) && *variable == Span::new(13, 18)
+    ));
+    assert_eq!(check_shadowing(input, false).1, []);
+}
@@ -0,0 +1,795 @@
+use crate::semantics::analyze;
+use lang::semantics::errors::Error;
+
+#[test]
+fn test_well_typed() {
+    let input = r#"
+        fn average(a: float, b: int) -> float {
+            return (a + b) / 2;
+        }
+
+        fn describe(value: float) -> str {
+            if (value > 1.5) {
+                return "big";
+            }
+
+            return "small";
+        }
+
+        let x: float;
+        x = average(1, 2);
+        print(describe(x) + "!");
+    "#;
+
+    assert!(analyze(input).is_empty());
+}
+
+#[test]
+fn test_argument_mismatch() {
+    let input = r#"
+        fn square(n: int) -> int {
+            return n * n;
+        }
+
+        square("four");
+    "#;
+
+    assert!(matches!(
+        analyze(input).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "str"
+    ));
+}
+
+#[test]
+fn test_return_mismatch() {
+    let input = r"
+        fn is_even(n: int) -> bool {
+            return n % 2;
+        }
+    ";
+    let missing = r"
+        fn answer() -> int {
+            return;
+        }
+    ";
+
+    assert!(matches!(
+        analyze(input).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "bool" && found == "int"
+    ));
+    assert!(matches!(
+        analyze(missing).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "void"
+    ));
+}
+
+#[test]
+fn test_operand_mismatch() {
+    let arithmetic = r#"
+        print("a" - 1);
+    "#;
+    let logical = r"
+        print(!5);
+    ";
+    let condition = r"
+        while (1) {}
+    ";
+
+    assert!(matches!(
+        analyze(arithmetic).as_slice(),
+        [Error::TypeMismatch { .. }]
+    ));
+    assert!(matches!(
+        analyze(logical).as_slice(),
+        [Error::TypeMismatch { .. }]
+    ));
+    assert!(matches!(
+        analyze(condition).as_slice(),
+        [Error::TypeMismatch { .. }]
+    ));
+}
+
+#[test]
+fn test_assignment_mismatch() {
+    let input = r#"
+        let name = "Lithium";
+        name = 5;
+    "#;
+
+    assert!(matches!(
+        analyze(input).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "str" && found == "int"
+    ));
+}
+
+#[test]
+fn test_unknown_type() {
+    let input = r"
+        fn f(n: number) {}
+    ";
+
+    assert!(matches!(
+        analyze(input).as_slice(),
+        [Error::UnknownType { name, .. }] if name == "number"
+    ));
+}
+
+#[test]
+fn test_compound_assignment() {
+    let valid = r#"
+        let total = 1.5;
+        total += 2;
+        total *= total;
+
+        let name = "Lithium";
+        name += "!";
+    "#;
+    let narrowing = r"
+        let count = 1;
+        count += 0.5;
+    ";
+    let invalid_operands = r"
+        let done = false;
+        done -= 1;
+    ";
+
+    assert!(analyze(valid).is_empty());
+    assert!(matches!(
+        analyze(narrowing).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "float"
+    ));
+    assert!(matches!(
+        analyze(invalid_operands).as_slice(),
+        [Error::TypeMismatch { .. }]
+    ));
+}
+
+#[test]
+fn test_update() {
+    let valid = r"
+        let i = 0;
+        let x = 1.5;
+        i++;
+        --x;
+    ";
+    let not_a_number = r#"
+        let name = "Lithium";
+        name++;
+    "#;
+    let uninitialized = r"
+        let i: int;
+        ++i;
+    ";
+    let not_a_variable = r"
+        fn f() {}
+        f--;
+    ";
+
+    assert!(analyze(valid).is_empty());
+    assert!(matches!(
+        analyze(not_a_number).as_slice(),
+        [Error::TypeMismatch { found, .. }] if found == "str"
+    ));
+    assert!(matches!(
+        analyze(uninitialized).as_slice(),
+        [Error::UninitializedVariable { .. }]
+    ));
+    assert!(matches!(
+        analyze(not_a_variable).as_slice(),
+        [Error::InvalidAssignment { .. }]
+    ));
+}
+
+#[test]
+fn test_bitwise_operators() {
+    let valid = r"
+        let flags = 1 | 2 ^ 3 & 4;
+        let shifted = flags << 2 >> 1;
+        shifted &= 7;
+    ";
+    let float = r"
+        let x = 1.5 & 1;
+    ";
+    let boolean = r"
+        let x = true | false;
+    ";
+
+    assert!(analyze(valid).is_empty());
+    assert!(matches!(
+        analyze(float).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "float"
+    ));
+    assert!(matches!(
+        analyze(boolean).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "bool"
+    ));
+}
+
+#[test]
+fn test_power() {
+    let valid = r"
+        let x = 2 ** 10;
+        let y = 2 ** 0.5;
+        y = 2.5 ** x;
+    ";
+    let integer = r"
+        let x = 2 ** 3;
+        x = 1.5;
+    ";
+    let string = r#"
+        let x = "2" ** 2;
+    "#;
+
+    assert!(analyze(valid).is_empty());
+    assert!(matches!(
+        analyze(integer).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "float"
+    ));
+    assert!(matches!(
+        analyze(string).as_slice(),
+        [Error::TypeMismatch { found, .. }] if found == "str"
+    ));
+}
+
+#[test]
+fn test_number_literals() {
+    let integer = r"
+        let x = 0x10 << 2;
+    ";
+    let float = r"
+        let x = 2.0 & 1;
+    ";
+
+    assert!(analyze(integer).is_empty());
+    assert!(matches!(
+        analyze(float).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "float"
+    ));
+}
+
+#[test]
+fn test_for_range() {
+    let input = r"
+        for i in 0 to 10 step 2 {
+            print(i & 1);
+        }
+    ";
+    let float = r"
+        for i in 0 to 1 step 0.25 {
+            print(i & 1);
+        }
+    ";
+    let string = r#"
+        for i in 0 to "ten" {}
+    "#;
+    let scoped = r"
+        for i in 0 to 10 {}
+        print(i);
+    ";
+    let zero = r"
+        for i in 0 to 10 step 0 {}
+        for i in 0 to 1 step (0.0) {}
+        let step = 0;
+        for i in 0 to 10 step step {}
+    ";
+
+    assert!(analyze(input).is_empty());
+    assert!(matches!(
+        analyze(float).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "float"
+    ));
+    assert!(matches!(
+        analyze(string).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int or float" && found == "str"
+    ));
+    assert!(matches!(
+        analyze(scoped).as_slice(),
+        [Error::UndefinedSymbol { name, .. }] if name == "i"
+    ));
+    assert!(matches!(
+        analyze(zero).as_slice(),
+        [Error::ZeroStep { .. }, Error::ZeroStep { .. }]
+    ));
+}
+
+#[test]
+fn test_arrays() {
+    let input = r#"
+        fn total(values: [float]) -> float {
+            let sum = 0.0;
+            for i in 0 to len(values) - 1 {
+                sum += values[i];
+            }
+
+            return sum;
+        }
+
+        let values = [1, 2.5];
+        values[0] = 3;
+        print(total(values) + len("abc"));
+
+        let weights: [float] = [1, 2];
+        let grid: [[float]] = [[1], [2, 3]];
+        weights = [3];
+        print(total([4, 5]) + total(weights) + total(grid[1]));
+    "#;
+    let heterogeneous = r#"
+        let values = [1, "two"];
+    "#;
+    let index = r#"
+        let values = [1, 2];
+        print(values["0"]);
+    "#;
+    let element = r#"
+        let names = ["a"];
+        names[0] = 1;
+    "#;
+    let invariant = r"
+        fn total(values: [float]) -> float {
+            return values[0];
+        }
+
+        let values = [1, 2];
+        print(total(values));
+    ";
+    let nested = r"
+        let row = [1];
+        let grid: [[float]] = [[2], row];
+    ";
+    let length = r"
+        print(len(3));
+    ";
+
+    assert!(analyze(input).is_empty());
+    assert!(matches!(
+        analyze(heterogeneous).as_slice(),
+        [Error::HeterogeneousArray { expected, found, .. }] if expected == "int" && found == "str"
+    ));
+    assert!(matches!(
+        analyze(index).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "int" && found == "str"
+    ));
+    assert!(matches!(
+        analyze(element).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "str" && found == "int"
+    ));
+    assert!(matches!(
+        analyze(invariant).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "[float]" && found == "[int]"
+    ));
+    assert!(matches!(
+        analyze(nested).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }]
+            if expected == "[[float]]" && found == "[[int]]"
+    ));
+    assert!(matches!(
+        analyze(length).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "array or str" && found == "int"
+    ));
+}
+
+#[test]
+fn test_structs() {
+    let input = r"
+        struct Point { x: float, y: float }
+        struct Path { points: [Point] }
+
+        fn origin() -> Point {
+            return Point { x: 0, y: 0.0 };
+        }
+
+        let path = Path { points: [origin()] };
+        path.points[0].x = 2;
+        print(path.points[0].y + 1);
+    ";
+    let missing = r"
+        struct Point { x: float, y: float }
+        let p = Point { y: 1.0, z: 2.0 };
+    ";
+    let duplicate = r"
+        struct Point { x: float, x: int }
+    ";
+    let field = r#"
+        struct Point { x: float }
+        let p = Point { x: 1.0 };
+        p.x = "one";
+        print(p.y);
+    "#;
+    let object = r"
+        let n = 1;
+        print(n.x);
+    ";
+    let value = r"
+        struct Point { x: float }
+        print(Point);
+    ";
+
+    assert!(analyze(input).is_empty());
+    assert!(matches!(
+        analyze(missing).as_slice(),
+        [
+            Error::UnknownField { field: unknown, .. },
+            Error::MissingField { field: missing, .. },
+        ] if unknown == "z" && missing == "x"
+    ));
+    assert!(matches!(
+        analyze(duplicate).as_slice(),
+        [Error::DuplicateField { structure, field, .. }] if structure == "Point" && field == "x"
+    ));
+    assert!(matches!(
+        analyze(field).as_slice(),
+        [
+            Error::TypeMismatch { expected, found, .. },
+            Error::UnknownField { field, .. },
+        ] if expected == "float" && found == "str" && field == "y"
+    ));
+    assert!(matches!(
+        analyze(object).as_slice(),
+        [Error::TypeMismatch { expected, found, .. }] if expected == "struct" && found == "int"
+    ));
+    assert!(matches!(
+        analyze(value).as_slice(),
+        [Error::InvalidVariable { name, .. }] if name == "Point"
+    ));
+}
+
+#[test]
+fn test_enums() {
+    let input = r"
+        enum Shape { Circle(float), Rect(float, float), Empty }
+
+        fn area(shape: Shape) -> float {
+            return match shape {
+                Shape::Circle(r) => 3 * r * r,
+                Shape::Rect(w, h) => w * h,
+                Shape::Empty => 0.0,
+            };
+        }
+
+        print(area(Shape::Rect(1, 2.5)));
+        print(match true { true => 1, false => 0 });
+    ";
+    let duplicate = r"
+        enum Shape { Empty, Empty }
+    ";
+    let variant = r"
+        enum Shape { Circle(float), Empty }
+        let a = Shape::Square;
+        let b = Shape::Circle(1.0, 2.0);
+        let c = Shape::Circle(true);
+    ";
+    let exhaustive = r"
+        enum Shape { Circle(float), Rect(float, float), Empty }
+        let shape = Shape::Empty;
+        let a = match shape { Shape::Circle(r) => r };
+        let b = match 1 { 0 => 0 };
+        let c = match false { true => 1 };
+    ";
+    let arms = r#"
+        let n = 1;
+        let a = match n { 0 => "zero", _ => 1 };
+        let b = match n { true => 0, _ => 1 };
+    "#;
+
+    assert!(analyze(input).is_empty());
+    assert!(matches!(
+        analyze(duplicate).as_slice(),
+        [Error::DuplicateVariant { enumeration, variant, .. }]
+            if enumeration == "Shape" && variant == "Empty"
+    ));
+    assert!(matches!(
+        analyze(variant).as_slice(),
+        [
+            Error::UnknownVariant { variant: unknown, .. },
+            Error::PayloadMismatch { variant: mismatched, expected: 1, found: 2, .. },
+            Error::TypeMismatch { expected, found, .. },
+        ] if unknown == "Square" && mismatched == "Shape::Circle" && expected == "float" && found == "bool"
+    ));
+    assert!(matches!(
+        analyze(exhaustive).as_slice(),
+        [
+            Error::NonExhaustiveMatch { missing: variants, .. },
+            Error::NonExhaustiveMatch { missing: wildcard, .. },
+            Error::NonExhaustiveMatch { missing: booleans, .. },
+        ] if variants == &["'Shape::Rect'", "'Shape::Empty'"]
+            && wildcard == &["'_'"]
+            && booleans == &["'false'"]
+    ));
+    assert!(matches!(
+        analyze(arms).as_slice(),
+        [
+            Error::TypeMismatch { expected: first, found: second, .. },
+            Error::TypeMismatch { expected, found, .. },
+        ] if first == "str" && second == "int" && expected == "int" && found == "bool"
+    ));
+}
+
+#[test]
+fn test_method_calls() {
+    let input = r#"
+        fn shout(s: str) -> str {
+            return s + "!";
+        }
+
+        fn half(x: float) -> float {
+            return x / 2;
+        }
+
+        let loud = "a".shout().shout();
+        print(4.half() + 1.5);
+    "#;
+    let invalid = r#"
+        fn shout(s: str) -> str {
+            return s + "!";
+        }
+
+        fn pad(s: str, n: int) -> str {
+            return s;
+        }
+
+        print(1.shout());
+        print("a".missing());
+        print("a".pad("b"));
+    "#;
+
+    assert!(analyze(input).is_empty());
+    assert!(matches!(
+        analyze(invalid).as_slice(),
+        [
+            Error::UnknownMethod { receiver, method: shout, .. },
+            Error::UnknownMethod { method: missing, .. },
+            Error::TypeMismatch { expected, found, .. },
+        ] if receiver == "int" && shout == "shout" && missing == "missing"
+            && expected == "int" && found == "str"
+    ));
+}
+
+#[test]
+fn test_typed_declarations() {
+    let input = r"
+        struct Point { x: float }
+
+        let ratio: float = 1;
+        let points: [Point] = [Point { x: ratio }];
+        let count: int;
+        count = len(points);
+        ratio = count;
+    ";
+    let invalid = r#"
+        let name: int = "Lithium";
+        let total: int;
+        total = 1.5;
+        let unknown: Foo = 1;
+        let untyped;
+    "#;
+
+    assert!(analyze(input).is_empty());
+    assert!(matches!(
+        analyze(invalid).as_slice(),
+        [
+            Error::TypeMismatch { expected: int, found: string, .. },
+            Error::TypeMismatch { expected, found, .. },
+            Error::UnknownType { name: unknown, .. },
+            Error::MissingTypeAnnotation { name: untyped, .. },
+        ] if int == "int" && string == "str" && expected == "int" && found == "float"
+            && unknown == "Foo" && untyped == "untyped"
+    ));
+}
+
+#[test]
+fn test_forward_references() {
+    let input = r"
+        print(is_even(10));
+        print(norm(Point { x: 3, y: 4 }));
+
+        fn is_even(n: int) -> bool {
+            if (n == 0) {
+                return true;
+            }
+
+            return is_odd(n - 1);
+        }
+
+        fn is_odd(n: int) -> bool {
+            if (n == 0) {
+                return false;
+            }
+
+            return is_even(n - 1);
+        }
+
+        fn norm(p: Point) -> float {
+            return p.x * p.x + p.y * p.y;
+        }
+
+        struct Point { x: float, y: float }
+    ";
+    let scoped = r#"
+        {
+            helper();
+
+            fn helper() {}
+        }
+
+        helper();
+        print(later("one"));
+
+        fn later(n: int) -> int {
+            return n;
+        }
+    "#;
+
+    assert!(analyze(input).is_empty());
+    // A function is hoisted within its own block only, and keeps its signature.
+    assert!(matches!(
+        analyze(scoped).as_slice(),
+        [
+            Error::UndefinedSymbol { name, .. },
+            Error::TypeMismatch { expected, found, .. },
+        ] if name == "helper" && expected == "int" && found == "str"
+    ));
+}
+
+#[test]
+fn test_forward_type_references() {
+    let input = r"
+        struct Line { start: Point, end: Point, style: Style }
+
+        enum Style { Solid, Dashed(Length) }
+
+        struct Length { value: float }
+
+        struct Point { x: float, y: float }
+
+        let line = Line {
+            start: Point { x: 0, y: 0 },
+            end: Point { x: 3, y: 4 },
+            style: Style::Dashed(Length { value: 0.5 }),
+        };
+        print(line.end.x + line.start.y);
+    ";
+    let unknown = r"
+        struct A { b: B }
+
+        {
+            struct B { x: int }
+        }
+    ";
+
+    assert!(analyze(input).is_empty());
+    // A type is hoisted within its own block only.
+    assert!(matches!(
+        analyze(unknown).as_slice(),
+        [Error::UnknownType { name, .. }] if name == "B"
+    ));
+}
+
+#[test]
+fn test_shadowed_types() {
+    let input = r"
+        struct P { x: int }
+        enum E { A(int) }
+
+        fn point() -> P {
+            return P { x: 1 };
+        }
+
+        fn variant() -> E {
+            return E::A(1);
+        }
+
+        {
+            struct P { y: str }
+            enum E { B }
+
+            let p = point();
+            print(p.x);
+            print(match variant() {
+                E::B => 0,
+            });
+
+            let q: P = point();
+        }
+    ";
+
+    // The types declared in the block are different from the outer ones of the same name.
+    assert!(matches!(
+        analyze(input).as_slice(),
+        [
+            Error::TypeMismatch { expected: e, found: other_e, line: 20, .. },
+            Error::NonExhaustiveMatch { .. },
+            Error::TypeMismatch { expected: p, found: other_p, line: 23, .. },
+        ] if e == "E" && other_e == "E" && p == "P" && other_p == "P"
+    ));
+}
+
+#[test]
+fn test_calls() {
+    let input = r#"
+        fn add(a: int, b: int) -> int {
+            return a + b;
+        }
+
+        fn shout(s: str) -> str {
+            return s + "!";
+        }
+
+        let f = add;
+        let p = print;
+        print(add(1, 2) + f(3, 4));
+        print("a".shout());
+        p(f);
+    "#;
+    let invalid = r#"
+        fn add(a: int, b: int) -> int {
+            return a + b;
+        }
+
+        fn shout(s: str) -> str {
+            return s + "!";
+        }
+
+        let number = 1;
+        add(1);
+        print(1, 2);
+        "a".len(1);
+        number(2);
+        add(1, 2)(3);
+
+        // Functions stored in variables keep their signatures.
+        let f = add;
+        f(1);
+        print(f("x", 2) + 1);
+        f = shout;
+    "#;
+
+    assert!(analyze(input).is_empty());
+    assert!(matches!(
+        analyze(invalid).as_slice(),
+        [
+            Error::ArityMismatch { expected: 2, found: 1, .. },
+            Error::ArityMismatch { expected: 1, found: 2, .. },
+            Error::ArityMismatch { expected: 1, found: 2, .. },
+            Error::NotCallable { found: variable, .. },
+            Error::NotCallable { found: call, .. },
+            Error::ArityMismatch { expected: 2, found: 1, .. },
+            Error::TypeMismatch { expected: int, found: string, .. },
+            Error::TypeMismatch { expected: add, found: shout, .. },
+        ] if variable == "int"
+            && call == "int"
+            && int == "int"
+            && string == "str"
+            && add == "fn(int, int) -> int"
+            && shout == "fn(str) -> str"
+    ));
+}
+
+#[test]
+fn test_captured_variables() {
+    let input = r"
+        let global = 1;
+
+        fn outer(parameter: int) -> int {
+            let x = 1;
+
+            fn inner() -> int {
+                return global + x + parameter;
+            }
+
+            fn set() {
+                x = 2;
+            }
+
+            return inner();
+        }
+    ";
+
+    assert!(matches!(
+        analyze(input).as_slice(),
+        [
+            Error::CapturedVariable { name: x, line: 8, .. },
+            Error::CapturedVariable { name: parameter, line: 8, .. },
+            Error::CapturedVariable { name: assigned, line: 12, .. },
+        ] if x == "x" && parameter == "parameter" && assigned == "x"
+    ));
+}
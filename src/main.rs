@@ -1,78 +1,268 @@
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use lang::bytecode::vm::VirtualMachine;
+use lang::bytecode::Compiler;
+use lang::diagnostics::Diagnostic;
+use lang::diagnostics::Severity;
+use lang::interpreter::Interpreter;
+use lang::lexer::span::SourceMap;
+use lang::lexer::tokens::Token;
+use lang::parser::ast::AST;
 use lang::semantics::SemanticAnalyzer;
 
+/// The size of the stack the tree-walking interpreter runs on, in bytes, which is as big as a
+/// main thread's default stack on Linux, so that the call depth limit is reached before the stack
+/// runs out on every platform.
+const INTERPRETER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
 /// The Lithium compiler CLI.
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// The input file.
-    #[arg(short, long)]
-    file: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// A command to run.
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Runs a program.
+    Run {
+        /// The input file.
+        file: String,
+        /// Prints an intermediate representation instead of running the program.
+        #[arg(long, value_enum)]
+        emit: Option<Emit>,
+        /// The backend which executes the program.
+        #[arg(long, value_enum, default_value_t = Backend::Vm)]
+        backend: Backend,
+        /// Doesn't warn when a variable shadows another variable or a parameter.
+        #[arg(long)]
+        allow_shadowing: bool,
+    },
+    /// Checks a program for errors without running it.
+    Check {
+        /// The input file.
+        file: String,
+        /// Doesn't warn when a variable shadows another variable or a parameter.
+        #[arg(long)]
+        allow_shadowing: bool,
+    },
+}
+
+/// A backend which can execute a program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Backend {
+    /// The bytecode virtual machine.
+    Vm,
+    /// The tree-walking interpreter.
+    Interpreter,
+}
+
+/// An intermediate representation which can be printed.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Emit {
+    /// The tokens produced by the lexer.
+    Tokens,
+    /// The AST produced by the parser.
+    Ast,
+    /// A disassembly of the bytecode produced by the compiler.
+    Bytecode,
 }
 
 fn main() {
     let args = Args::parse();
 
-    let contents = match lang::lexer::read_file(&args.file) {
-        Ok(contents) => contents,
-        Err(why) => {
-            eprintln!("Failed to read file: {why}");
+    match args.command {
+        Command::Run {
+            file,
+            emit,
+            backend,
+            allow_shadowing,
+        } => run(&file, emit, backend, allow_shadowing),
+        Command::Check {
+            file,
+            allow_shadowing,
+        } => check(&file, allow_shadowing),
+    }
+}
 
-            std::process::exit(1);
-        }
-    };
+fn run(file: &str, emit: Option<Emit>, backend: Backend, allow_shadowing: bool) {
+    let contents = read(file);
+    let source = SourceMap::new(&contents);
 
-    let mut lexer = lang::lexer::Lexer::new(&contents);
-    let (result, elapsed) = {
-        let start = std::time::Instant::now();
+    if matches!(emit, Some(Emit::Tokens)) {
+        let tokens = tokenize(&contents, &source, file);
+        println!("{tokens:#?}");
 
-        (lexer.tokenize(), start.elapsed())
-    };
-    let tokens = match result {
-        Ok(tokens) => tokens,
-        Err(why) => {
-            eprintln!("Failed to tokenize input: {why}");
+        return;
+    }
 
-            std::process::exit(1);
-        }
-    };
+    let ast = parse(&contents, &source, file);
+    if matches!(emit, Some(Emit::Ast)) {
+        println!("{ast:#?}");
 
-    println!("Tokens: {tokens:#?}");
-    println!("Lexical analysis took {elapsed:#?}.");
+        return;
+    }
 
-    let mut parser = lang::parser::Parser::new(&tokens);
-    let (result, elapsed) = {
-        let start = std::time::Instant::now();
+    let semantics = analyze(&ast, &source, file, allow_shadowing);
 
-        (parser.parse(), start.elapsed())
-    };
-    let ast = match result {
-        Ok(statements) => statements,
+    if backend == Backend::Interpreter && emit.is_none() {
+        interpret(&ast);
+
+        return;
+    }
+
+    let mut compiler = Compiler::new(&ast, &semantics);
+    let program = match compiler.compile() {
+        Ok(program) => program,
         Err(why) => {
-            eprintln!("Failed to parse input: {why}");
+            eprintln!("Failed to compile input: {why}");
 
             std::process::exit(1);
         }
     };
 
-    println!("AST: {ast:#?}");
-    println!("Parsing took {elapsed:#?}.");
+    if matches!(emit, Some(Emit::Bytecode)) {
+        print!("{program}");
+
+        return;
+    }
 
-    let mut semantics = SemanticAnalyzer::new(&ast);
-    let (result, elapsed) = {
-        let start = std::time::Instant::now();
+    let mut vm = VirtualMachine::new(&program, std::io::stdout().lock());
+    if let Err(why) = vm.run() {
+        eprintln!("Runtime error: {why}");
 
-        (semantics.analyze(), start.elapsed())
-    };
-    match result {
-        Ok(()) => {}
-        Err(why) => {
-            eprintln!("Failed to analyze input: {why}");
+        std::process::exit(1);
+    }
+}
 
-            std::process::exit(1);
+/// Runs a program with the tree-walking interpreter, which recurses for every nested call, on a
+/// thread whose stack is big enough for the maximum call depth.
+fn interpret(ast: &AST) {
+    let result = std::thread::scope(|scope| {
+        let thread = std::thread::Builder::new()
+            .stack_size(INTERPRETER_STACK_SIZE)
+            .spawn_scoped(scope, || {
+                Interpreter::new(ast, std::io::stdout().lock()).interpret()
+            });
+
+        match thread {
+            Ok(thread) => thread
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic)),
+            Err(why) => {
+                eprintln!("Failed to start the interpreter: {why}");
+
+                std::process::exit(1);
+            }
         }
+    });
+
+    if let Err(why) = result {
+        eprintln!("Runtime error: {why}");
+
+        std::process::exit(1);
+    }
+}
+
+fn check(file: &str, allow_shadowing: bool) {
+    let contents = read(file);
+    let source = SourceMap::new(&contents);
+
+    let ast = parse(&contents, &source, file);
+    analyze(&ast, &source, file, allow_shadowing);
+}
+
+fn read(file: &str) -> String {
+    match lang::lexer::read_file(file) {
+        Ok(contents) => contents,
+        Err(why) => fail(&[Diagnostic::from(&why)], &SourceMap::new(""), file),
+    }
+}
+
+fn tokenize(contents: &str, source: &SourceMap, file: &str) -> Vec<Token> {
+    let mut lexer = lang::lexer::Lexer::new(contents);
+    match lexer.tokenize() {
+        Ok(tokens) => tokens,
+        Err(why) => fail(&[Diagnostic::from(&why)], source, file),
     }
+}
+
+/// Parses a program, lexing it as the parser goes.
+fn parse(contents: &str, source: &SourceMap, file: &str) -> AST {
+    let mut parser = lang::parser::Parser::streaming(lang::lexer::Lexer::new(contents));
+    let (ast, errors) = parser.parse();
+    if !errors.is_empty() {
+        let diagnostics = errors.iter().map(Diagnostic::from).collect::<Vec<_>>();
+
+        fail(&diagnostics, source, file);
+    }
+
+    ast
+}
 
-    println!("Semantics: {semantics:#?}");
-    println!("Semantic analysis took {elapsed:#?}.");
+/// Analyzes a program, printing its warnings, or every error and warning if there are errors.
+fn analyze<'a>(
+    ast: &'a AST,
+    source: &SourceMap,
+    file: &str,
+    allow_shadowing: bool,
+) -> SemanticAnalyzer<'a> {
+    let mut semantics = SemanticAnalyzer::new(ast).with_shadowing_warnings(!allow_shadowing);
+    let result = semantics.analyze();
+
+    let mut diagnostics = semantics
+        .warnings()
+        .iter()
+        .map(Diagnostic::from)
+        .collect::<Vec<_>>();
+    if let Err(errors) = &result {
+        diagnostics.extend(errors.iter().map(Diagnostic::from));
+    }
+    diagnostics.sort_by_key(|diagnostic| diagnostic.primary_label().map(|label| label.span.start));
+
+    if result.is_err() {
+        fail(&diagnostics, source, file);
+    }
+
+    report(&diagnostics, source, file);
+
+    semantics
+}
+
+/// Prints diagnostics along with the source code they concern.
+fn report(diagnostics: &[Diagnostic], source: &SourceMap, file: &str) {
+    for diagnostic in diagnostics {
+        eprintln!("{}", diagnostic.render(source, file));
+    }
+}
+
+/// Prints diagnostics along with the source code they concern, and exits.
+fn fail(diagnostics: &[Diagnostic], source: &SourceMap, file: &str) -> ! {
+    report(diagnostics, source, file);
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    match (errors, warnings) {
+        (0 | 1, 0) => {}
+        (errors, 0) => eprintln!("Found {errors} errors in '{file}'."),
+        (errors, warnings) => eprintln!(
+            "Found {errors} {} and {warnings} {} in '{file}'.",
+            plural(errors, "error"),
+            plural(warnings, "warning")
+        ),
+    }
+
+    std::process::exit(1);
+}
+
+fn plural(count: usize, word: &str) -> String {
+    if count == 1 {
+        word.to_string()
+    } else {
+        format!("{word}s")
+    }
 }